@@ -43,7 +43,8 @@ func _on_combat_started(attacker_ulid: PackedByteArray, defender_ulid: PackedByt
 func _on_damage_dealt(
 	attacker_ulid: PackedByteArray,
 	defender_ulid: PackedByteArray,
-	damage: int  # Changed from float to int (new system)
+	damage: int,  # Changed from float to int (new system)
+	outcome: String  # "normal", "critical", "glancing" or "dodged"
 ) -> void:
 	if defender_ulid.is_empty():
 		push_error("CombatManager: Invalid defender ULID in damage_dealt")
@@ -68,9 +69,9 @@ func _on_damage_dealt(
 	# Check if entity is visible (in viewport)
 	var is_visible = _is_entity_visible(defender)
 
-	# Display damage number popup (only if visible)
+	# Display damage number popup (only if visible; a dodge deals nothing to show)
 	# NOTE: Projectiles are spawned via spawn_projectile signal (for ranged attacks)
-	if is_visible:
+	if is_visible and outcome != "dodged":
 		_show_damage_number(defender, float(damage))
 
 	# TODO: Screen shake for critical hits
@@ -128,15 +129,10 @@ func _on_spawn_projectile(
 	target_pos_q: int,
 	target_pos_r: int,
 	projectile_type: int,
-	damage: int
+	damage: int,
+	_splash_radius: int,  # The Actor bursts it on ProjectileHit
+	cover: int
 ) -> void:
-	print("[Projectile] Spawn signal received: attacker=%s, target=%s, type=%d, damage=%d" % [
-		UlidManager.to_hex(attacker_ulid),
-		UlidManager.to_hex(target_ulid),
-		projectile_type,
-		damage
-	])
-
 	# Validate entities
 	var attacker = UlidManager.get_instance(attacker_ulid) as Node2D
 	attacker = EntityManager.get_valid_entity_with_ulid(attacker, attacker_ulid)
@@ -152,8 +148,19 @@ func _on_spawn_projectile(
 
 	print("[Projectile] Entities validated, firing projectile from %s to %s" % [attacker.name, target.name])
 
+	# The Actor applies the damage (and any splash) where the projectile lands, with the
+	# power and cover it was fired with
+	var on_hit = func(impact: Vector2) -> void:
+		var impact_hex = Vector2i(target_pos_q, target_pos_r)
+		var tile_map = Cache.get_tile_map()
+		if tile_map:
+			impact_hex = tile_map.local_to_map(impact)
+		var bridge = Cache.get_unified_event_bridge()
+		if bridge:
+			bridge.projectile_hit(attacker_ulid, target_ulid, damage, projectile_type, impact_hex.x, impact_hex.y, cover)
+
 	# Fire projectile from attacker to target
-	_fire_projectile(attacker, target)
+	_fire_projectile(attacker, target, on_hit)
 
 ## Set or clear IN_COMBAT flag on an entity
 func _set_combat_flag(ulid: PackedByteArray, enable: bool) -> void:
@@ -215,7 +222,8 @@ func _trigger_death_animation(entity: Node) -> void:
 		tween.finished.connect(func(): entity.queue_free())
 
 ## Fire a projectile from attacker to defender
-func _fire_projectile(attacker: Node2D, defender: Node2D) -> void:
+## on_hit is called with the position it lands on (once, when it hits or runs out of range)
+func _fire_projectile(attacker: Node2D, defender: Node2D, on_hit: Callable) -> void:
 	print("[Projectile] _fire_projectile called: attacker=%s pos=%s, defender=%s pos=%s" % [
 		attacker.name,
 		attacker.global_position,
@@ -270,11 +278,15 @@ func _fire_projectile(attacker: Node2D, defender: Node2D) -> void:
 			_:  # Default or NONE
 				projectile_type = Projectile.Type.SPEAR
 
-	# Setup return callback
-	projectile.on_return_to_pool = func(proj):
+	# Report the impact, then return to the pool once it has played out
+	projectile.projectile_hit.connect(func():
+		on_hit.call(projectile.target_pos)
+	, CONNECT_ONE_SHOT)
+	projectile.ready_for_pool.connect(func(proj: Projectile):
 		if proj.is_inside_tree():
 			proj.get_parent().remove_child(proj)
 		Cluster.release("projectile", proj)
+	, CONNECT_ONE_SHOT)
 
 	# Fire!
 	print("[Projectile] Calling projectile.fire() with type=%d, speed=300.0, arc=30.0" % projectile_type)
@@ -14,15 +14,23 @@ signal path_found(ulid: PackedByteArray, path: Array, cost: float)
 signal path_failed(ulid: PackedByteArray)
 signal random_dest_found(ulid: PackedByteArray, destination_q: int, destination_r: int, found: bool)
 signal combat_started(attacker: PackedByteArray, defender: PackedByteArray)
-signal damage_dealt(attacker: PackedByteArray, defender: PackedByteArray, damage: int)
+signal damage_dealt(attacker: PackedByteArray, defender: PackedByteArray, damage: int, outcome: String)
 signal entity_died(ulid: PackedByteArray)
 signal combat_ended(attacker: PackedByteArray, defender: PackedByteArray)
-signal spawn_projectile(attacker_ulid: PackedByteArray, attacker_pos_q: int, attacker_pos_r: int, target_ulid: PackedByteArray, target_pos_q: int, target_pos_r: int, projectile_type: int, damage: int)
+signal spawn_projectile(attacker_ulid: PackedByteArray, attacker_pos_q: int, attacker_pos_r: int, target_ulid: PackedByteArray, target_pos_q: int, target_pos_r: int, projectile_type: int, damage: int, splash_radius: int, cover: int)
 signal resource_changed(resource_type: int, current: float, cap: float, rate: float)
+signal player_resource_changed(player_ulid: PackedByteArray, resource_type: int, current: float, cap: float, rate: float)
 signal stat_changed(ulid: PackedByteArray, stat_type: int, new_value: float)
 signal entity_damaged(ulid: PackedByteArray, damage: float, new_hp: float)
 signal entity_healed(ulid: PackedByteArray, heal_amount: float, new_hp: float)
+signal status_applied(ulid: PackedByteArray, source_ulid: PackedByteArray, kind: int, stacks: int, duration: float, move_speed_multiplier: float)
+signal status_expired(ulid: PackedByteArray, kind: int, move_speed_multiplier: float)
 signal combo_detected(hand_rank: int, hand_name: String, positions: Array, bonuses: Array)
+signal resource_shortage(player_ulid: PackedByteArray, resource_type: int, deficit: float, effect: Dictionary)
+signal shortage_ended(player_ulid: PackedByteArray, resource_type: int)
+signal recipe_status_changed(ulid: PackedByteArray, player_ulid: PackedByteArray, status: Dictionary)
+signal trade_completed(player_ulid: PackedByteArray, market_id: int, sold: Dictionary, bought: Dictionary)
+signal city_taxed(city_id: int, player_ulid: PackedByteArray, income_per_turn: float, reputation_delta: float)
 
 # DEPRECATED: IRC Chat Signals removed - now handled by IrcWebSocketClient autoload
 # See irc_websocket_client.gd for IRC functionality
@@ -45,10 +53,18 @@ func _ready() -> void:
 		event_bridge.combat_ended.connect(_on_combat_ended)
 		event_bridge.spawn_projectile.connect(_on_spawn_projectile)
 		event_bridge.resource_changed.connect(_on_resource_changed)
+		event_bridge.player_resource_changed.connect(_on_player_resource_changed)
 		event_bridge.stat_changed.connect(_on_stat_changed)
 		event_bridge.entity_damaged.connect(_on_entity_damaged)
 		event_bridge.entity_healed.connect(_on_entity_healed)
+		event_bridge.status_applied.connect(_on_status_applied)
+		event_bridge.status_expired.connect(_on_status_expired)
 		event_bridge.combo_detected.connect(_on_combo_detected)
+		event_bridge.resource_shortage.connect(_on_resource_shortage)
+		event_bridge.shortage_ended.connect(_on_shortage_ended)
+		event_bridge.recipe_status_changed.connect(_on_recipe_status_changed)
+		event_bridge.trade_completed.connect(_on_trade_completed)
+		event_bridge.city_taxed.connect(_on_city_taxed)
 
 		# DEPRECATED: IRC signals removed - now handled by IrcWebSocketClient autoload
 	else:
@@ -80,8 +96,8 @@ func _on_random_dest_found(ulid: PackedByteArray, destination_q: int, destinatio
 func _on_combat_started(attacker: PackedByteArray, defender: PackedByteArray) -> void:
 	combat_started.emit(attacker, defender)
 
-func _on_damage_dealt(attacker: PackedByteArray, defender: PackedByteArray, damage: int) -> void:
-	damage_dealt.emit(attacker, defender, damage)
+func _on_damage_dealt(attacker: PackedByteArray, defender: PackedByteArray, damage: int, outcome: String) -> void:
+	damage_dealt.emit(attacker, defender, damage, outcome)
 
 func _on_entity_died(ulid: PackedByteArray) -> void:
 	entity_died.emit(ulid)
@@ -89,12 +105,15 @@ func _on_entity_died(ulid: PackedByteArray) -> void:
 func _on_combat_ended(attacker: PackedByteArray, defender: PackedByteArray) -> void:
 	combat_ended.emit(attacker, defender)
 
-func _on_spawn_projectile(attacker_ulid: PackedByteArray, attacker_pos_q: int, attacker_pos_r: int, target_ulid: PackedByteArray, target_pos_q: int, target_pos_r: int, projectile_type: int, damage: int) -> void:
-	spawn_projectile.emit(attacker_ulid, attacker_pos_q, attacker_pos_r, target_ulid, target_pos_q, target_pos_r, projectile_type, damage)
+func _on_spawn_projectile(attacker_ulid: PackedByteArray, attacker_pos_q: int, attacker_pos_r: int, target_ulid: PackedByteArray, target_pos_q: int, target_pos_r: int, projectile_type: int, damage: int, splash_radius: int, cover: int) -> void:
+	spawn_projectile.emit(attacker_ulid, attacker_pos_q, attacker_pos_r, target_ulid, target_pos_q, target_pos_r, projectile_type, damage, splash_radius, cover)
 
 func _on_resource_changed(resource_type: int, current: float, cap: float, rate: float) -> void:
 	resource_changed.emit(resource_type, current, cap, rate)
 
+func _on_player_resource_changed(player_ulid: PackedByteArray, resource_type: int, current: float, cap: float, rate: float) -> void:
+	player_resource_changed.emit(player_ulid, resource_type, current, cap, rate)
+
 func _on_stat_changed(ulid: PackedByteArray, stat_type: int, new_value: float) -> void:
 	stat_changed.emit(ulid, stat_type, new_value)
 
@@ -104,9 +123,30 @@ func _on_entity_damaged(ulid: PackedByteArray, damage: float, new_hp: float) ->
 func _on_entity_healed(ulid: PackedByteArray, heal_amount: float, new_hp: float) -> void:
 	entity_healed.emit(ulid, heal_amount, new_hp)
 
+func _on_status_applied(ulid: PackedByteArray, source_ulid: PackedByteArray, kind: int, stacks: int, duration: float, move_speed_multiplier: float) -> void:
+	status_applied.emit(ulid, source_ulid, kind, stacks, duration, move_speed_multiplier)
+
+func _on_status_expired(ulid: PackedByteArray, kind: int, move_speed_multiplier: float) -> void:
+	status_expired.emit(ulid, kind, move_speed_multiplier)
+
 func _on_combo_detected(hand_rank: int, hand_name: String, positions: Array, bonuses: Array) -> void:
 	combo_detected.emit(hand_rank, hand_name, positions, bonuses)
 
+func _on_resource_shortage(player_ulid: PackedByteArray, resource_type: int, deficit: float, effect: Dictionary) -> void:
+	resource_shortage.emit(player_ulid, resource_type, deficit, effect)
+
+func _on_shortage_ended(player_ulid: PackedByteArray, resource_type: int) -> void:
+	shortage_ended.emit(player_ulid, resource_type)
+
+func _on_recipe_status_changed(ulid: PackedByteArray, player_ulid: PackedByteArray, status: Dictionary) -> void:
+	recipe_status_changed.emit(ulid, player_ulid, status)
+
+func _on_trade_completed(player_ulid: PackedByteArray, market_id: int, sold: Dictionary, bought: Dictionary) -> void:
+	trade_completed.emit(player_ulid, market_id, sold, bought)
+
+func _on_city_taxed(city_id: int, player_ulid: PackedByteArray, income_per_turn: float, reputation_delta: float) -> void:
+	city_taxed.emit(city_id, player_ulid, income_per_turn, reputation_delta)
+
 # ============================================================================
 # SPAWN API (Compatible with EntitySpawnBridge)
 # ============================================================================
@@ -168,6 +208,13 @@ func remove_entity(ulid: PackedByteArray) -> void:
 # ECONOMY API (Compatible with ResourceLedger)
 # ============================================================================
 
+## Set the player whose resource pool the economy calls below use (empty = AI/neutral pool)
+func set_local_player(player_ulid: PackedByteArray) -> void:
+	if not event_bridge:
+		return
+
+	event_bridge.set_local_player(player_ulid)
+
 ## Register a resource producer
 func register_producer(ulid: PackedByteArray, resource_type: int, rate_per_sec: float, active: bool = true) -> void:
 	if not event_bridge:
@@ -229,12 +276,13 @@ func heal(ulid: PackedByteArray, amount: float) -> void:
 	event_bridge.heal(ulid, amount)
 
 ## Called by projectile when it hits target
-## This applies the damage for ranged/bow/magic combat
-func projectile_hit(attacker_ulid: PackedByteArray, defender_ulid: PackedByteArray, damage: int, projectile_type: int) -> void:
+## This applies the damage for ranged/bow/magic combat; impact_q/impact_r is the hex it landed on,
+## damage and cover are passed back from spawn_projectile
+func projectile_hit(attacker_ulid: PackedByteArray, defender_ulid: PackedByteArray, damage: int, projectile_type: int, impact_q: int, impact_r: int, cover: int) -> void:
 	if not event_bridge:
 		return
 
-	event_bridge.projectile_hit(attacker_ulid, defender_ulid, damage, projectile_type)
+	event_bridge.projectile_hit(attacker_ulid, defender_ulid, damage, projectile_type, impact_q, impact_r, cover)
 
 # ============================================================================
 # RESOURCE API (Compatible with ResourceLedger)
@@ -1,12 +1,13 @@
 extends Node
 ## ResourceLedger (Singleton)
-## Manages global resource economy with Rust-backed storage
-## Connects to GameTimer and emits signals for UI updates
+## Synchronous view of the local player's resources, backed by the GameActor's economy
+## Writes are requests to the GameActor; its changes are re-emitted as resource_changed
 
 # Signals
 signal resource_changed(kind: int, current: float, cap: float, rate: float)
 
-# Resource enum - accessible globally as ResourceLedger.R
+# Built-in resource ids - accessible globally as ResourceLedger.R
+# The resource registry may define more (see get_resource_types)
 enum R {
 	GOLD = 0,
 	FOOD = 1,
@@ -14,49 +15,33 @@ enum R {
 	FAITH = 3,
 }
 
+# Optional resource registry override (same format as rust/sim/data/resources.json)
+const RESOURCE_REGISTRY_PATH := "res://data/resources.json"
+
 # Rust bridge
 var rust_bridge: ResourceLedgerBridge
 
 func _ready() -> void:
-	# Create Rust bridge (used as CACHE for synchronous queries only)
+	# Create Rust bridge (reads the GameActor's pools, queues writes to it)
 	rust_bridge = ResourceLedgerBridge.new()
 	add_child(rust_bridge)
 	rust_bridge.name = "RustBridge"
 
-	# NOTE: rust_bridge.resource_changed is NOT connected anymore
-	# GameActor via UnifiedEventBridge is the ONLY source of truth for resource changes
+	# Resource types come from the registry; a data file here replaces the built-in one
+	if FileAccess.file_exists(RESOURCE_REGISTRY_PATH):
+		var json := FileAccess.get_file_as_string(RESOURCE_REGISTRY_PATH)
+		if not rust_bridge.load_resource_registry(json):
+			push_error("ResourceLedger: invalid %s, using built-in resources" % RESOURCE_REGISTRY_PATH)
 
-	# Connect to GameActor's resource signals via UnifiedEventBridge
-	# This updates our cached values when GameActor changes resources
+	# GameActor via UnifiedEventBridge is the ONLY source of truth for resource changes
+	# (production, upkeep and per-turn food consumption all run in the GameActor)
 	if UnifiedEventBridge:
 		UnifiedEventBridge.resource_changed.connect(_on_gameactor_resource_changed)
 	else:
 		push_error("ResourceLedger: UnifiedEventBridge not found!")
 
-	# Connect to GameTimer
-	if GameTimer:
-		GameTimer.timer_tick.connect(_on_game_timer_tick)
-		GameTimer.consume_food.connect(_on_consume_food)
-	else:
-		push_error("ResourceLedger: GameTimer not found!")
-
-func _on_game_timer_tick(time_left: int) -> void:
-	# Forward timer tick to Rust (which will tick economy and emit signals)
-	rust_bridge.on_timer_tick(time_left)
-
-func _on_consume_food() -> void:
-	# Consume 1 food per turn (every 60 seconds)
-	add(R.FOOD, -1.0)
-
 func _on_gameactor_resource_changed(kind: int, current: float, cap: float, rate: float) -> void:
-	# GameActor (via UnifiedEventBridge) has updated resources
-	# Update our cached rust_bridge values for synchronous queries
-	var resource_name = ["GOLD", "FOOD", "LABOR", "FAITH"][kind] if kind < 4 else "UNKNOWN"
-	# print("ResourceLedger: Received GameActor resource update - %s: current=%.1f, cap=%.1f, rate=%.1f" % [resource_name, current, cap, rate])
-
-	# Update cached values in rust_bridge
-	rust_bridge.set_current(kind, current)
-	rust_bridge.set_cap(kind, cap)
+	resource_changed.emit(kind, current, cap, rate)
 
 # ---- Public API ----
 
@@ -80,69 +65,23 @@ func set_cap(kind: int, value: float) -> void:
 func set_current(kind: int, value: float) -> void:
 	rust_bridge.set_current(kind, value)
 
-## Add to current amount (clamped to [0, cap])
+## Add to current amount (clamped to cap; a negative amount the pool can't cover is rejected)
 func add(kind: int, amount: float) -> void:
-	# Delegate to GameActor via UnifiedEventBridge (single source of truth)
-	var event_bridge = get_node_or_null("/root/UnifiedEventBridge")
-	if event_bridge:
-		event_bridge.add_resources(kind, amount)
-	else:
-		push_error("ResourceLedger: UnifiedEventBridge not available!")
+	rust_bridge.add(kind, amount)
 
 ## Check if we can spend resources
 ## cost: Dictionary of {Resource enum -> amount}
 ## Example: {R.GOLD: 50, R.FOOD: 10}
-## NOTE: This is best-effort based on last known values.
-## For authoritative check, use spend() which will fail if insufficient.
+## NOTE: Based on the amounts the GameActor last published.
 func can_spend(cost: Dictionary) -> bool:
-	# Best-effort check using rust_bridge's cached values
-	# GameActor has the authoritative state, so this may be slightly stale
-	for resource_type in cost.keys():
-		var required = cost[resource_type]
-		var current = rust_bridge.get_current(resource_type)
-		if current < required:
-			return false
-	return true
-
-## Spend resources (GameActor checks and returns true/false)
+	return rust_bridge.can_spend(cost)
+
+## Spend resources
 ## cost: Dictionary of {Resource enum -> amount}
-## NOTE: This is ASYNCHRONOUS - GameActor will emit resource_changed signals
-## when the spend completes. We return true optimistically.
+## Returns false if the cost can't be covered; otherwise the spend is queued for the GameActor,
+## which re-checks it (UnifiedEventBridge.transaction_rejected if the pool changed meanwhile)
 func spend(cost: Dictionary) -> bool:
-	# Delegate to GameActor via UnifiedEventBridge (single source of truth)
-	var event_bridge = get_node_or_null("/root/UnifiedEventBridge")
-	if not event_bridge:
-		push_error("ResourceLedger: UnifiedEventBridge not available!")
-		return false
-
-	# Serialize cost to PackedByteArray for efficient Rust FFI
-	# Format: [resource_type (i64, 8 bytes), amount (f64, 8 bytes)] repeated
-	var costs_bytes = PackedByteArray()
-	for resource_type in cost.keys():
-		var amount = cost[resource_type]
-
-		# Encode resource_type as i64 (8 bytes, little-endian)
-		costs_bytes.append((resource_type >> 0) & 0xFF)
-		costs_bytes.append((resource_type >> 8) & 0xFF)
-		costs_bytes.append((resource_type >> 16) & 0xFF)
-		costs_bytes.append((resource_type >> 24) & 0xFF)
-		costs_bytes.append((resource_type >> 32) & 0xFF)
-		costs_bytes.append((resource_type >> 40) & 0xFF)
-		costs_bytes.append((resource_type >> 48) & 0xFF)
-		costs_bytes.append((resource_type >> 56) & 0xFF)
-
-		# Encode amount as f64 (8 bytes)
-		# Use PackedFloat64Array for proper IEEE 754 f64 encoding
-		var temp_array = PackedFloat64Array([amount])
-		var float_bytes = temp_array.to_byte_array()
-		for i in range(8):
-			costs_bytes.append(float_bytes[i])
-
-	event_bridge.spend_resources(costs_bytes)
-
-	# Return true optimistically - GameActor will emit events with actual results
-	# TODO: This should be async/await pattern in the future
-	return true
+	return rust_bridge.spend(cost)
 
 ## Register a producer
 ## Returns: the entity's ULID for future reference
@@ -204,7 +143,11 @@ func remove_producer(ulid: PackedByteArray) -> void:
 func remove_consumer(ulid: PackedByteArray) -> void:
 	rust_bridge.remove_consumer(ulid)
 
-## Reset all resources to their initial values (1000 each)
+## Resource types from the registry: Array of { id, key, name, default_amount, default_cap, decay, suit }
+func get_resource_types() -> Array[Dictionary]:
+	return rust_bridge.get_resource_types()
+
+## Reset all resources to their starting amounts and caps (producers/consumers are kept)
 func reset_resources() -> void:
 	rust_bridge.reset_resources()
 
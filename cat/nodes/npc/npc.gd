@@ -1322,7 +1322,7 @@ func _on_combat_ended(attacker_ulid: PackedByteArray, defender_ulid: PackedByteA
 	add_state(State.IDLE)
 
 # Handle damage dealt event (for ATTACKING state management)
-func _on_damage_dealt(attacker_ulid: PackedByteArray, defender_ulid: PackedByteArray, damage: int) -> void:
+func _on_damage_dealt(attacker_ulid: PackedByteArray, defender_ulid: PackedByteArray, damage: int, _outcome: String) -> void:
 	# Check if this entity is the attacker
 	if attacker_ulid != ulid:
 		return
@@ -1501,11 +1501,15 @@ func ranged_attack(target: Node2D, projectile_type: int = Projectile.Type.SPEAR)
 	if "ulid" in target and target.ulid is PackedByteArray:
 		target_ulid = target.ulid
 
-	# Connect to projectile_hit signal to deal damage
+	# Connect to projectile_hit signal so the Actor applies the damage where it lands
+	# (a manual shot traces no sightline, so it carries no cover)
+	var sim_projectile_type = self.projectile_type
 	projectile.projectile_hit.connect(func():
-		# Deal damage through StatsManager
-		if StatsManager and not target_ulid.is_empty():
-			var damage_dealt = StatsManager.take_damage(target_ulid, attack_power)
+		var bridge = Cache.get_unified_event_bridge()
+		var tile_map = Cache.get_tile_map()
+		if bridge and tile_map and not ulid.is_empty() and not target_ulid.is_empty():
+			var impact_hex = tile_map.local_to_map(projectile.target_pos)
+			bridge.projectile_hit(ulid, target_ulid, int(attack_power), sim_projectile_type, impact_hex.x, impact_hex.y, 0)
 	, CONNECT_ONE_SHOT)
 
 	# Connect to ready_for_pool signal to return projectile
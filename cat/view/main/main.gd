@@ -120,6 +120,11 @@ func _ready():
 	else:
 		push_error("Main: UlidManager not found!")
 
+	# Resources, producers and card rewards go to this player's pool in the Actor
+	var event_bridge = get_node_or_null("/root/UnifiedEventBridge")
+	if event_bridge:
+		event_bridge.set_local_player(player_ulid)
+
 	# Note: Language/seed selection now happens in title.tscn
 	# World seed already set in MapConfig by title.gd
 
@@ -0,0 +1,1833 @@
+// Game Actor - Central coordinator that owns all shared state
+// Processes requests, coordinates workers, emits events
+// TRUE ACTOR PATTERN: Runs on dedicated thread, communicates only via channels
+// Game logic lives in GameSystems (see systems/); the Actor routes requests and drives ticks
+
+use std::sync::Arc;
+use std::thread;
+use dashmap::DashMap;
+use crossbeam_channel::{Sender, Receiver};
+use std::sync::Mutex;
+use std::collections::HashMap;
+use std::mem::Discriminant;
+use std::time::{Duration, Instant};
+use once_cell::sync::Lazy;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::clock::SimClock;
+use crate::entity::EntityStats;
+// DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+// use crate::web::{NetworkWorkerHandle, NetworkWorkerConfig, start_network_worker, NetworkWorkerResponse, IrcClient, IrcConfig, IrcEvent, ChannelHistory, ChatMessage, MessageType};
+use crate::types::{GameEvent, GameRequest, QueuePolicy, TurnSummary};
+use crate::metrics::ActorMetrics;
+use crate::replay::EventSink;
+use crate::savegame::{GameSnapshot, SaveError};
+use crate::systems::{
+    CardSystem, CombatSystem, EconomySystem, EntitySystem, GameSystem, LootSystem, PathfindingSystem, SharedResources,
+    SpawnSystem, SystemContext, TurnPhase, World,
+};
+
+// Global entity stats storage (thread-safe, shared between Actor and FFI)
+// Actor owns write access, FFI reads via get_all_stats()
+pub static ACTOR_ENTITY_STATS: Lazy<Arc<DashMap<Vec<u8>, EntityStats>>> = Lazy::new(|| {
+    Arc::new(DashMap::new())
+});
+
+// Global resource pools (thread-safe, shared between Actor and FFI)
+// The economy system owns write access, FFI reads for synchronous queries (ResourceLedgerBridge)
+pub static ACTOR_RESOURCES: Lazy<SharedResources> = Lazy::new(|| {
+    Arc::new(DashMap::new())
+});
+
+// Latest Actor metrics (thread-safe, shared between Actor and FFI)
+// Actor publishes at the end of every tick, FFI reads via get_metrics()
+pub static ACTOR_METRICS: Lazy<Arc<Mutex<ActorMetrics>>> = Lazy::new(|| {
+    Arc::new(Mutex::new(ActorMetrics::default()))
+});
+
+/// Requests Godot may queue before the bridge starts rejecting them (see QueuePolicy)
+pub const REQUEST_QUEUE_CAPACITY: usize = 4096;
+
+// Fixed phase slots; each system adds collect/tick slots at registration
+const PHASE_REQUESTS: usize = 0;
+const PHASE_FOLLOW_UPS: usize = 1;
+
+// DEPRECATED: IRC chat history now handled by GDScript (irc_websocket_client.gd)
+// Global IRC chat history storage (thread-safe, shared between Actor and FFI)
+// Actor owns write access, FFI reads for UI rendering
+// pub static IRC_CHAT_HISTORY: Lazy<Arc<DashMap<String, ChannelHistory>>> = Lazy::new(|| {
+//     Arc::new(DashMap::new())
+// });
+
+/// Central coordinator that owns all shared state
+/// RUNS ON DEDICATED THREAD - No shared state, zero lock contention
+pub struct GameActor {
+    // === OWNED STATE (Direct ownership, no Arc needed) ===
+    world: World,                          // Entities, stats and teams shared by all systems
+    systems: Vec<Box<dyn GameSystem>>,     // Request routing + tick order = registration order
+    system_phases: Vec<(usize, usize)>,    // Per system: (collect, tick) metrics phase slots
+
+    // === COMMUNICATION (crossbeam_channel for proper Actor pattern) ===
+    request_rx: Receiver<GameRequest>,  // Receive requests from Godot
+    event_tx: EventSink,                // Send events to Godot (tapped while recording a replay)
+    follow_ups: Vec<GameRequest>,       // Requests systems queued for each other this tick
+
+    // === DETERMINISTIC MODE (replay recording / verification) ===
+    // Workers run inline on the Actor thread and push into the same result channels,
+    // so results land on the same tick every run
+    deterministic: bool,
+    mode_before_replay: Option<bool>,  // Mode to go back to when recording stops (None = not recording)
+    rng: StdRng,
+
+    // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+    // === GENERIC NETWORK WORKER (for general HTTP/WebSocket, NOT IRC) ===
+    // network_worker: Option<NetworkWorkerHandle>,
+
+    // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+    // === IRC CLIENT (owns its own dedicated network worker) ===
+    // irc_client: Option<IrcClient>,
+
+    // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+    // === IRC CHAT HISTORY ===
+    // chat_history: Arc<DashMap<String, ChannelHistory>>,  // Reference to global chat history
+
+    // === TIMING (accumulated tick delta, so replays are not tied to wall-clock time) ===
+    tick_count: u64,
+    clock: SimClock,         // Real tick delta -> simulated delta (pause, speed, stepping)
+    record_clock: bool,      // Write the clock state into the first tick of a new replay
+
+    // === TURN MODE (periodic system ticks paused; the world advances on StepTurn) ===
+    turn_mode: bool,
+    turn: u64,  // Turns completed
+
+    // === TELEMETRY ===
+    metrics: ActorMetrics,
+    shared_metrics: Arc<Mutex<ActorMetrics>>,  // Global ACTOR_METRICS unless deterministic
+    metrics_interval: f64,  // MetricsReport cadence (0 = off)
+    metrics_timer: f64,
+
+    running: bool,  // Cleared by GameRequest::Shutdown; ends run()
+}
+
+impl GameActor {
+    /// Create an Actor and start its workers
+    /// Hosts normally use `spawn_actor_thread`; headless harnesses can own the
+    /// Actor directly and drive it with `tick()`
+    pub fn new(
+        request_rx: Receiver<GameRequest>,
+        event_tx: Sender<GameEvent>,
+    ) -> Self {
+        Self::build(
+            request_rx,
+            event_tx,
+            Arc::clone(&ACTOR_ENTITY_STATS),  // Use global stats storage
+            Arc::clone(&ACTOR_RESOURCES),
+            Arc::clone(&ACTOR_METRICS),
+            StdRng::from_os_rng(),
+            false,
+        )
+    }
+
+    /// Create a deterministic Actor for replay verification
+    /// Seeded RNG, inline workers, and private stats/resources/metrics (does not touch ACTOR_ENTITY_STATS)
+    pub fn with_seed(
+        request_rx: Receiver<GameRequest>,
+        event_tx: Sender<GameEvent>,
+        seed: u64,
+    ) -> Self {
+        Self::build(
+            request_rx,
+            event_tx,
+            Arc::new(DashMap::new()),
+            Arc::new(DashMap::new()),
+            Arc::new(Mutex::new(ActorMetrics::default())),
+            StdRng::seed_from_u64(seed),
+            true,
+        )
+    }
+
+    fn build(
+        request_rx: Receiver<GameRequest>,
+        event_tx: Sender<GameEvent>,
+        entity_stats: Arc<DashMap<Vec<u8>, EntityStats>>,
+        resources: SharedResources,
+        shared_metrics: Arc<Mutex<ActorMetrics>>,
+        rng: StdRng,
+        deterministic: bool,
+    ) -> Self {
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // Generic network worker (for HTTP/WebSocket, NOT IRC)
+        // let network_worker = None;
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // IRC client will own its own dedicated network worker when created
+        // let irc_client = None;
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // Get reference to global chat history
+        // let chat_history = Arc::clone(&IRC_CHAT_HISTORY);
+
+        let mut metrics = ActorMetrics::default();
+        metrics.add_phase("requests");
+        metrics.add_phase("follow_ups");
+
+        let mut actor = Self {
+            world: World::new(entity_stats, resources),
+            systems: Vec::new(),
+            system_phases: Vec::new(),
+
+            request_rx,
+            event_tx: EventSink::new(event_tx),
+            follow_ups: Vec::new(),
+
+            deterministic,
+            mode_before_replay: None,
+            rng,
+
+            // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+            // network_worker,
+            // irc_client,
+            // chat_history,
+
+            tick_count: 0,
+            clock: SimClock::new(),
+            record_clock: false,
+
+            turn_mode: false,
+            turn: 0,
+
+            metrics,
+            shared_metrics,
+            metrics_interval: 0.0,
+            metrics_timer: 0.0,
+
+            running: true,
+        };
+
+        // Built-in systems (each starts its own workers unless deterministic)
+        actor.register_system(Box::new(EntitySystem::new()));
+        actor.register_system(Box::new(SpawnSystem::new()));
+        actor.register_system(Box::new(PathfindingSystem::new()));
+        actor.register_system(Box::new(CombatSystem::new()));
+        actor.register_system(Box::new(EconomySystem::new()));
+        actor.register_system(Box::new(CardSystem::new()));
+        actor.register_system(Box::new(LootSystem::new()));
+
+        actor
+    }
+
+    /// Add a system after the built-in ones and initialize it
+    /// It sees requests no earlier system consumed, and ticks after them
+    pub fn register_system(&mut self, mut system: Box<dyn GameSystem>) {
+        let mut ctx = SystemContext::new(&self.world, &self.event_tx, &mut self.rng, self.deterministic, &mut self.follow_ups);
+        system.init(&mut ctx);
+        sim_print!("Actor: Registered system '{}'", system.name());
+        let collect_phase = self.metrics.add_phase(format!("collect.{}", system.name()));
+        let tick_phase = self.metrics.add_phase(format!("tick.{}", system.name()));
+        self.system_phases.push((collect_phase, tick_phase));
+        self.systems.push(system);
+    }
+
+    /// Main tick function - called with the elapsed time since the previous tick
+    /// Systems see simulated time (see SimClock); requests and worker results are handled even while paused
+    pub fn tick(&mut self, delta: f64) {
+        let tick_start = Instant::now();
+        self.tick_count += 1;
+        self.event_tx.begin_tick(delta);
+
+        // A replay Actor starts with a default clock: record the requests that rebuild this one,
+        // and rebuild it the same way here so both emit identical events
+        if std::mem::take(&mut self.record_clock) {
+            let requests = self.clock.state_requests();
+            self.clock = SimClock::new();
+            for request in requests {
+                self.event_tx.record_request(&request);
+                self.apply_clock_request(&request);
+            }
+        }
+
+        // 1. Process all incoming requests from Godot
+        let phase_start = Instant::now();
+        self.process_requests();
+        self.metrics.record_phase(PHASE_REQUESTS, phase_start);
+
+        if !self.running {
+            return;
+        }
+
+        // 2. Restart dead workers, then collect results from workers and update state
+        {
+            let mut ctx = SystemContext::new(&self.world, &self.event_tx, &mut self.rng, self.deterministic, &mut self.follow_ups);
+            for (system, &(collect_phase, _)) in self.systems.iter_mut().zip(&self.system_phases) {
+                let phase_start = Instant::now();
+                system.supervise(&mut ctx);
+                system.collect(&mut ctx);
+                self.metrics.record_phase(collect_phase, phase_start);
+            }
+        }
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // self.collect_network_results();
+        // self.collect_irc_events();
+
+        // 3. Requests systems queued for each other (e.g. combat kiting -> pathfinding)
+        let phase_start = Instant::now();
+        for request in std::mem::take(&mut self.follow_ups) {
+            self.route_request(request);
+        }
+        self.metrics.record_phase(PHASE_FOLLOW_UPS, phase_start);
+
+        // 4. Periodic ticks with simulated time (none in turn mode; StepTurn advances the world instead)
+        let sim_delta = self.clock.advance(delta);
+        if !self.turn_mode && sim_delta > 0.0 {
+            let mut ctx = SystemContext::new(&self.world, &self.event_tx, &mut self.rng, self.deterministic, &mut self.follow_ups);
+            for (system, &(_, tick_phase)) in self.systems.iter_mut().zip(&self.system_phases) {
+                let phase_start = Instant::now();
+                system.tick(sim_delta, &mut ctx);
+                self.metrics.record_phase(tick_phase, phase_start);
+            }
+        }
+
+        // 5. Telemetry
+        self.metrics.tick.record_since(tick_start);
+        self.update_metrics(delta);
+    }
+
+    /// Refresh queue depths, publish for FFI, and emit the periodic MetricsReport
+    fn update_metrics(&mut self, delta: f64) {
+        self.metrics.tick_count = self.tick_count;
+        self.metrics.request_queue = self.request_rx.len();
+        self.metrics.event_queue = self.event_tx.len();
+        for system in &self.systems {
+            system.report_metrics(&mut self.metrics);
+        }
+
+        if let Ok(mut shared) = self.shared_metrics.lock() {
+            shared.clone_from(&self.metrics);
+        }
+
+        // Wall-clock timings differ run to run, so never put them in a replay
+        if self.metrics_interval <= 0.0 || self.deterministic {
+            return;
+        }
+        self.metrics_timer += delta;
+        if self.metrics_timer >= self.metrics_interval {
+            self.metrics_timer = 0.0;
+            let _ = self.event_tx.send(GameEvent::MetricsReport {
+                metrics: Box::new(self.metrics.clone()),
+            });
+        }
+    }
+
+    /// Latest metrics (same data FFI reads from ACTOR_METRICS)
+    pub fn metrics(&self) -> &ActorMetrics {
+        &self.metrics
+    }
+
+    /// Start recording a replay from the next tick
+    /// Switches to deterministic mode so live results match a seeded replay Actor
+    fn start_replay_recording(&mut self, seed: u64) {
+        self.mode_before_replay.get_or_insert(self.deterministic);
+        self.deterministic = true;
+        self.rng = StdRng::seed_from_u64(seed);
+        for system in &mut self.systems {
+            system.reset_for_replay();
+        }
+        self.event_tx.start_recording(seed);
+        self.record_clock = true;
+        sim_print!("Actor: Replay recording started (seed={})", seed);
+    }
+
+    /// Stop recording and write the replay file
+    /// A live Actor goes back to its workers
+    fn stop_replay_recording(&mut self, path: String) {
+        let Some(replay) = self.event_tx.stop_recording() else {
+            let _ = self.event_tx.send(GameEvent::ReplayFailed {
+                error: "Replay recording was not started".to_string(),
+            });
+            return;
+        };
+
+        if self.mode_before_replay.take() == Some(false) {
+            self.deterministic = false;
+            let mut ctx = SystemContext::new(&self.world, &self.event_tx, &mut self.rng, self.deterministic, &mut self.follow_ups);
+            for system in &mut self.systems {
+                system.resume_workers(&mut ctx);
+            }
+        }
+
+        let ticks = replay.ticks.len() as u64;
+        match replay.save(&path) {
+            Ok(()) => {
+                sim_print!("Actor: Replay saved to {} ({} ticks)", path, ticks);
+                let _ = self.event_tx.send(GameEvent::ReplaySaved { path, ticks });
+            }
+            Err(e) => {
+                sim_error!("Actor: Failed to save replay to {}: {}", path, e);
+                let _ = self.event_tx.send(GameEvent::ReplayFailed { error: e.to_string() });
+            }
+        }
+    }
+
+    /// Write a snapshot of all Actor-owned state
+    fn save_game(&mut self, path: String) {
+        match self.snapshot().save(&path) {
+            Ok(()) => {
+                sim_print!("Actor: Game saved to {}", path);
+                let _ = self.event_tx.send(GameEvent::GameSaved { path });
+            }
+            Err(e) => {
+                sim_error!("Actor: Failed to save game to {}: {}", path, e);
+                let _ = self.event_tx.send(GameEvent::SaveFailed { path, error: e.to_string() });
+            }
+        }
+    }
+
+    /// Replace all Actor-owned state from a save file
+    fn load_game(&mut self, path: String) {
+        let loaded = GameSnapshot::load(&path).and_then(|snapshot| {
+            let counts = (snapshot.entities.len(), snapshot.cards.len());
+            self.restore(snapshot).map(|()| counts)
+        });
+
+        match loaded {
+            Ok((entity_count, card_count)) => {
+                sim_print!("Actor: Game loaded from {}", path);
+                let _ = self.event_tx.send(GameEvent::GameLoaded {
+                    path,
+                    entity_count,
+                    card_count,
+                });
+            }
+            Err(e) => {
+                sim_error!("Actor: Failed to load game from {}: {}", path, e);
+                let _ = self.event_tx.send(GameEvent::LoadFailed { path, error: e.to_string() });
+            }
+        }
+    }
+
+    /// Capture all Actor-owned state (sorted so identical state gives identical saves)
+    pub fn snapshot(&self) -> GameSnapshot {
+        let mut snapshot = GameSnapshot {
+            turn_mode: self.turn_mode,
+            turn: self.turn,
+            ..GameSnapshot::default()
+        };
+        for system in &self.systems {
+            system.snapshot(&self.world, &mut snapshot);
+        }
+        snapshot
+    }
+
+    /// Restore a snapshot all-or-nothing
+    /// Every system validates before any live state is touched
+    pub fn restore(&mut self, snapshot: GameSnapshot) -> Result<(), SaveError> {
+        for system in &self.systems {
+            system.validate(&snapshot)?;
+        }
+
+        {
+            let mut ctx = SystemContext::new(&self.world, &self.event_tx, &mut self.rng, self.deterministic, &mut self.follow_ups);
+            for system in &mut self.systems {
+                system.restore(&snapshot, &mut ctx);
+            }
+        }
+
+        self.turn = snapshot.turn;
+        if snapshot.turn_mode != self.turn_mode {
+            self.set_turn_mode(snapshot.turn_mode);
+        }
+
+        Ok(())
+    }
+
+    /// Pause/speed/step the simulation clock and report its new state
+    fn apply_clock_request(&mut self, request: &GameRequest) {
+        match *request {
+            GameRequest::SetSimulationSpeed { speed } => self.clock.set_speed(speed),
+            GameRequest::SetSimulationPaused { paused } => self.clock.set_paused(paused),
+            GameRequest::StepSimulation { ticks } => self.clock.step(ticks),
+            _ => return,
+        }
+
+        let _ = self.event_tx.send(GameEvent::SimulationClockChanged {
+            paused: self.clock.paused(),
+            speed: self.clock.speed(),
+        });
+    }
+
+    /// Switch between real-time ticks and turn mode
+    fn set_turn_mode(&mut self, enabled: bool) {
+        if self.turn_mode != enabled {
+            self.turn_mode = enabled;
+            let mut ctx = SystemContext::new(&self.world, &self.event_tx, &mut self.rng, self.deterministic, &mut self.follow_ups);
+            for system in &mut self.systems {
+                system.set_turn_mode(enabled, &mut ctx);
+            }
+            sim_print!("Actor: Turn mode {} (turn {})", if enabled { "on" } else { "off" }, self.turn);
+        }
+
+        let _ = self.event_tx.send(GameEvent::TurnModeChanged { enabled, turn: self.turn });
+    }
+
+    /// Run every turn phase in order across all systems, then report the turn
+    fn step_turn(&mut self) {
+        self.turn += 1;
+        let mut summary = TurnSummary {
+            turn: self.turn,
+            ..TurnSummary::default()
+        };
+
+        for phase in TurnPhase::ORDER {
+            {
+                let mut ctx = SystemContext::new(&self.world, &self.event_tx, &mut self.rng, self.deterministic, &mut self.follow_ups);
+                for system in &mut self.systems {
+                    system.turn_phase(phase, &mut summary, &mut ctx);
+                }
+            }
+
+            // Requests queued during the phase (loot/combo resources, kiting paths) land before the next one
+            for request in std::mem::take(&mut self.follow_ups) {
+                self.route_request(request);
+            }
+        }
+
+        sim_print!("Actor: Turn {} completed ({} attacks, {} deaths)", self.turn, summary.attacks, summary.deaths.len());
+        let _ = self.event_tx.send(GameEvent::TurnCompleted {
+            summary: Box::new(summary),
+        });
+    }
+
+    /// Stop every system's workers (joins them) and end the run loop
+    fn shutdown(&mut self) {
+        for system in &mut self.systems {
+            system.shutdown();
+        }
+        self.running = false;
+        sim_print!("Actor: Shut down after {} ticks", self.tick_count);
+    }
+
+    /// Process incoming requests from Godot (via channel)
+    fn process_requests(&mut self) {
+        let mut batch = Vec::new();
+
+        // Drain all available requests (non-blocking)
+        while let Ok(request) = self.request_rx.try_recv() {
+            // Replay control requests are never part of the recording itself
+            if !matches!(request, GameRequest::StopReplayRecording { .. } | GameRequest::Shutdown) {
+                self.event_tx.record_request(&request);
+            }
+
+            // Actor-level requests see every earlier request applied first
+            if matches!(request,
+                GameRequest::StartReplayRecording { .. }
+                | GameRequest::StopReplayRecording { .. }
+                | GameRequest::SaveGame { .. }
+                | GameRequest::LoadGame { .. }
+                | GameRequest::SetMetricsInterval { .. }
+                | GameRequest::SetTurnMode { .. }
+                | GameRequest::SetSimulationSpeed { .. }
+                | GameRequest::SetSimulationPaused { .. }
+                | GameRequest::StepSimulation { .. }
+                | GameRequest::StepTurn
+                | GameRequest::Shutdown
+            ) {
+                self.route_batch(std::mem::take(&mut batch));
+            }
+
+            match request {
+                GameRequest::StartReplayRecording { seed } => {
+                    self.start_replay_recording(seed);
+                    // Leave remaining requests for the first recorded tick
+                    break;
+                }
+
+                GameRequest::StopReplayRecording { path } => {
+                    self.stop_replay_recording(path);
+                }
+
+                GameRequest::SaveGame { path } => {
+                    self.save_game(path);
+                }
+
+                GameRequest::LoadGame { path } => {
+                    self.load_game(path);
+                }
+
+                GameRequest::SetMetricsInterval { interval_secs } => {
+                    self.metrics_interval = interval_secs.max(0.0);
+                    self.metrics_timer = 0.0;
+                }
+
+                GameRequest::SetSimulationSpeed { .. }
+                | GameRequest::SetSimulationPaused { .. }
+                | GameRequest::StepSimulation { .. } => {
+                    self.apply_clock_request(&request);
+                }
+
+                GameRequest::SetTurnMode { enabled } => {
+                    self.set_turn_mode(enabled);
+                }
+
+                GameRequest::StepTurn => {
+                    if self.turn_mode {
+                        self.step_turn();
+                    } else {
+                        sim_warn!("Actor: StepTurn ignored - turn mode is off");
+                        let _ = self.event_tx.send(GameRequest::StepTurn.rejected("turn mode is off"));
+                    }
+                }
+
+                GameRequest::Shutdown => {
+                    self.shutdown();
+                    break;
+                }
+
+                other => batch.push(other),
+            }
+        }
+
+        self.route_batch(batch);
+    }
+
+    /// Route a batch of requests, keeping only the newest Coalesce/Supersede request per ULID
+    /// The kept request stays at the position of the newest one, so ordering with other requests holds
+    fn route_batch(&mut self, batch: Vec<GameRequest>) {
+        if batch.is_empty() {
+            return;
+        }
+
+        let key = |request: &GameRequest| -> Option<(Discriminant<GameRequest>, Vec<u8>)> {
+            if request.queue_policy() == QueuePolicy::Reject {
+                return None;
+            }
+            request.ulid().map(|ulid| (std::mem::discriminant(request), ulid.to_vec()))
+        };
+
+        let mut newest = HashMap::new();
+        for (index, request) in batch.iter().enumerate() {
+            if let Some(key) = key(request) {
+                newest.insert(key, index);
+            }
+        }
+
+        for (index, request) in batch.into_iter().enumerate() {
+            let superseded = key(&request).is_some_and(|key| newest.get(&key) != Some(&index));
+            if superseded {
+                self.metrics.coalesced_requests += 1;
+                continue;
+            }
+            self.route_request(request);
+        }
+    }
+
+    /// Offer a request to each system in order until one handles it
+    fn route_request(&mut self, request: GameRequest) {
+        let mut ctx = SystemContext::new(&self.world, &self.event_tx, &mut self.rng, self.deterministic, &mut self.follow_ups);
+        let mut pending = Some(request);
+        for system in &mut self.systems {
+            let Some(request) = pending.take() else {
+                return;
+            };
+            pending = system.handle_request(request, &mut ctx);
+        }
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // GameRequest::NetworkConnect { url } => {
+        //     // Initialize network worker if not already started
+        //     if self.network_worker.is_none() {
+        //         let config = NetworkWorkerConfig::default();
+        //         self.network_worker = Some(start_network_worker(config));
+        //     }
+
+        //     // Send connect request to network worker
+        //     if let Some(ref worker) = self.network_worker {
+        //         worker.connect(url);
+        //     }
+        // }
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // GameRequest::NetworkSend { message } => {
+        //     if let Some(ref worker) = self.network_worker {
+        //         worker.send_message(message);
+        //     } else {
+        //         // Network worker not initialized
+        //         let _ = self.event_tx.send(GameEvent::NetworkError {
+        //             message: "Network worker not initialized".to_string(),
+        //         });
+        //     }
+        // }
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // GameRequest::NetworkDisconnect => {
+        //     if let Some(ref worker) = self.network_worker {
+        //         worker.disconnect();
+        //     }
+        // }
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // GameRequest::IrcConnect { player_name } => {
+        //     sim_print!("[IRC] Received IrcConnect request for player: {}", player_name);
+
+        //     // Create network worker and IRC client
+        //     sim_print!("[IRC] Starting network worker...");
+        //     let worker_config = NetworkWorkerConfig::default();
+        //     let worker_handle = start_network_worker(worker_config);
+
+        //     sim_print!("[IRC] Creating IRC client...");
+        //     let config = IrcConfig::cityvote(player_name);
+        //     sim_print!("[IRC] Connecting to: {}", config.url);
+        //     let mut client = IrcClient::new(config, worker_handle);
+        //     client.connect();
+        //     self.irc_client = Some(client);
+        //     sim_print!("[IRC] IRC client created and connecting...");
+        // }
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // GameRequest::IrcSendMessage { message } => {
+        //     if let Some(ref mut client) = self.irc_client {
+        //         client.send_channel_message(&message);
+        //     } else {
+        //         let _ = self.event_tx.send(GameEvent::IrcError {
+        //             message: "IRC not connected".to_string(),
+        //         });
+        //     }
+        // }
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // GameRequest::IrcJoinChannel { channel } => {
+        //     if let Some(ref mut client) = self.irc_client {
+        //         client.join_channel(&channel);
+        //     }
+        // }
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // GameRequest::IrcLeaveChannel { channel, message } => {
+        //     if let Some(ref mut client) = self.irc_client {
+        //         client.leave_channel(&channel, message.as_deref());
+        //     }
+        // }
+
+        // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+        // GameRequest::IrcDisconnect { message } => {
+        //     if let Some(ref mut client) = self.irc_client {
+        //         client.disconnect(message.as_deref());
+        //         self.irc_client = None;
+        //     }
+        // }
+
+        if let Some(request) = pending {
+            // Unhandled requests (deprecated network/IRC requests)
+            sim_warn!("Actor: No system handled request {:?}", request);
+        }
+    }
+
+    // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+    // /// Collect network worker responses
+    // fn collect_network_results(&mut self) {
+    //     if let Some(ref worker) = self.network_worker {
+    //         while let Some(response) = worker.try_recv() {
+    //             match response {
+    //                 NetworkWorkerResponse::Connected => {
+    //                     let _ = self.event_tx.send(GameEvent::NetworkConnected {
+    //                         session_id: "connected".to_string(), // TODO: Get actual session ID
+    //                     });
+    //                 }
+    //                 NetworkWorkerResponse::ConnectionFailed { error } => {
+    //                     let _ = self.event_tx.send(GameEvent::NetworkConnectionFailed {
+    //                         error,
+    //                     });
+    //                 }
+    //                 NetworkWorkerResponse::Disconnected => {
+    //                     let _ = self.event_tx.send(GameEvent::NetworkDisconnected);
+    //                 }
+    //                 NetworkWorkerResponse::MessageReceived { data } => {
+    //                     let _ = self.event_tx.send(GameEvent::NetworkMessageReceived {
+    //                         data,
+    //                     });
+    //                 }
+    //                 NetworkWorkerResponse::TextReceived { text } => {
+    //                     // Convert text to bytes for consistency
+    //                     let _ = self.event_tx.send(GameEvent::NetworkMessageReceived {
+    //                         data: text.into_bytes(),
+    //                     });
+    //                 }
+    //                 NetworkWorkerResponse::Error { message } => {
+    //                     let _ = self.event_tx.send(GameEvent::NetworkError {
+    //                         message,
+    //                     });
+    //                 }
+    //                 NetworkWorkerResponse::StateChanged { .. } => {
+    //                     // Could emit state change events if needed
+    //                 }
+    //             }
+    //         }
+    //     }
+    // }
+
+    // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
+    // /// Collect IRC events
+    // fn collect_irc_events(&mut self) {
+    //     // Process IRC client if it exists
+    //     if let Some(ref mut client) = self.irc_client {
+    //         // Process network events (must be called regularly)
+    //         client.process();
+
+    //         // Collect IRC events
+    //         while let Some(event) = client.try_recv_event() {
+    //             match event {
+    //                 IrcEvent::Connected { ref nickname, ref server } => {
+    //                     let _ = self.event_tx.send(GameEvent::IrcConnected {
+    //                         nickname: nickname.clone(),
+    //                         server: server.clone(),
+    //                     });
+    //                 }
+    //                 IrcEvent::Disconnected { ref reason } => {
+    //                     let _ = self.event_tx.send(GameEvent::IrcDisconnected {
+    //                         reason: reason.clone(),
+    //                     });
+    //                 }
+    //                 IrcEvent::Joined { ref channel, ref nickname } => {
+    //                     // Ensure channel history exists
+    //                     self.chat_history
+    //                         .entry(channel.clone())
+    //                         .or_insert_with(|| ChannelHistory::new(channel, 500));
+
+    //                     // Add system message
+    //                     if let Some(mut history) = self.chat_history.get_mut(channel) {
+    //                         history.add_message(ChatMessage::system(format!("{} joined", nickname)));
+    //                     }
+
+    //                     let _ = self.event_tx.send(GameEvent::IrcJoinedChannel {
+    //                         channel: channel.clone(),
+    //                         nickname: nickname.clone(),
+    //                     });
+    //                 }
+    //                 IrcEvent::Parted { ref channel, ref nickname, ref message } => {
+    //                     // Add system message
+    //                     if let Some(mut history) = self.chat_history.get_mut(channel) {
+    //                         let msg = if let Some(m) = message {
+    //                             format!("{} left ({})", nickname, m)
+    //                         } else {
+    //                             format!("{} left", nickname)
+    //                         };
+    //                         history.add_message(ChatMessage::system(msg));
+    //                     }
+
+    //                     let _ = self.event_tx.send(GameEvent::IrcLeftChannel {
+    //                         channel: channel.clone(),
+    //                         nickname: nickname.clone(),
+    //                         message: message.clone(),
+    //                     });
+    //                 }
+    //                 IrcEvent::ChannelMessage { ref channel, ref sender, ref message } => {
+    //                     // Store message in chat history
+    //                     if let Some(mut history) = self.chat_history.get_mut(channel) {
+    //                         history.add_message(ChatMessage::channel(sender, message));
+    //                     }
+
+    //                     let _ = self.event_tx.send(GameEvent::IrcChannelMessage {
+    //                         channel: channel.clone(),
+    //                         sender: sender.clone(),
+    //                         message: message.clone(),
+    //                     });
+    //                 }
+    //                 IrcEvent::PrivateMessage { ref sender, ref message } => {
+    //                     // Store in private message "channel" (use sender as key)
+    //                     let pm_channel = format!("PM:{}", sender);
+    //                     self.chat_history
+    //                         .entry(pm_channel.clone())
+    //                         .or_insert_with(|| ChannelHistory::new(&pm_channel, 500));
+
+    //                     if let Some(mut history) = self.chat_history.get_mut(&pm_channel) {
+    //                         history.add_message(ChatMessage::private(sender, message));
+    //                     }
+
+    //                     let _ = self.event_tx.send(GameEvent::IrcPrivateMessage {
+    //                         sender: sender.clone(),
+    //                         message: message.clone(),
+    //                     });
+    //                 }
+    //                 IrcEvent::UserJoined { ref channel, ref nickname } => {
+    //                     // Add system message
+    //                     if let Some(mut history) = self.chat_history.get_mut(channel) {
+    //                         history.add_message(ChatMessage::system(format!("{} joined", nickname)));
+    //                     }
+
+    //                     let _ = self.event_tx.send(GameEvent::IrcUserJoined {
+    //                         channel: channel.clone(),
+    //                         nickname: nickname.clone(),
+    //                     });
+    //                 }
+    //                 IrcEvent::UserParted { ref channel, ref nickname, ref message } => {
+    //                     // Add system message
+    //                     if let Some(mut history) = self.chat_history.get_mut(channel) {
+    //                         let msg = if let Some(m) = message {
+    //                             format!("{} left ({})", nickname, m)
+    //                         } else {
+    //                             format!("{} left", nickname)
+    //                         };
+    //                         history.add_message(ChatMessage::system(msg));
+    //                     }
+
+    //                     let _ = self.event_tx.send(GameEvent::IrcUserLeft {
+    //                         channel: channel.clone(),
+    //                         nickname: nickname.clone(),
+    //                         message: message.clone(),
+    //                     });
+    //                 }
+    //                 IrcEvent::UserQuit { ref nickname, ref message } => {
+    //                     // Add to all channels (simplified - normally track user presence per channel)
+    //                     let quit_msg = if let Some(m) = message {
+    //                         format!("{} quit ({})", nickname, m)
+    //                     } else {
+    //                         format!("{} quit", nickname)
+    //                     };
+
+    //                     for mut history in self.chat_history.iter_mut() {
+    //                         history.add_message(ChatMessage::system(&quit_msg));
+    //                     }
+
+    //                     let _ = self.event_tx.send(GameEvent::IrcUserQuit {
+    //                         nickname: nickname.clone(),
+    //                         message: message.clone(),
+    //                     });
+    //                 }
+    //                 IrcEvent::Error { ref message } => {
+    //                     let _ = self.event_tx.send(GameEvent::IrcError {
+    //                         message: message.clone(),
+    //                     });
+    //                 }
+    //                 _ => {
+    //                     // Unhandled IRC events
+    //                 }
+    //             }
+    //         }
+    //     }
+    // }
+
+    /// Run the Actor's main loop (called on dedicated thread)
+    fn run(mut self) {
+        const TICK_RATE: Duration = Duration::from_millis(16); // ~60 ticks/sec
+
+        let mut last_tick = Instant::now();
+
+        while self.running {
+            let tick_start = Instant::now();
+
+            // Process one tick with the real elapsed time (covers overrun ticks)
+            let delta = tick_start.duration_since(last_tick).as_secs_f64().max(TICK_RATE.as_secs_f64());
+            last_tick = tick_start;
+            self.tick(delta);
+
+            // Sleep for remainder of tick interval
+            let elapsed = tick_start.elapsed();
+            if elapsed < TICK_RATE {
+                thread::sleep(TICK_RATE - elapsed);
+            }
+        }
+    }
+}
+
+/// Spawn the Actor on a dedicated thread
+/// This is the TRUE ACTOR PATTERN - zero shared state, no lock contention
+/// Uses crossbeam_channel for proper Actor communication
+/// The thread runs until GameRequest::Shutdown; join the handle after sending it
+pub fn spawn_actor_thread(
+    request_rx: Receiver<GameRequest>,
+    event_tx: Sender<GameEvent>,
+) -> std::io::Result<thread::JoinHandle<()>> {
+    thread::Builder::new()
+        .name("game-actor".to_string())
+        .spawn(move || {
+            let actor = GameActor::new(request_rx, event_tx);
+            actor.run(); // Run until shutdown on this dedicated thread
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossbeam_channel::unbounded;
+    use crate::types::ForecastHorizon;
+
+    fn headless_actor() -> (GameActor, Sender<GameRequest>, Receiver<GameEvent>) {
+        let (request_tx, request_rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+        let actor = GameActor::new(request_rx, event_tx);
+        (actor, request_tx, event_rx)
+    }
+
+    /// Deterministic Actor with its own stats and resources (seed 1)
+    fn seeded_actor() -> (GameActor, Sender<GameRequest>, Receiver<GameEvent>) {
+        let (request_tx, request_rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+        let actor = GameActor::with_seed(request_rx, event_tx, 1);
+        (actor, request_tx, event_rx)
+    }
+
+    /// Gold in the pool of player [1; 16]
+    fn gold(actor: &GameActor) -> Option<f64> {
+        actor.snapshot().resources.iter()
+            .find(|r| r.player_ulid == vec![1; 16] && r.resource_type == 0)
+            .map(|r| r.current)
+    }
+
+    /// Register a melee unit with 1 hex of reach that picks fights within `aggro_range` hexes
+    fn register_unit(
+        request_tx: &Sender<GameRequest>,
+        ulid: &[u8],
+        player_ulid: &[u8],
+        entity_type: &str,
+        position: (i32, i32),
+        aggro_range: i32,
+    ) {
+        request_tx.send(GameRequest::RegisterEntityStats {
+            ulid: ulid.to_vec(),
+            player_ulid: player_ulid.to_vec(),
+            entity_type: entity_type.to_string(),
+            terrain_type: 1,
+            position,
+            combat_type: 1,
+            projectile_type: 0,
+            combat_range: 1,
+            aggro_range,
+        }).unwrap();
+    }
+
+    #[test]
+    fn test_initial_resources_emitted() {
+        let (_actor, _request_tx, event_rx) = headless_actor();
+        let resources: Vec<i64> = event_rx
+            .try_iter()
+            .filter_map(|event| match event {
+                GameEvent::ResourceChanged { resource_type, .. } => Some(resource_type),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(resources.len(), 4);
+    }
+
+    #[test]
+    fn test_add_resources_go_to_the_named_player() {
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        event_rx.try_iter().for_each(drop);
+
+        let player = vec![1; 16];
+        request_tx.send(GameRequest::AddResources { player_ulid: player.clone(), resource_type: 0, amount: 50.0 }).unwrap();
+        actor.tick(0.016);
+
+        // The player's pool starts with defaults; the AI/neutral pool is untouched
+        let gold: Vec<(Vec<u8>, f64)> = event_rx.try_iter().filter_map(|event| match event {
+            GameEvent::ResourceChanged { player_ulid, resource_type: 0, current, .. } => Some((player_ulid, current)),
+            _ => None,
+        }).collect();
+        assert_eq!(gold, vec![(player.clone(), 1000.0), (player, 1050.0)]);
+        assert_eq!(actor.snapshot().resources.iter().find(|r| r.player_ulid.is_empty() && r.resource_type == 0).map(|r| r.current), Some(1000.0));
+    }
+
+    #[test]
+    fn test_transactions_are_atomic_and_roll_back() {
+        use crate::types::TransactionReason;
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let player = vec![1; 16];
+        request_tx.send(GameRequest::ApplyTransaction {
+            request_id: 1, player_ulid: player.clone(), reason: TransactionReason::Construction,
+            changes: vec![(0, -300.0), (2, -50.0)],
+        }).unwrap();
+        request_tx.send(GameRequest::ApplyTransaction {
+            request_id: 2, player_ulid: player.clone(), reason: TransactionReason::Construction,
+            changes: vec![(0, -100.0), (1, -5000.0)],
+        }).unwrap();
+        actor.tick(0.016);
+
+        // The second transaction can't cover food, so its gold is not spent either
+        let events: Vec<GameEvent> = event_rx.try_iter().collect();
+        let transaction_id = events.iter().find_map(|e| match e {
+            GameEvent::TransactionApplied { request_id: 1, entry } => Some(entry.transaction_id),
+            _ => None,
+        }).unwrap();
+        assert!(events.iter().any(|e| matches!(e,
+            GameEvent::TransactionRejected { request_id: 2, shortfall, .. } if shortfall == &vec![(1, 4000.0)])));
+        assert_eq!(gold(&actor), Some(700.0));
+
+        request_tx.send(GameRequest::RollbackTransaction { request_id: 3, transaction_id }).unwrap();
+        request_tx.send(GameRequest::RollbackTransaction { request_id: 4, transaction_id }).unwrap();
+        request_tx.send(GameRequest::QueryLedger { request_id: 5, player_ulid: Some(player), reason: None, limit: 0 }).unwrap();
+        actor.tick(0.016);
+
+        let events: Vec<GameEvent> = event_rx.try_iter().collect();
+        assert_eq!(gold(&actor), Some(1000.0));
+        assert!(events.iter().any(|e| matches!(e, GameEvent::TransactionRejected { request_id: 4, .. })));
+        let entries = events.iter().find_map(|e| match e {
+            GameEvent::LedgerReply { request_id: 5, entries } => Some(entries.clone()),
+            _ => None,
+        }).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].rolled_back);
+        assert_eq!((entries[1].reason, entries[1].reverts), (TransactionReason::Rollback, Some(transaction_id)));
+        assert_eq!(entries[1].changes, vec![(0, 300.0), (2, 50.0)]);
+    }
+
+    #[test]
+    fn test_production_adds_to_pools_changed_while_the_job_ran() {
+        let (mut actor, request_tx, _event_rx) = seeded_actor();
+        let player = vec![1; 16];
+        request_tx.send(GameRequest::RegisterProducer {
+            ulid: vec![9; 16], player_ulid: player.clone(), resource_type: 0, rate_per_sec: 10.0, active: true,
+        }).unwrap();
+        actor.tick(1.0);  // One second of production in flight
+
+        // The spend lands before the production result; the result must not undo it
+        request_tx.send(GameRequest::SpendResources { player_ulid: player, cost: vec![(0, 300.0)] }).unwrap();
+        actor.tick(0.0);
+        assert_eq!(gold(&actor), Some(710.0));
+    }
+
+    #[test]
+    fn test_ledger_matches_pool_when_transactions_land_mid_production() {
+        use crate::types::TransactionReason;
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let player = vec![1; 16];
+        let ledgered_gold = |actor: &GameActor| -> f64 {
+            actor.snapshot().ledger.iter()
+                .flat_map(|entry| entry.changes.iter())
+                .filter(|(resource_type, _)| *resource_type == 0)
+                .map(|(_, amount)| amount)
+                .sum()
+        };
+
+        request_tx.send(GameRequest::RegisterProducer {
+            ulid: vec![9; 16], player_ulid: player.clone(), resource_type: 0, rate_per_sec: 10.0, active: true,
+        }).unwrap();
+
+        // A transaction and then its rollback, each accepted while a production job is in flight
+        actor.tick(1.0);
+        request_tx.send(GameRequest::ApplyTransaction {
+            request_id: 1, player_ulid: player.clone(), reason: TransactionReason::Construction,
+            changes: vec![(0, -300.0)],
+        }).unwrap();
+        actor.tick(0.0);
+        assert_eq!(gold(&actor), Some(1000.0 + 10.0 + ledgered_gold(&actor)));
+
+        let transaction_id = event_rx.try_iter().find_map(|e| match e {
+            GameEvent::TransactionApplied { request_id: 1, entry } => Some(entry.transaction_id),
+            _ => None,
+        }).unwrap();
+        actor.tick(1.0);
+        request_tx.send(GameRequest::RollbackTransaction { request_id: 2, transaction_id }).unwrap();
+        actor.tick(0.0);
+        assert_eq!(actor.snapshot().ledger.len(), 2);
+        assert_eq!(gold(&actor), Some(1000.0 + 20.0 + ledgered_gold(&actor)));
+        assert_eq!(gold(&actor), Some(1020.0));
+    }
+
+    #[test]
+    fn test_cap_amount_and_reset_update_shared_pools() {
+        let (mut actor, request_tx, _event_rx) = seeded_actor();
+        let player = vec![1; 16];
+        let shared = |actor: &GameActor, resource_type: i64| actor.world.resources
+            .get(&(vec![1; 16], resource_type))
+            .map(|r| (r.0, r.1));
+
+        request_tx.send(GameRequest::SetResourceCap { player_ulid: player.clone(), resource_type: 0, cap: 500.0 }).unwrap();
+        request_tx.send(GameRequest::SetResourceAmount { player_ulid: player.clone(), resource_type: 1, amount: 20000.0 }).unwrap();
+        actor.tick(0.016);
+        assert_eq!(shared(&actor, 0), Some((500.0, 500.0)));
+        assert_eq!(shared(&actor, 1), Some((10000.0, 10000.0)));
+
+        request_tx.send(GameRequest::ResetResources { player_ulid: player }).unwrap();
+        actor.tick(0.016);
+        assert_eq!(shared(&actor, 0), Some((1000.0, 10000.0)));
+        assert_eq!(shared(&actor, 1), Some((1000.0, 10000.0)));
+    }
+
+    #[test]
+    fn test_forecast_projects_flows_and_upkeep() {
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let player = vec![1; 16];
+
+        for id in [3, 4] {
+            register_unit(&request_tx, &[id; 16], &player, "viking", (id as i32, 2), 8);
+        }
+        request_tx.send(GameRequest::RegisterProducer { ulid: vec![5; 16], player_ulid: player.clone(), resource_type: 0, rate_per_sec: 5.0, active: true }).unwrap();
+        request_tx.send(GameRequest::RegisterConsumer { ulid: vec![6; 16], player_ulid: player.clone(), resource_type: 1, rate_per_sec: 10.0, active: true }).unwrap();
+        request_tx.send(GameRequest::QueryForecast { request_id: 9, player_ulid: player, horizon: ForecastHorizon::Seconds(120.0) }).unwrap();
+        actor.tick(0.016);
+
+        let (horizon_secs, resources) = event_rx.try_iter()
+            .find_map(|event| match event {
+                GameEvent::ForecastReply { request_id: 9, horizon_secs, resources, .. } => Some((horizon_secs, resources)),
+                _ => None,
+            })
+            .expect("forecast reply");
+        assert_eq!(horizon_secs, 120.0);
+
+        // Gold: 1000 + 5/s, never near the cap
+        assert_eq!(resources[0].projected, 1600.0);
+        assert_eq!(resources[0].full_at, None);
+
+        // Food: 1000 - 10/s leaves 400 at the end of the first turn (60s), 2 are eaten, and the
+        // remaining 398 run out 39.8s into the next turn: empty at 99.8s
+        assert_eq!(resources[1].upkeep_per_turn, 2.0);
+        assert!((resources[1].empty_at.unwrap() - 99.8).abs() < 1e-9);
+        assert_eq!(resources[1].projected, 0.0);
+    }
+
+    #[test]
+    fn test_shortages_starve_units_pause_production_and_cost_reputation() {
+        use crate::entity::StatType;
+        use crate::types::ShortageEffect;
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let player = vec![1; 16];
+        let unit = vec![3; 16];
+
+        register_unit(&request_tx, &unit, &player, "viking", (2, 2), 8);
+        request_tx.send(GameRequest::RegisterProducer { ulid: vec![5; 16], player_ulid: player.clone(), resource_type: 0, rate_per_sec: 5.0, active: true }).unwrap();
+        request_tx.send(GameRequest::RegisterCity { city_id: 6, player_ulid: player.clone(), population: 0, wealth: 50.0, reputation: 10.0, tax_rate: 0.2 }).unwrap();
+        for resource_type in 1..=3 {
+            request_tx.send(GameRequest::SetResourceAmount { player_ulid: player.clone(), resource_type, amount: 0.0 }).unwrap();
+        }
+        request_tx.send(GameRequest::ProcessTurnConsumption).unwrap();
+        actor.tick(0.016);
+        actor.tick(1.0);
+
+        let shortages: Vec<(i64, ShortageEffect)> = event_rx.try_iter()
+            .filter_map(|event| match event {
+                GameEvent::ResourceShortage { resource_type, effect, .. } => Some((resource_type, effect)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(shortages, vec![
+            (1, ShortageEffect::Starvation { units: vec![unit.clone()], hp_loss: 5.0, morale_loss: 10.0 }),
+            (3, ShortageEffect::ReputationLoss { amount: 2.0 }),
+            (2, ShortageEffect::ProductionPaused { producers: vec![vec![5; 16]] }),
+        ]);
+        let stats = actor.world.entity_stats.get(&unit).unwrap().clone();
+        assert_eq!(stats.get(StatType::Morale), 90.0);
+        assert!(stats.get(StatType::HP) < stats.get(StatType::MaxHP));
+        assert_eq!(actor.world.resources.get(&(player.clone(), 0)).map(|r| r.0), Some(1000.0));  // Gold paused
+        assert_eq!(actor.snapshot().cities[0].reputation, 8.0);  // Faithless (taxed at the neutral rate)
+
+        // Fed again: the food shortage is over
+        request_tx.send(GameRequest::AddResources { player_ulid: player, resource_type: 1, amount: 10.0 }).unwrap();
+        request_tx.send(GameRequest::ProcessTurnConsumption).unwrap();
+        actor.tick(0.016);
+        assert!(event_rx.try_iter().any(|event| matches!(event, GameEvent::ShortageEnded { resource_type: 1, .. })));
+    }
+
+    #[test]
+    fn test_recipe_stalls_without_inputs_then_completes_cycles() {
+        use crate::types::{Recipe, RecipeStatus};
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let player = vec![1; 16];
+        let smithy = vec![7; 16];
+
+        // 10 food -> 25 gold every 2s, with no food in the pool yet
+        request_tx.send(GameRequest::SetResourceAmount { player_ulid: player.clone(), resource_type: 1, amount: 0.0 }).unwrap();
+        request_tx.send(GameRequest::RegisterRecipe {
+            ulid: smithy.clone(),
+            player_ulid: player.clone(),
+            recipe: Recipe { inputs: vec![(1, 4.0), (1, 6.0)], outputs: vec![(0, 25.0)], cycle_secs: 2.0 },
+            active: true,
+        }).unwrap();
+        actor.tick(1.0);
+        actor.tick(0.016);
+        assert!(event_rx.try_iter().any(|event| matches!(
+            event,
+            GameEvent::RecipeStatusChanged { status: RecipeStatus::Stalled { ref missing }, .. } if *missing == vec![(1, 10.0)]
+        )));
+
+        // Enough food for two cycles: both run, then it stalls again
+        request_tx.send(GameRequest::AddResources { player_ulid: player.clone(), resource_type: 1, amount: 20.0 }).unwrap();
+        actor.tick(0.016);
+        let gold = actor.world.resources.get(&(player.clone(), 0)).map(|r| r.0).unwrap();
+        for _ in 0..5 {
+            actor.tick(1.0);
+        }
+        assert_eq!(actor.world.resources.get(&(player.clone(), 0)).map(|r| r.0), Some(gold + 50.0));
+        assert_eq!(actor.world.resources.get(&(player.clone(), 1)).map(|r| r.0), Some(0.0));
+
+        request_tx.send(GameRequest::QueryRecipes { request_id: 9, player_ulid: player }).unwrap();
+        actor.tick(0.016);
+        let report = event_rx.try_iter()
+            .find_map(|event| match event {
+                GameEvent::RecipesReply { request_id: 9, recipes } => Some(recipes),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].cycles_completed, 2);
+        assert_eq!(report[0].recipe.inputs, vec![(1, 10.0)]);
+        assert!(matches!(report[0].status, RecipeStatus::Stalled { .. }));
+    }
+
+    #[test]
+    fn test_decay_spoils_food_drifts_faith_and_storage_protects() {
+        use crate::types::{LedgerEntry, TransactionReason};
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let (player, other) = (vec![1; 16], vec![2; 16]);
+        let amount = |actor: &GameActor, player: &Vec<u8>, resource_type: i64| {
+            actor.world.resources.get(&(player.clone(), resource_type)).map(|r| r.0).unwrap()
+        };
+
+        for pool in [&player, &other] {
+            request_tx.send(GameRequest::SetResourceAmount { player_ulid: pool.clone(), resource_type: 1, amount: 5000.0 }).unwrap();
+            request_tx.send(GameRequest::SetResourceAmount { player_ulid: pool.clone(), resource_type: 3, amount: 0.0 }).unwrap();
+        }
+        // A granary saving half the spoilage, for one player only
+        request_tx.send(GameRequest::RegisterStorage { ulid: vec![8; 16], player_ulid: player.clone(), protection: vec![(1, 0.5)] }).unwrap();
+        request_tx.send(GameRequest::ProcessTurnConsumption).unwrap();
+        actor.tick(0.016);
+
+        // Built-in rules: food loses 10% above 2000, faith drifts 5% towards 1000, gold keeps
+        assert_eq!(amount(&actor, &other, 1), 4700.0);
+        assert_eq!(amount(&actor, &player, 1), 4850.0);
+        assert_eq!(amount(&actor, &player, 3), 50.0);
+        assert_eq!(amount(&actor, &player, 0), 1000.0);
+        assert!(event_rx.try_iter().any(|event| matches!(
+            event,
+            GameEvent::TransactionApplied { entry: LedgerEntry { reason: TransactionReason::Decay, ref changes, .. }, .. }
+                if *changes == vec![(1, -150.0), (3, 50.0)]
+        )));
+
+        // Gone with its storage
+        request_tx.send(GameRequest::RemoveStorage { ulid: vec![8; 16] }).unwrap();
+        request_tx.send(GameRequest::ProcessTurnConsumption).unwrap();
+        actor.tick(0.016);
+        assert_eq!(amount(&actor, &player, 1), 4850.0 - 285.0);
+    }
+
+    #[test]
+    fn test_market_trades_move_prices_and_record_in_ledger() {
+        use crate::types::{LedgerEntry, TransactionReason};
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let player = vec![1; 16];
+        let amount = |actor: &GameActor, resource_type: i64| {
+            actor.world.resources.get(&(player.clone(), resource_type)).map(|r| r.0).unwrap()
+        };
+
+        request_tx.send(GameRequest::RegisterMarket { market_id: 4, trade_modifier: 0.0, wealth: 50.0 }).unwrap();
+        request_tx.send(GameRequest::Trade { request_id: 1, player_ulid: player.clone(), market_id: 4, sell: (1, 100.0), buy: 0 }).unwrap();
+        actor.tick(0.016);
+
+        // 100 food (0.5 each) buys a little under 50 gold after the fee
+        let bought = amount(&actor, 0) - 1000.0;
+        assert_eq!(amount(&actor, 1), 900.0);
+        assert!(bought > 40.0 && bought < 50.0);
+        let events: Vec<GameEvent> = event_rx.try_iter().collect();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            GameEvent::TransactionApplied { request_id: 1, entry: LedgerEntry { reason: TransactionReason::Trade, .. } }
+        )));
+        assert!(events.iter().any(|event| matches!(event, GameEvent::TradeCompleted { market_id: 4, sold: (1, 100.0), .. })));
+
+        // The market now has more food, so the same sale earns less
+        request_tx.send(GameRequest::Trade { request_id: 2, player_ulid: player.clone(), market_id: 4, sell: (1, 100.0), buy: 0 }).unwrap();
+        request_tx.send(GameRequest::QueryMarket { request_id: 3, market_id: 4 }).unwrap();
+        actor.tick(0.016);
+        assert!(amount(&actor, 0) - 1000.0 - bought < bought);
+        assert!(event_rx.try_iter().any(|event| matches!(
+            event,
+            GameEvent::MarketReply { request_id: 3, ref prices, .. }
+                if prices.iter().any(|p| p.resource_type == 1 && p.price < 0.5)
+        )));
+
+        // Unknown markets and sales the pool can't cover are rejected without touching the pool
+        request_tx.send(GameRequest::Trade { request_id: 4, player_ulid: player.clone(), market_id: 9, sell: (1, 10.0), buy: 0 }).unwrap();
+        request_tx.send(GameRequest::Trade { request_id: 5, player_ulid: player.clone(), market_id: 4, sell: (3, 5000.0), buy: 0 }).unwrap();
+        actor.tick(0.016);
+        let rejected: Vec<u64> = event_rx.try_iter()
+            .filter_map(|event| match event {
+                GameEvent::TransactionRejected { request_id, .. } => Some(request_id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(rejected, vec![4, 5]);
+        assert_eq!(amount(&actor, 1), 800.0);
+    }
+
+    #[test]
+    fn test_city_taxes_pay_gold_and_cost_reputation() {
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let player = vec![1; 16];
+
+        // 1000 people at average wealth taxed at 40%: 20 gold a turn at neutral reputation
+        request_tx.send(GameRequest::SetTurnMode { enabled: true }).unwrap();
+        request_tx.send(GameRequest::RegisterCity {
+            city_id: 4,
+            player_ulid: player.clone(),
+            population: 1000,
+            wealth: 50.0,
+            reputation: 0.0,
+            tax_rate: 0.4,
+        }).unwrap();
+        request_tx.send(GameRequest::StepTurn).unwrap();
+        actor.tick(0.016);
+
+        // Upkeep costs 2 reputation first, so production collects a little less
+        assert!(event_rx.try_iter().any(|event| matches!(
+            event,
+            GameEvent::CityTaxed { city_id: 4, reputation_delta, .. } if reputation_delta == -2.0
+        )));
+        let gold = actor.world.resources.get(&(player.clone(), 0)).map(|r| r.0).unwrap();
+        assert!((gold - (1000.0 + 20.0 * 0.99)).abs() < 1e-6);
+
+        // The host re-registering its stale reputation updates the terms but keeps the drift
+        request_tx.send(GameRequest::RegisterCity {
+            city_id: 4,
+            player_ulid: player.clone(),
+            population: 2000,
+            wealth: 50.0,
+            reputation: 0.0,
+            tax_rate: 0.4,
+        }).unwrap();
+        actor.tick(0.016);
+        let city = actor.snapshot().cities[0].clone();
+        assert_eq!((city.population, city.reputation), (2000, -2.0));
+
+        // Without the city the pool gets nothing
+        request_tx.send(GameRequest::RemoveCity { city_id: 4 }).unwrap();
+        request_tx.send(GameRequest::StepTurn).unwrap();
+        actor.tick(0.016);
+        assert_eq!(actor.world.resources.get(&(player.clone(), 0)).map(|r| r.0), Some(gold));
+        assert!(!event_rx.try_iter().any(|event| matches!(event, GameEvent::CityTaxed { .. })));
+    }
+
+    #[test]
+    fn test_status_effects_tick_expire_and_respect_immunity() {
+        use crate::entity::StatType;
+        use crate::types::StatusKind;
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let unit = vec![3; 16];
+
+        register_unit(&request_tx, &unit, &[], "viking", (0, 0), 8);
+        request_tx.send(GameRequest::SetStatusImmunities { ulid: unit.clone(), kinds: vec![StatusKind::Stun] }).unwrap();
+        for (kind, magnitude) in [(StatusKind::Stun, 0.0), (StatusKind::Poison, 2.0), (StatusKind::Poison, 2.0)] {
+            request_tx.send(GameRequest::ApplyStatus {
+                ulid: unit.clone(),
+                source_ulid: Vec::new(),
+                kind,
+                duration: 3.0,
+                magnitude,
+            }).unwrap();
+        }
+
+        // Poison stacks twice (the stun is shrugged off), then ticks 4 HP a second for 3 seconds
+        for _ in 0..8 {
+            actor.tick(0.5);
+        }
+        let events: Vec<GameEvent> = event_rx.try_iter().collect();
+        let applied: Vec<(StatusKind, u32)> = events.iter()
+            .filter_map(|e| match e {
+                GameEvent::StatusApplied { kind, stacks, .. } => Some((*kind, *stacks)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(applied, vec![(StatusKind::Poison, 1), (StatusKind::Poison, 2)]);
+        assert!(events.iter().any(|e| matches!(e, GameEvent::StatusExpired { kind: StatusKind::Poison, .. })));
+        assert_eq!(actor.world.entity_stats.get(&unit).unwrap().get(StatType::HP), 50.0 - 12.0);
+        assert!(actor.snapshot().status_effects.is_empty());
+    }
+
+    #[test]
+    fn test_fired_projectiles_carry_their_status_when_the_host_reports_the_hit() {
+        use crate::entity::ProjectileType;
+        use crate::types::StatusKind;
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let (mage, raider) = (vec![1; 16], vec![2; 16]);
+        request_tx.send(GameRequest::RegisterEntityStats {
+            ulid: mage.clone(),
+            player_ulid: vec![1; 16],
+            entity_type: "viking".to_string(),
+            terrain_type: 1,
+            position: (0, 0),
+            combat_type: 1 << 3,  // Magic
+            projectile_type: ProjectileType::FireBolt.to_u8(),
+            combat_range: 4,
+            aggro_range: 8,
+        }).unwrap();
+        register_unit(&request_tx, &raider, &[2; 16], "viking", (3, 0), 0);
+        for _ in 0..4 {
+            actor.tick(0.5);
+        }
+        actor.tick(0.0);  // Collect the last combat round
+
+        // What the host's combat manager does: fly the projectile, then hand its payload back on impact
+        let hit = event_rx.try_iter()
+            .find_map(|e| match e {
+                GameEvent::SpawnProjectile { attacker_ulid, target_ulid, target_position, projectile_type, damage, cover, .. } => {
+                    Some(GameRequest::ProjectileHit {
+                        attacker_ulid,
+                        defender_ulid: target_ulid,
+                        damage,
+                        projectile_type,
+                        impact_position: target_position,
+                        cover,
+                    })
+                }
+                _ => None,
+            })
+            .expect("the mage fires at the raider");
+        request_tx.send(hit).unwrap();
+        actor.tick(0.0);
+        actor.tick(0.5);  // The effect takes hold on the next combat round
+        actor.tick(0.0);
+
+        let events: Vec<GameEvent> = event_rx.try_iter().collect();
+        assert!(events.iter().any(|e| matches!(e, GameEvent::DamageDealt { defender_ulid, .. } if *defender_ulid == raider)));
+        assert!(events.iter().any(|e| matches!(e, GameEvent::StatusApplied { ulid, kind: StatusKind::Burn, .. } if *ulid == raider)));
+    }
+
+    #[test]
+    fn test_taunt_pulls_threat_driven_units() {
+        use crate::types::{CombatProfile, TargetingPolicy};
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let (guard, raider, tank) = (vec![1; 16], vec![2; 16], vec![3; 16]);
+
+        request_tx.send(GameRequest::SetCombatProfile {
+            entity_type: "guard".to_string(),
+            profile: CombatProfile { targeting: TargetingPolicy::HighestThreat, is_structure: false },
+        }).unwrap();
+        for (ulid, player, entity_type, position) in [
+            (&guard, vec![1; 16], "guard", (0, 0)),
+            (&raider, vec![2; 16], "viking", (1, 0)),
+            (&tank, vec![2; 16], "tank", (3, 0)),
+        ] {
+            register_unit(&request_tx, ulid, &player, entity_type, position, 8);
+        }
+        actor.tick(0.5);
+        actor.tick(0.0);  // Collect the combat round
+
+        // Closest enemy first, until the tank taunts
+        let started = |events: &[GameEvent]| -> Vec<Vec<u8>> {
+            events.iter()
+                .filter_map(|e| match e {
+                    GameEvent::CombatStarted { attacker_ulid, defender_ulid } if *attacker_ulid == guard => Some(defender_ulid.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(started(&event_rx.try_iter().collect::<Vec<_>>()), vec![raider.clone()]);
+
+        request_tx.send(GameRequest::Taunt { ulid: tank.clone(), radius: 5, threat: 50.0 }).unwrap();
+        actor.tick(0.5);
+        actor.tick(0.0);
+        assert_eq!(started(&event_rx.try_iter().collect::<Vec<_>>()), vec![tank.clone()]);
+        assert!(actor.snapshot().threat.iter().any(|(holder, enemy, _)| *holder == guard && *enemy == tank));
+    }
+
+    #[test]
+    fn test_projectiles_carry_the_cover_they_were_fired_through() {
+        use crate::entity::ProjectileType;
+        use crate::types::HitOutcome;
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let (archer, target) = (vec![1; 16], vec![2; 16]);
+        register_unit(&request_tx, &archer, &[1; 16], "viking", (0, 0), 0);
+        register_unit(&request_tx, &target, &[2; 16], "viking", (4, 0), 0);
+        actor.tick(0.0);
+        let _ = event_rx.try_iter().count();
+
+        // The same volley (of 1-damage hits the target survives) with and without the cover traced when it was fired
+        let dodged = |actor: &mut GameActor, cover: u32| -> usize {
+            for _ in 0..20 {
+                request_tx.send(GameRequest::ProjectileHit {
+                    attacker_ulid: archer.clone(),
+                    defender_ulid: target.clone(),
+                    damage: 0,
+                    projectile_type: ProjectileType::Arrow.to_u8(),
+                    impact_position: (4, 0),
+                    cover,
+                }).unwrap();
+            }
+            actor.tick(0.0);
+            event_rx.try_iter()
+                .filter(|e| matches!(e, GameEvent::DamageDealt { outcome: HitOutcome::Dodged, .. }))
+                .count()
+        };
+        let in_the_open = dodged(&mut actor, 0);
+        let behind_a_ridge = dodged(&mut actor, 3);
+        assert!(behind_a_ridge > in_the_open + 3, "{} dodges behind cover vs {} in the open", behind_a_ridge, in_the_open);
+    }
+
+    #[test]
+    fn test_fire_bolt_bursts_on_enemies_around_the_impact() {
+        use crate::entity::ProjectileType;
+        use crate::types::SplashProfile;
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        let (mage, target, raider, squire, far) = (vec![1; 16], vec![2; 16], vec![3; 16], vec![4; 16], vec![5; 16]);
+
+        for (ulid, player, position) in [
+            (&mage, vec![1; 16], (0, 0)),
+            (&target, vec![2; 16], (4, 0)),
+            (&raider, vec![2; 16], (5, 0)),
+            (&squire, vec![1; 16], (4, 1)),
+            (&far, vec![2; 16], (7, 0)),
+        ] {
+            register_unit(&request_tx, ulid, &player, "viking", position, 0);
+        }
+        let fire_bolt = |request_tx: &crossbeam_channel::Sender<GameRequest>| {
+            request_tx.send(GameRequest::ProjectileHit {
+                attacker_ulid: mage.clone(),
+                defender_ulid: target.clone(),
+                damage: 20,
+                projectile_type: ProjectileType::FireBolt.to_u8(),
+                impact_position: (4, 0),
+                cover: 0,
+            }).unwrap();
+        };
+        let hit = |events: Vec<GameEvent>| -> Vec<Vec<u8>> {
+            events.into_iter()
+                .filter_map(|e| match e {
+                    GameEvent::DamageDealt { defender_ulid, .. } => Some(defender_ulid),
+                    _ => None,
+                })
+                .collect()
+        };
+        actor.tick(0.0);
+        let _ = event_rx.try_iter().count();
+
+        // The target and the enemy beside it; the mage's squire and the far enemy are spared
+        fire_bolt(&request_tx);
+        actor.tick(0.0);
+        assert_eq!(hit(event_rx.try_iter().collect()), vec![target.clone(), raider.clone()]);
+
+        // With friendly fire the squire gets caught too
+        request_tx.send(GameRequest::SetProjectileSplash {
+            projectile_type: ProjectileType::FireBolt.to_u8(),
+            splash: SplashProfile { radius: 1, falloff: 0.5, friendly_fire: true },
+        }).unwrap();
+        fire_bolt(&request_tx);
+        actor.tick(0.0);
+        assert_eq!(hit(event_rx.try_iter().collect()), vec![target.clone(), raider.clone(), squire.clone()]);
+        assert!(actor.snapshot().projectile_splash[0].1.friendly_fire);
+    }
+
+    #[test]
+    fn test_save_load_restores_cards_and_stats() {
+        use crate::entity::StatType;
+
+        let path = std::env::temp_dir().join(format!("godo_save_test_{}.json", std::process::id()));
+        let path_str = path.to_string_lossy().to_string();
+        let ulid = vec![3; 16];
+
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        register_unit(&request_tx, &ulid, &[1; 16], "viking", (2, 2), 8);
+        request_tx.send(GameRequest::SetStat { ulid: ulid.clone(), stat_type: StatType::HP as i64, value: 42.0 }).unwrap();
+        request_tx.send(GameRequest::PlaceCard { x: 1, y: 1, ulid: vec![5; 16], player_ulid: vec![1; 16], suit: 0, value: 1, card_id: 0, is_custom: false }).unwrap();
+        request_tx.send(GameRequest::AddResources { player_ulid: vec![1; 16], resource_type: 1, amount: 5.0 }).unwrap();
+        request_tx.send(GameRequest::SaveGame { path: path_str.clone() }).unwrap();
+        actor.tick(0.016);
+        assert!(event_rx.try_iter().any(|e| matches!(e, GameEvent::GameSaved { .. })));
+
+        let (request_tx, request_rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+        let mut restored = GameActor::with_seed(request_rx, event_tx, 2);
+        request_tx.send(GameRequest::LoadGame { path: path_str }).unwrap();
+        restored.tick(0.016);
+        let _ = std::fs::remove_file(&path);
+
+        assert!(event_rx.try_iter().any(|e| matches!(e, GameEvent::GameLoaded { card_count: 1, .. })));
+        let snapshot = restored.snapshot();
+        assert_eq!(snapshot.cards.first().and_then(|card| card.position), Some((1, 1)));
+        assert_eq!(restored.world.entity_stats.get(&ulid).map(|s| s.get(StatType::HP)), Some(42.0));
+        assert_eq!(restored.world.entity_player_ulids.get(&ulid).map(|p| p.clone()), Some(vec![1; 16]));
+        assert_eq!(snapshot.resources.iter().find(|r| r.player_ulid == vec![1; 16] && r.resource_type == 1).map(|r| r.current), Some(1005.0));
+    }
+
+    #[test]
+    fn test_queries_reply_with_their_request_id() {
+        let (mut actor, request_tx, event_rx) = headless_actor();
+        event_rx.try_iter().for_each(drop);
+
+        request_tx.send(GameRequest::QueryCanAfford { request_id: 7, player_ulid: Vec::new(), cost: vec![(0, 400.0)] }).unwrap();
+        request_tx.send(GameRequest::QueryCanAfford { request_id: 8, player_ulid: Vec::new(), cost: vec![(0, 1500.0), (9, 1.0)] }).unwrap();
+        request_tx.send(GameRequest::GetStat { request_id: 9, ulid: vec![0; 16], stat_type: 0 }).unwrap();
+        actor.tick(0.016);
+
+        let events: Vec<GameEvent> = event_rx.try_iter().collect();
+        assert!(events.iter().any(|e| matches!(e,
+            GameEvent::CanAffordReply { request_id: 7, affordable: true, shortfall } if shortfall.is_empty())));
+        assert!(events.iter().any(|e| matches!(e,
+            GameEvent::CanAffordReply { request_id: 8, affordable: false, shortfall } if shortfall == &vec![(0, 500.0), (9, 1.0)])));
+        assert!(events.iter().any(|e| matches!(e, GameEvent::StatReply { request_id: 9, value: None, .. })));
+    }
+
+    #[test]
+    fn test_ensure_player_pool_creates_it_once() {
+        let (mut actor, request_tx, event_rx) = seeded_actor();
+        event_rx.try_iter().for_each(drop);
+        request_tx.send(GameRequest::EnsurePlayerPool { player_ulid: vec![1; 16] }).unwrap();
+        actor.tick(0.016);
+        assert_eq!(gold(&actor), Some(1000.0));
+        assert!(event_rx.try_iter().any(|e| matches!(e, GameEvent::ResourceChanged { .. })));
+
+        // An existing pool is left as it is
+        request_tx.send(GameRequest::SpendResources { player_ulid: vec![1; 16], cost: vec![(0, 100.0)] }).unwrap();
+        request_tx.send(GameRequest::EnsurePlayerPool { player_ulid: vec![1; 16] }).unwrap();
+        actor.tick(0.016);
+        assert_eq!(gold(&actor), Some(900.0));
+    }
+
+    #[test]
+    fn test_metrics_track_phases_and_path_latency() {
+        use crate::terrain_cache::TerrainType;
+
+        let (request_tx, request_rx) = unbounded();
+        let (event_tx, _event_rx) = unbounded();
+        let mut actor = GameActor::with_seed(request_rx, event_tx, 3);
+        request_tx.send(GameRequest::QueryPathCost { request_id: 1, terrain_type: TerrainType::Land, start: (0, 0), goal: (1, 0) }).unwrap();
+        actor.tick(0.016);
+
+        let metrics = actor.metrics();
+        assert_eq!(metrics.tick_count, 1);
+        assert_eq!(metrics.phases.len(), 2 + 2 * actor.systems.len());
+        assert!(metrics.phases.iter().all(|phase| phase.timing.count == 1));
+        assert_eq!(metrics.path_latency.count, 1);
+        assert!(metrics.worker_queues.iter().any(|queue| queue.worker == "pathfinding"));
+    }
+
+    #[test]
+    fn test_path_cost_queries_answered_while_paused() {
+        use crate::terrain_cache::TerrainType;
+
+        let (mut actor, request_tx, event_rx) = headless_actor();
+        request_tx.send(GameRequest::SetSimulationPaused { paused: true }).unwrap();
+        actor.tick(0.016);
+        event_rx.try_iter().for_each(drop);
+
+        // More than the pool queue holds, so some wait in the backlog
+        let queries = 1000;
+        for request_id in 0..queries {
+            request_tx.send(GameRequest::QueryPathCost { request_id, terrain_type: TerrainType::Land, start: (0, 0), goal: (3, 2) }).unwrap();
+        }
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut replies = 0;
+        while replies < queries && Instant::now() < deadline {
+            actor.tick(0.016);
+            replies += event_rx.try_iter().filter(|e| matches!(e, GameEvent::PathCostReply { .. })).count() as u64;
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(replies, queries);
+    }
+
+    #[test]
+    fn test_position_updates_coalesce_per_ulid() {
+        use crate::entity::{EntityData, TerrainType};
+
+        let (mut actor, request_tx, _event_rx) = headless_actor();
+        let ulid = vec![9; 16];
+        actor.world.entities.insert(ulid.clone(), EntityData::new(ulid.clone(), (0, 0), TerrainType::Land, "viking".to_string()));
+        for x in 1..=5 {
+            request_tx.send(GameRequest::UpdateEntityPosition { ulid: ulid.clone(), position: (x, 0) }).unwrap();
+        }
+        actor.tick(0.016);
+
+        assert_eq!(actor.world.entities.get(&ulid).unwrap().position, (5, 0));
+        assert_eq!(actor.metrics().coalesced_requests, 4);
+    }
+
+    #[test]
+    fn test_step_turn_runs_phases_and_reports() {
+        let (request_tx, request_rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+        let mut actor = GameActor::with_seed(request_rx, event_tx, 5);
+        for (byte, player_ulid, position) in [(1u8, vec![1; 16], (0, 0)), (2, Vec::new(), (1, 0))] {
+            register_unit(&request_tx, &[byte; 16], &player_ulid, "viking", position, 8);
+        }
+        request_tx.send(GameRequest::StepTurn).unwrap();
+        request_tx.send(GameRequest::SetTurnMode { enabled: true }).unwrap();
+        request_tx.send(GameRequest::StepTurn).unwrap();
+        actor.tick(0.016);
+
+        let events: Vec<GameEvent> = event_rx.try_iter().collect();
+        assert!(events.iter().any(|e| matches!(e, GameEvent::RequestRejected { kind, .. } if kind == "StepTurn")));
+        let summary = events.iter()
+            .find_map(|e| match e {
+                GameEvent::TurnCompleted { summary } => Some(summary),
+                _ => None,
+            })
+            .expect("turn completed");
+        assert_eq!(summary.turn, 1);
+        assert_eq!(summary.food_upkeep, 1.0);
+        assert!(summary.attacks > 0);
+        assert_eq!(actor.snapshot().turn, 1);
+    }
+
+    #[test]
+    fn test_paused_clock_is_replayed_from_recording_start() {
+        let path = std::env::temp_dir().join(format!("godo_clock_replay_{}.grpl", std::process::id()));
+        let path_str = path.to_string_lossy().to_string();
+
+        let (request_tx, request_rx) = unbounded();
+        let (event_tx, _event_rx) = unbounded();
+        let mut actor = GameActor::with_seed(request_rx, event_tx, 4);
+        request_tx.send(GameRequest::RegisterProducer { ulid: vec![4; 16], player_ulid: Vec::new(), resource_type: 0, rate_per_sec: 10.0, active: true }).unwrap();
+        request_tx.send(GameRequest::SetSimulationSpeed { speed: 2.0 }).unwrap();
+        request_tx.send(GameRequest::SetSimulationPaused { paused: true }).unwrap();
+        request_tx.send(GameRequest::StartReplayRecording { seed: 4 }).unwrap();
+        actor.tick(0.016);
+        for _ in 0..100 {
+            actor.tick(0.016);
+        }
+        request_tx.send(GameRequest::StepSimulation { ticks: 3 }).unwrap();
+        request_tx.send(GameRequest::StopReplayRecording { path: path_str.clone() }).unwrap();
+        actor.tick(0.016);
+
+        let replay = crate::replay::Replay::load(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        // Nothing was produced while paused, and a fresh Actor rebuilds the paused 2x clock
+        assert_eq!(actor.snapshot().resources[0].current, 1000.0);
+        assert_eq!(replay.ticks[0].requests.len(), 2);
+        assert!(crate::replay::verify(&replay).is_match());
+    }
+
+    #[test]
+    fn test_workers_resume_after_replay_recording() {
+        use crate::terrain_cache::TerrainType;
+
+        let path = std::env::temp_dir().join(format!("godo_resume_replay_{}.grpl", std::process::id()));
+        let (mut actor, request_tx, event_rx) = headless_actor();
+        request_tx.send(GameRequest::StartReplayRecording { seed: 6 }).unwrap();
+        actor.tick(0.016);
+        request_tx.send(GameRequest::StopReplayRecording { path: path.to_string_lossy().to_string() }).unwrap();
+        actor.tick(0.016);
+        let _ = std::fs::remove_file(&path);
+        assert!(!actor.deterministic);
+
+        // The pool answers on its own thread again, so the reply can take a few ticks
+        event_rx.try_iter().for_each(drop);
+        request_tx.send(GameRequest::QueryPathCost { request_id: 11, terrain_type: TerrainType::Land, start: (0, 0), goal: (1, 0) }).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut replied = false;
+        while !replied && Instant::now() < deadline {
+            actor.tick(0.016);
+            replied = event_rx.try_iter().any(|e| matches!(e, GameEvent::PathCostReply { request_id: 11, .. }));
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert!(replied);
+    }
+
+    #[test]
+    fn test_load_failure_leaves_state_untouched() {
+        let (mut actor, request_tx, event_rx) = headless_actor();
+        request_tx.send(GameRequest::PlaceCard { x: 0, y: 0, ulid: vec![6; 16], player_ulid: Vec::new(), suit: 1, value: 2, card_id: 1, is_custom: false }).unwrap();
+        request_tx.send(GameRequest::LoadGame { path: "/nonexistent/godo.save".to_string() }).unwrap();
+        actor.tick(0.016);
+
+        assert!(event_rx.try_iter().any(|e| matches!(e, GameEvent::LoadFailed { .. })));
+        assert_eq!(actor.snapshot().cards.len(), 1);
+    }
+}
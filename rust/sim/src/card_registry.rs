@@ -0,0 +1,185 @@
+use dashmap::DashMap;
+use std::sync::Arc;
+use crate::card::{CardData, CardState};
+
+/// Registry for tracking cards on the board
+/// Maps hex coordinates to card data
+#[derive(Clone)]
+pub struct CardRegistry {
+    /// Maps (x, y) hex coords -> CardData
+    cards_by_position: Arc<DashMap<(i32, i32), CardData>>,
+    /// Maps ULID -> (x, y) hex coords for reverse lookup
+    position_by_ulid: Arc<DashMap<Vec<u8>, (i32, i32)>>,
+}
+
+impl Default for CardRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CardRegistry {
+    pub fn new() -> Self {
+        Self {
+            cards_by_position: Arc::new(DashMap::new()),
+            position_by_ulid: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Place a card on the board at specific hex coordinates
+    pub fn place_card(&self, x: i32, y: i32, mut card: CardData) -> bool {
+        // Check if position is already occupied
+        if self.cards_by_position.contains_key(&(x, y)) {
+            sim_warn!("CardRegistry: Position ({}, {}) is already occupied", x, y);
+            return false;
+        }
+
+        // Check if card is already placed elsewhere
+        if self.position_by_ulid.contains_key(&card.ulid) {
+            sim_warn!("CardRegistry: Card already placed on board");
+            return false;
+        }
+
+        // Update card state
+        card.place_on_board(x, y);
+
+        // Store the card
+        let ulid = card.ulid.clone();
+        self.cards_by_position.insert((x, y), card);
+        self.position_by_ulid.insert(ulid, (x, y));
+
+        true
+    }
+
+    /// Remove a card from the board by position
+    pub fn remove_card_at(&self, x: i32, y: i32) -> Option<CardData> {
+        if let Some((_, card)) = self.cards_by_position.remove(&(x, y)) {
+            self.position_by_ulid.remove(&card.ulid);
+            Some(card)
+        } else {
+            None
+        }
+    }
+
+    /// Remove a card from the board by ULID
+    pub fn remove_card_by_ulid(&self, ulid: &[u8]) -> Option<CardData> {
+        if let Some((_, pos)) = self.position_by_ulid.remove(ulid) {
+            if let Some((_, card)) = self.cards_by_position.remove(&pos) {
+                return Some(card);
+            }
+        }
+        None
+    }
+
+    /// Move a card from one position to another
+    pub fn move_card(&self, from_x: i32, from_y: i32, to_x: i32, to_y: i32) -> bool {
+        // Check if destination is occupied
+        if self.cards_by_position.contains_key(&(to_x, to_y)) {
+            return false;
+        }
+
+        // Remove from old position
+        if let Some(mut card) = self.remove_card_at(from_x, from_y) {
+            // Place at new position
+            card.place_on_board(to_x, to_y);
+            let ulid = card.ulid.clone();
+            self.cards_by_position.insert((to_x, to_y), card);
+            self.position_by_ulid.insert(ulid, (to_x, to_y));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Get card at specific position
+    pub fn get_card_at(&self, x: i32, y: i32) -> Option<CardData> {
+        self.cards_by_position.get(&(x, y)).map(|entry| entry.value().clone())
+    }
+
+    /// Get card by ULID
+    pub fn get_card_by_ulid(&self, ulid: &[u8]) -> Option<CardData> {
+        if let Some(pos_entry) = self.position_by_ulid.get(ulid) {
+            let pos = *pos_entry.value();
+            self.cards_by_position.get(&pos).map(|entry| entry.value().clone())
+        } else {
+            None
+        }
+    }
+
+    /// Get position of a card by ULID
+    pub fn get_position(&self, ulid: &[u8]) -> Option<(i32, i32)> {
+        self.position_by_ulid.get(ulid).map(|entry| *entry.value())
+    }
+
+    /// Check if position has a card
+    pub fn has_card_at(&self, x: i32, y: i32) -> bool {
+        self.cards_by_position.contains_key(&(x, y))
+    }
+
+    /// Get all cards within a radius of a position
+    pub fn get_cards_in_radius(&self, center_x: i32, center_y: i32, radius: i32) -> Vec<(i32, i32, CardData)> {
+        let mut result = Vec::new();
+        let radius_sq = radius * radius;
+
+        for entry in self.cards_by_position.iter() {
+            let (x, y) = *entry.key();
+            let dx = x - center_x;
+            let dy = y - center_y;
+            let dist_sq = dx * dx + dy * dy;
+
+            if dist_sq <= radius_sq {
+                result.push((x, y, entry.value().clone()));
+            }
+        }
+
+        result
+    }
+
+    /// Get all cards in a rectangular area
+    pub fn get_cards_in_area(&self, min_x: i32, min_y: i32, max_x: i32, max_y: i32) -> Vec<(i32, i32, CardData)> {
+        let mut result = Vec::new();
+
+        for entry in self.cards_by_position.iter() {
+            let (x, y) = *entry.key();
+            if x >= min_x && x <= max_x && y >= min_y && y <= max_y {
+                result.push((x, y, entry.value().clone()));
+            }
+        }
+
+        result
+    }
+
+    /// Get all cards on the board
+    pub fn get_all_cards(&self) -> Vec<(i32, i32, CardData)> {
+        self.cards_by_position
+            .iter()
+            .map(|entry| {
+                let (x, y) = *entry.key();
+                (x, y, entry.value().clone())
+            })
+            .collect()
+    }
+
+    /// Get count of cards on board
+    pub fn count(&self) -> usize {
+        self.cards_by_position.len()
+    }
+
+    /// Clear all cards from the board
+    pub fn clear(&self) {
+        self.cards_by_position.clear();
+        self.position_by_ulid.clear();
+    }
+
+    /// Update card state
+    pub fn update_card_state(&self, ulid: &[u8], state: CardState) -> bool {
+        if let Some(pos_entry) = self.position_by_ulid.get(ulid) {
+            let pos = *pos_entry.value();
+            if let Some(mut card_entry) = self.cards_by_position.get_mut(&pos) {
+                card_entry.value_mut().set_state(state);
+                return true;
+            }
+        }
+        false
+    }
+}
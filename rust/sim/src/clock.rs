@@ -0,0 +1,111 @@
+// Simulation clock - turns the Actor's real tick delta into simulated time
+// Pause, speed multiplier and single-tick stepping all happen here, so systems and
+// workers only ever see simulated seconds
+
+use crate::types::GameRequest;
+
+/// Slowest and fastest simulation speed
+pub const MIN_SPEED: f64 = 0.5;
+pub const MAX_SPEED: f64 = 8.0;
+
+/// Simulated seconds one step covers while paused (one Actor tick at 1x)
+pub const STEP_DELTA: f64 = 0.016;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimClock {
+    paused: bool,
+    speed: f64,
+    pending_steps: u32,  // Steps requested while paused, one consumed per tick
+}
+
+impl Default for SimClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SimClock {
+    pub fn new() -> Self {
+        Self {
+            paused: false,
+            speed: 1.0,
+            pending_steps: 0,
+        }
+    }
+
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Clamped to MIN_SPEED..=MAX_SPEED
+    pub fn set_speed(&mut self, speed: f64) {
+        self.speed = if speed.is_finite() { speed.clamp(MIN_SPEED, MAX_SPEED) } else { 1.0 };
+    }
+
+    /// Pausing drops steps that were still pending from a previous pause
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+        self.pending_steps = 0;
+    }
+
+    /// Queue single-tick steps; ignored unless paused
+    pub fn step(&mut self, ticks: u32) {
+        if self.paused {
+            self.pending_steps = self.pending_steps.saturating_add(ticks);
+        }
+    }
+
+    /// Simulated delta for a tick of `real_delta` seconds (0 while paused with no step pending)
+    pub fn advance(&mut self, real_delta: f64) -> f64 {
+        if !self.paused {
+            real_delta * self.speed
+        } else if self.pending_steps > 0 {
+            self.pending_steps -= 1;
+            STEP_DELTA
+        } else {
+            0.0
+        }
+    }
+
+    /// Requests that put a default clock into this state (replayed at the start of a recording)
+    pub fn state_requests(&self) -> Vec<GameRequest> {
+        let mut requests = Vec::new();
+        if self.speed != 1.0 {
+            requests.push(GameRequest::SetSimulationSpeed { speed: self.speed });
+        }
+        if self.paused {
+            requests.push(GameRequest::SetSimulationPaused { paused: true });
+            if self.pending_steps > 0 {
+                requests.push(GameRequest::StepSimulation { ticks: self.pending_steps });
+            }
+        }
+        requests
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speed_pause_and_step() {
+        let mut clock = SimClock::new();
+        clock.set_speed(20.0);
+        assert_eq!(clock.advance(0.5), 4.0);
+
+        clock.set_paused(true);
+        clock.step(2);
+        assert_eq!(clock.advance(0.5), STEP_DELTA);
+        assert_eq!(clock.advance(0.5), STEP_DELTA);
+        assert_eq!(clock.advance(0.5), 0.0);
+
+        clock.set_paused(false);
+        clock.step(3);  // Ignored while running
+        clock.set_speed(0.1);
+        assert_eq!(clock.advance(1.0), MIN_SPEED);
+    }
+}
@@ -1,15 +1,15 @@
-/// Centralized configuration constants for the entire game
-///
-/// IMPORTANT: These values MUST be kept in sync with GDScript:
-/// - cat/core/map_config.gd
-///
-/// This module provides a single source of truth for map dimensions
-/// and other global configuration values used across the Rust codebase.
+//! Centralized configuration constants for the entire game
+//!
+//! IMPORTANT: These values MUST be kept in sync with GDScript:
+//! - cat/core/map_config.gd
+//!
+//! This module provides a single source of truth for map dimensions
+//! and other global configuration values used across the Rust codebase.
 
 /// Map configuration constants
 pub mod map {
-    /// INFINITE WORLD: No fixed map dimensions
-    /// Chunks are generated procedurally on-demand
+    // INFINITE WORLD: No fixed map dimensions
+    // Chunks are generated procedurally on-demand
 
     /// Chunk settings
     pub const CHUNK_SIZE: usize = 32;  // 32x32 tiles per chunk
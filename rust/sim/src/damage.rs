@@ -0,0 +1,88 @@
+// Damage resolution - how much of an attack lands, from both sides' stats
+
+use rand::Rng;
+
+use crate::types::HitOutcome;
+
+/// Defense that halves incoming damage (mitigation = defense / (defense + ARMOR_SCALE))
+pub const ARMOR_SCALE: f32 = 10.0;
+/// Highest dodge chance, whatever the Evasion
+pub const MAX_DODGE_CHANCE: f32 = 0.5;
+/// Highest crit chance, whatever the Luck
+pub const MAX_CRIT_CHANCE: f32 = 0.5;
+/// Damage multiplier of a critical hit
+pub const CRIT_MULTIPLIER: f32 = 1.5;
+/// Damage multiplier of a glancing blow (a near dodge)
+pub const GLANCING_MULTIPLIER: f32 = 0.5;
+/// Attack multiplier at 0 Morale (full Morale of 100 attacks at full strength)
+pub const MIN_MORALE_MULTIPLIER: f32 = 0.5;
+
+/// Result of one hit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    pub damage: i32,  // 0 when dodged, at least 1 otherwise
+    pub outcome: HitOutcome,
+}
+
+/// What an attacker puts into an attack: Attack scaled by Morale (0-100, higher counts as 100)
+pub fn attack_power(attack: f32, morale: f32) -> f32 {
+    let morale = if morale.is_finite() { (morale / 100.0).clamp(0.0, 1.0) } else { 1.0 };
+    attack.max(0.0) * (MIN_MORALE_MULTIPLIER + (1.0 - MIN_MORALE_MULTIPLIER) * morale)
+}
+
+/// Resolve an attack's power against a defender
+/// Evasion and Luck are percentages: 20 Evasion dodges 20% of hits and glances off another 20%,
+/// 10 Luck crits 10% of the hits that aren't glancing. Always draws two rolls from the caller's
+/// (seeded) RNG, so one hit's outcome never shifts the rolls of the next.
+pub fn resolve_hit(power: f32, luck: f32, defense: f32, evasion: f32, rng: &mut impl Rng) -> Hit {
+    let dodge_chance = (evasion / 100.0).clamp(0.0, MAX_DODGE_CHANCE);
+    let crit_chance = (luck / 100.0).clamp(0.0, MAX_CRIT_CHANCE);
+    let (evade_roll, crit_roll): (f32, f32) = (rng.random(), rng.random());
+
+    let (outcome, multiplier) = if evade_roll < dodge_chance {
+        return Hit { damage: 0, outcome: HitOutcome::Dodged };
+    } else if evade_roll < dodge_chance * 2.0 {
+        (HitOutcome::Glancing, GLANCING_MULTIPLIER)
+    } else if crit_roll < crit_chance {
+        (HitOutcome::Critical, CRIT_MULTIPLIER)
+    } else {
+        (HitOutcome::Normal, 1.0)
+    };
+
+    let defense = defense.max(0.0);
+    let mitigated = power * multiplier * ARMOR_SCALE / (defense + ARMOR_SCALE);
+    Hit { damage: (mitigated.round() as i32).max(1), outcome }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_stats_shape_damage_and_rolls_are_seeded() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        // No Luck or Evasion: every hit lands normally, mitigated by Defense
+        assert_eq!(resolve_hit(20.0, 0.0, 10.0, 0.0, &mut rng), Hit { damage: 10, outcome: HitOutcome::Normal });
+        assert_eq!(resolve_hit(20.0, 0.0, 0.0, 0.0, &mut rng).damage, 20);
+        assert_eq!(resolve_hit(0.5, 0.0, 50.0, 0.0, &mut rng).damage, 1);
+        assert_eq!(attack_power(20.0, 0.0), 10.0);
+        assert_eq!(attack_power(20.0, 150.0), 20.0);
+
+        // Capped Luck/Evasion: half the hits dodge, the rest glance; crits need a hit that isn't glancing
+        let outcomes: Vec<HitOutcome> = (0..200).map(|_| resolve_hit(20.0, 100.0, 0.0, 100.0, &mut rng).outcome).collect();
+        assert!(outcomes.contains(&HitOutcome::Dodged) && outcomes.contains(&HitOutcome::Glancing));
+        assert!(!outcomes.contains(&HitOutcome::Normal) && !outcomes.contains(&HitOutcome::Critical));
+        let crits = (0..200).filter(|_| resolve_hit(20.0, 100.0, 0.0, 0.0, &mut rng) == Hit { damage: 30, outcome: HitOutcome::Critical }).count();
+        assert!(crits > 50 && crits < 150);
+
+        // Same seed, same fight
+        let fight = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..20).map(|_| resolve_hit(20.0, 30.0, 5.0, 30.0, &mut rng)).collect::<Vec<_>>()
+        };
+        assert_eq!(fight(3), fight(3));
+    }
+}
@@ -3,6 +3,8 @@
 
 use rand::Rng;
 
+use crate::resource_registry::resources;
+
 /// Represents a single reward item
 #[derive(Debug, Clone)]
 pub struct Reward {
@@ -13,25 +15,32 @@ pub struct Reward {
 /// Types of rewards that can be dropped
 #[derive(Debug, Clone, PartialEq)]
 pub enum RewardType {
-    Draw,      // Card draw
-    Gold,      // Currency
-    Food,      // Resource
-    Faith,     // Resource
-    Labor,     // Resource
-    Experience, // XP
+    Draw,              // Card draw
+    Resource(String),  // Economy resource, by registry key ("gold", "food", ...)
+    Experience,        // XP
 }
 
 impl RewardType {
+    /// Resource reward by registry key
+    pub fn resource(key: &str) -> Self {
+        RewardType::Resource(key.to_string())
+    }
+
     pub fn to_string(&self) -> String {
         match self {
             RewardType::Draw => "draw".to_string(),
-            RewardType::Gold => "gold".to_string(),
-            RewardType::Food => "food".to_string(),
-            RewardType::Faith => "faith".to_string(),
-            RewardType::Labor => "labor".to_string(),
+            RewardType::Resource(key) => key.clone(),
             RewardType::Experience => "experience".to_string(),
         }
     }
+
+    /// Actor economy resource type, None for non-resources (and keys missing from the registry)
+    pub fn resource_type(&self) -> Option<i64> {
+        match self {
+            RewardType::Resource(key) => resources().by_key(key).map(|def| def.id),
+            RewardType::Draw | RewardType::Experience => None,
+        }
+    }
 }
 
 /// A potential drop with its probability
@@ -53,18 +62,22 @@ pub struct DropTable {
 impl DropTable {
     /// Roll for loot drops based on this table
     pub fn roll_drops(&self) -> Vec<Reward> {
-        let mut rng = rand::thread_rng();
+        self.roll_drops_with(&mut rand::rng())
+    }
+
+    /// Roll with a caller-provided RNG (seeded rolls for turn mode / replays)
+    pub fn roll_drops_with(&self, rng: &mut impl Rng) -> Vec<Reward> {
         let mut rewards = Vec::new();
 
         for entry in &self.drops {
             // Roll for probability
-            let roll: f32 = rng.gen();
+            let roll: f32 = rng.random();
             if roll <= entry.probability {
                 // Determine amount
                 let amount = if entry.min_amount == entry.max_amount {
                     entry.min_amount
                 } else {
-                    rng.gen_range(entry.min_amount..=entry.max_amount)
+                    rng.random_range(entry.min_amount..=entry.max_amount)
                 };
 
                 rewards.push(Reward {
@@ -83,6 +96,12 @@ pub struct DropTableRegistry {
     tables: std::collections::HashMap<String, DropTable>,
 }
 
+impl Default for DropTableRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DropTableRegistry {
     pub fn new() -> Self {
         let mut registry = Self {
@@ -107,13 +126,13 @@ impl DropTableRegistry {
                     probability: 1.0, // Always drop 1 draw
                 },
                 DropEntry {
-                    reward_type: RewardType::Gold,
+                    reward_type: RewardType::resource("gold"),
                     min_amount: 50,
                     max_amount: 250,
                     probability: 0.8, // 80% chance
                 },
                 DropEntry {
-                    reward_type: RewardType::Food,
+                    reward_type: RewardType::resource("food"),
                     min_amount: 50,
                     max_amount: 250,
                     probability: 0.6, // 60% chance
@@ -132,7 +151,7 @@ impl DropTableRegistry {
                     probability: 1.0, // Always drop 1 draw
                 },
                 DropEntry {
-                    reward_type: RewardType::Food,
+                    reward_type: RewardType::resource("food"),
                     min_amount: 75,
                     max_amount: 300,
                     probability: 0.9, // 90% chance (raptors are good for food)
@@ -151,13 +170,13 @@ impl DropTableRegistry {
                     probability: 1.0, // Always drop 1-2 draws
                 },
                 DropEntry {
-                    reward_type: RewardType::Faith,
+                    reward_type: RewardType::resource("faith"),
                     min_amount: 100,
                     max_amount: 300,
                     probability: 0.9, // 90% chance
                 },
                 DropEntry {
-                    reward_type: RewardType::Gold,
+                    reward_type: RewardType::resource("gold"),
                     min_amount: 100,
                     max_amount: 400,
                     probability: 0.7, // 70% chance
@@ -176,7 +195,7 @@ impl DropTableRegistry {
                     probability: 1.0, // Always drop 1 draw
                 },
                 DropEntry {
-                    reward_type: RewardType::Labor,
+                    reward_type: RewardType::resource("labor"),
                     min_amount: 50,
                     max_amount: 200,
                     probability: 0.7, // 70% chance
@@ -195,7 +214,7 @@ impl DropTableRegistry {
                     probability: 1.0, // Always drop 1 draw
                 },
                 DropEntry {
-                    reward_type: RewardType::Gold,
+                    reward_type: RewardType::resource("gold"),
                     min_amount: 25,
                     max_amount: 100,
                     probability: 0.5, // 50% chance
@@ -229,12 +248,17 @@ impl DropTableRegistry {
 
     /// Generate loot for a killed entity
     pub fn generate_loot(&self, entity_type: &str) -> Vec<Reward> {
+        self.generate_loot_with(entity_type, &mut rand::rng())
+    }
+
+    /// Generate loot with a caller-provided RNG
+    pub fn generate_loot_with(&self, entity_type: &str, rng: &mut impl Rng) -> Vec<Reward> {
         if let Some(table) = self.get_table(entity_type) {
-            table.roll_drops()
+            table.roll_drops_with(rng)
         } else {
             // No table found, use default
             if let Some(default_table) = self.tables.get("default") {
-                default_table.roll_drops()
+                default_table.roll_drops_with(rng)
             } else {
                 Vec::new()
             }
@@ -254,3 +278,8 @@ static DROP_TABLES: Lazy<Mutex<DropTableRegistry>> = Lazy::new(|| {
 pub fn generate_loot(entity_type: &str) -> Vec<Reward> {
     DROP_TABLES.lock().unwrap().generate_loot(entity_type)
 }
+
+/// Generate loot for a killed entity with a caller-provided RNG
+pub fn generate_loot_with(entity_type: &str, rng: &mut impl Rng) -> Vec<Reward> {
+    DROP_TABLES.lock().unwrap().generate_loot_with(entity_type, rng)
+}
@@ -0,0 +1,112 @@
+// Economy forecast - projects a resource ahead from its net flow, per-turn upkeep and decay
+
+use crate::types::{DecayRule, ForecastHorizon, ResourceForecast};
+
+/// Longest forecast, in turns (longer horizons are cut to it)
+pub const MAX_FORECAST_TURNS: u32 = 1000;
+
+/// Simulated seconds a horizon covers (clamped to MAX_FORECAST_TURNS)
+pub fn horizon_secs(horizon: ForecastHorizon, turn_secs: f64) -> f64 {
+    let max_secs = MAX_FORECAST_TURNS as f64 * turn_secs;
+    match horizon {
+        ForecastHorizon::Seconds(secs) if secs.is_finite() => secs.clamp(0.0, max_secs),
+        ForecastHorizon::Seconds(_) => 0.0,
+        ForecastHorizon::Turns(turns) => turns.min(MAX_FORECAST_TURNS) as f64 * turn_secs,
+    }
+}
+
+/// Fill in projected/empty_at/full_at from current, cap, rate, upkeep_per_turn and decay
+/// Flows and clamps like the economy worker, so projections match what happens
+pub fn project(resource: &mut ResourceForecast, horizon: ForecastHorizon, turn_secs: f64) {
+    let end = horizon_secs(horizon, turn_secs);
+
+    // Upkeep (and decay) times: end of each full turn in real time, start of each turn in turn mode
+    let per_turn = resource.upkeep_per_turn != 0.0 || resource.decay != DecayRule::None;
+    let upkeep_times: Vec<f64> = if !per_turn || turn_secs <= 0.0 {
+        Vec::new()
+    } else {
+        match horizon {
+            ForecastHorizon::Seconds(_) => (1..).map(|turn| turn as f64 * turn_secs).take_while(|t| *t <= end).collect(),
+            ForecastHorizon::Turns(_) => (0..).map(|turn| turn as f64 * turn_secs).take_while(|t| *t < end).collect(),
+        }
+    };
+
+    let (cap, rate) = (resource.cap, resource.rate);
+    let mut amount = resource.current;
+    let mut time = 0.0;
+    resource.empty_at = None;
+    resource.full_at = None;
+
+    let mark = |resource: &mut ResourceForecast, amount: f64, at: f64| {
+        if amount <= 0.0 && resource.empty_at.is_none() {
+            resource.empty_at = Some(at);
+        }
+        if amount >= cap && resource.full_at.is_none() {
+            resource.full_at = Some(at);
+        }
+    };
+    mark(resource, amount, 0.0);
+
+    for (until, upkeep_due) in upkeep_times.iter().map(|t| (*t, true)).chain(std::iter::once((end, false))) {
+        // Flow up to the next upkeep (or the end), noting where it crosses zero or the cap
+        if rate < 0.0 && amount > 0.0 {
+            let at = time + amount / -rate;
+            if at <= until {
+                mark(resource, 0.0, at);
+            }
+        } else if rate > 0.0 && amount < cap {
+            let at = time + (cap - amount) / rate;
+            if at <= until {
+                mark(resource, cap, at);
+            }
+        }
+        amount = (amount + rate * (until - time)).clamp(0.0, cap);
+        time = until;
+
+        if upkeep_due {
+            amount = (amount - resource.upkeep_per_turn).max(0.0);
+            amount = resource.decay.apply(amount, cap, resource.decay_protection);
+            mark(resource, amount, time);
+        }
+    }
+
+    resource.projected = amount;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upkeep_and_flow_crossings() {
+        // 100 food, 1/s production, 100 eaten per turn: real time runs dry at the end of turn 3
+        let mut food = ResourceForecast { resource_type: 1, current: 100.0, cap: 1000.0, rate: 1.0, upkeep_per_turn: 100.0, ..Default::default() };
+        project(&mut food, ForecastHorizon::Seconds(300.0), 60.0);
+        assert_eq!(food.empty_at, Some(180.0));
+        assert_eq!(food.full_at, None);
+        assert_eq!(food.projected, 0.0);
+
+        // Turn mode charges upkeep first, so the same pool is empty at once
+        project(&mut food, ForecastHorizon::Turns(5), 60.0);
+        assert_eq!(food.empty_at, Some(0.0));
+
+        // Flow alone: reaches the cap mid-turn and stays there
+        let mut gold = ResourceForecast { resource_type: 0, current: 900.0, cap: 1000.0, rate: 4.0, ..Default::default() };
+        project(&mut gold, ForecastHorizon::Turns(2), 60.0);
+        assert_eq!(gold.full_at, Some(25.0));
+        assert_eq!(gold.projected, 1000.0);
+        assert_eq!(horizon_secs(ForecastHorizon::Turns(u32::MAX), 60.0), MAX_FORECAST_TURNS as f64 * 60.0);
+
+        // Spoilage after each upkeep: 10% of what's above 2000, half of that saved by storage
+        let mut grain = ResourceForecast {
+            resource_type: 1,
+            current: 3000.0,
+            cap: 10000.0,
+            decay: DecayRule::Spoil { rate: 0.1, threshold: 2000.0 },
+            decay_protection: 0.5,
+            ..Default::default()
+        };
+        project(&mut grain, ForecastHorizon::Turns(2), 60.0);
+        assert!((grain.projected - (2000.0 + 1000.0 * 0.95 * 0.95)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,43 @@
+//! Headless simulation core for Godo
+//!
+//! Everything here is plain Rust with no Godot dependency: the game Actor and
+//! its workers, entity stats, the card registry and combo detector, terrain
+//! cache, pathfinding, world generation noise and loot tables. The GDExtension
+//! (`godo`) wraps this crate with thin Godot bridges; tests and the axum server
+//! can drive `GameActor` directly.
+
+// Legacy name helpers (CardState, PokerHand, ...) keep their inherent to_string()
+#![allow(clippy::inherent_to_string)]
+
+#[macro_use]
+pub mod log;
+
+pub mod config;  // Centralized configuration constants
+pub mod world_gen;
+pub mod terrain_cache;
+pub mod entity;
+pub mod pathfinding;
+pub mod card;
+pub mod card_registry;
+pub mod card_combo;
+pub mod drop_table;
+pub mod resource_registry;  // Resource types (data/resources.json)
+pub mod types;
+pub mod workers;
+pub mod actor;
+pub mod replay;
+pub mod savegame;
+pub mod systems;
+pub mod metrics;
+pub mod clock;
+pub mod damage;
+pub mod status;
+pub mod targeting;
+pub mod range_calculator;
+pub mod splash;
+pub mod forecast;
+pub mod market;
+pub mod taxation;
+
+pub use actor::{GameActor, spawn_actor_thread};
+pub use types::{GameEvent, GameRequest};
@@ -0,0 +1,62 @@
+//! Pluggable logging for the simulation core
+//!
+//! The core runs headless (tests, axum server) as well as inside Godot, so it
+//! cannot call `godot_print!` directly. Hosts install a sink with
+//! `set_log_sink`; without one, messages go to stderr.
+
+use parking_lot::RwLock;
+
+/// Log severity, mirrors godot_print / godot_warn / godot_error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+pub type LogSink = fn(LogLevel, &str);
+
+static LOG_SINK: RwLock<Option<LogSink>> = RwLock::new(None);
+
+/// Route all core log output through `sink` (e.g. Godot's console)
+pub fn set_log_sink(sink: LogSink) {
+    *LOG_SINK.write() = Some(sink);
+}
+
+/// Restore the default stderr sink
+pub fn clear_log_sink() {
+    *LOG_SINK.write() = None;
+}
+
+#[doc(hidden)]
+pub fn emit(level: LogLevel, message: &str) {
+    match *LOG_SINK.read() {
+        Some(sink) => sink(level, message),
+        None => match level {
+            LogLevel::Info => eprintln!("{}", message),
+            LogLevel::Warn => eprintln!("WARNING: {}", message),
+            LogLevel::Error => eprintln!("ERROR: {}", message),
+        },
+    }
+}
+
+#[macro_export]
+macro_rules! sim_print {
+    ($($arg:tt)*) => {
+        $crate::log::emit($crate::log::LogLevel::Info, &format!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! sim_warn {
+    ($($arg:tt)*) => {
+        $crate::log::emit($crate::log::LogLevel::Warn, &format!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! sim_error {
+    ($($arg:tt)*) => {
+        $crate::log::emit($crate::log::LogLevel::Error, &format!($($arg)*))
+    };
+}
@@ -0,0 +1,133 @@
+// Market pricing - supply and demand at one trading post
+
+use std::collections::BTreeMap;
+
+/// Normal stock of every resource at a market of average wealth (50)
+pub const BASE_DEPTH: f64 = 1000.0;
+/// Share of a trade's value the market keeps
+pub const TRADE_FEE: f64 = 0.05;
+/// Most of a market's stock one trade can buy
+pub const MAX_TRADE_SHARE: f64 = 0.5;
+/// Share of the gap to normal stock a market recovers each turn
+pub const RESTOCK_RATE: f64 = 0.2;
+
+/// One trading post's stock and terms
+#[derive(Debug, Clone, PartialEq)]
+pub struct Market {
+    pub market_id: i64,
+    pub trade_modifier: f64,  // From the structure's reputation: -0.5 (50% better rates) to 0.5 (50% worse)
+    pub wealth: f64,          // 0-100: richer markets hold more stock, so prices move less per trade
+    pub stock: BTreeMap<i64, f64>,  // resource_type -> stock (missing = normal depth)
+}
+
+impl Market {
+    pub fn new(market_id: i64, trade_modifier: f64, wealth: f64) -> Self {
+        let mut market = Self { market_id, trade_modifier: 0.0, wealth: 0.0, stock: BTreeMap::new() };
+        market.set_terms(trade_modifier, wealth);
+        market
+    }
+
+    /// Update reputation/wealth terms (stock is kept)
+    pub fn set_terms(&mut self, trade_modifier: f64, wealth: f64) {
+        self.trade_modifier = if trade_modifier.is_finite() { trade_modifier.clamp(-0.5, 0.5) } else { 0.0 };
+        self.wealth = if wealth.is_finite() { wealth.clamp(0.0, 100.0) } else { 50.0 };
+    }
+
+    /// Normal stock of every resource (BASE_DEPTH at wealth 50, half that when poor, 1.5x when rich)
+    pub fn depth(&self) -> f64 {
+        BASE_DEPTH * (0.5 + self.wealth / 100.0)
+    }
+
+    pub fn stock_of(&self, resource_type: i64) -> f64 {
+        self.stock.get(&resource_type).copied().unwrap_or_else(|| self.depth())
+    }
+
+    /// Current unit price of a resource, before fee and trade modifier
+    /// The base price scaled by normal depth over stock: selling a resource here cheapens it, buying makes it dearer
+    pub fn price(&self, resource_type: i64, base_price: f64) -> f64 {
+        base_price * self.depth() / self.stock_of(resource_type)
+    }
+
+    /// Amount of `buy` received for selling `sell.1` of `sell.0` (market state is not touched)
+    /// Both legs are priced at their average stock over the trade, so splitting a trade doesn't pay
+    pub fn quote(&self, sell: (i64, f64), buy: i64, base_price: impl Fn(i64) -> Option<f64>) -> Result<f64, String> {
+        let (sell_type, amount) = sell;
+        if !(amount.is_finite() && amount > 0.0) {
+            return Err("trade amount must be positive".to_string());
+        }
+        if sell_type == buy {
+            return Err("cannot trade a resource for itself".to_string());
+        }
+        let (Some(sell_base), Some(buy_base)) = (base_price(sell_type), base_price(buy)) else {
+            return Err("unknown resource type".to_string());
+        };
+
+        // Value of what is sold, after the fee and the structure's reputation terms
+        let depth = self.depth();
+        let sell_stock = self.stock_of(sell_type);
+        let value = amount * sell_base * depth / (sell_stock + amount / 2.0)
+            * (1.0 - TRADE_FEE) * (1.0 - self.trade_modifier);
+
+        // Solve y * buy_base * depth / (stock - y/2) * (1 + modifier) = value
+        let buy_stock = self.stock_of(buy);
+        let cost = value / (1.0 + self.trade_modifier);
+        let received = cost * buy_stock / (buy_base * depth + cost / 2.0);
+        if received > buy_stock * MAX_TRADE_SHARE {
+            return Err("the market can't supply that much".to_string());
+        }
+        Ok(received)
+    }
+
+    /// Move stock for a settled trade (sold goes into the market, bought comes out)
+    pub fn settle(&mut self, sell: (i64, f64), buy: (i64, f64)) {
+        let sold = self.stock_of(sell.0) + sell.1;
+        let bought = self.stock_of(buy.0) - buy.1;
+        self.stock.insert(sell.0, sold);
+        self.stock.insert(buy.0, bought);
+    }
+
+    /// One turn of recovery towards normal stock
+    pub fn restock(&mut self) {
+        let depth = self.depth();
+        for stock in self.stock.values_mut() {
+            *stock += (depth - *stock) * RESTOCK_RATE;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prices_follow_supply_and_reputation() {
+        let base = |resource_type: i64| match resource_type {
+            0 => Some(1.0),
+            1 => Some(0.5),
+            _ => None,
+        };
+        let mut market = Market::new(1, 0.0, 50.0);
+
+        // Small trades go at close to base rates, minus the fee
+        let gold = market.quote((1, 10.0), 0, base).unwrap();
+        assert!((gold - 4.75).abs() < 0.05);
+
+        // Selling food floods the market: food gets cheaper there, and the next sale earns less
+        let big = market.quote((1, 500.0), 0, base).unwrap();
+        market.settle((1, 500.0), (0, big));
+        assert!(market.price(1, 0.5) < 0.5 && market.price(0, 1.0) > 1.0);
+        assert!(market.quote((1, 10.0), 0, base).unwrap() < gold);
+
+        // Stock recovers over turns
+        let flooded = market.stock_of(1);
+        market.restock();
+        assert!(market.stock_of(1) < flooded);
+
+        // Good reputation (negative modifier) pays better; no more than half the stock per trade
+        let friendly = Market::new(2, -0.25, 50.0);
+        assert!(friendly.quote((1, 10.0), 0, base).unwrap() > gold);
+        assert!(friendly.quote((1, 100_000.0), 0, base).is_err());
+        assert!(friendly.quote((1, 10.0), 1, base).is_err());
+        assert!(friendly.quote((1, 10.0), 7, base).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+// Actor telemetry - per-phase tick timings, channel queue depths and pathfinding latency
+// The Actor updates its own copy every tick and publishes it to a shared slot that
+// FFI reads synchronously (same pattern as ACTOR_ENTITY_STATS)
+
+use std::time::{Duration, Instant};
+use serde::{Serialize, Deserialize};
+
+/// Weight of the newest sample in the rolling average
+const AVERAGE_WEIGHT: f64 = 0.1;
+
+/// Rolling timing for one phase or latency source (milliseconds)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimingStats {
+    pub count: u64,
+    pub last_ms: f64,
+    pub avg_ms: f64,  // Exponential moving average
+    pub max_ms: f64,  // Worst sample since startup
+}
+
+impl TimingStats {
+    pub fn record(&mut self, duration: Duration) {
+        let ms = duration.as_secs_f64() * 1000.0;
+        self.avg_ms = if self.count == 0 {
+            ms
+        } else {
+            self.avg_ms + (ms - self.avg_ms) * AVERAGE_WEIGHT
+        };
+        self.last_ms = ms;
+        self.max_ms = self.max_ms.max(ms);
+        self.count += 1;
+    }
+
+    pub fn record_since(&mut self, start: Instant) {
+        self.record(start.elapsed());
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PhaseTiming {
+    pub phase: String,  // "requests", "follow_ups", "collect.<system>", "tick.<system>"
+    pub timing: TimingStats,
+}
+
+/// Backlog of one worker's channels at the end of a tick
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkerQueueDepth {
+    pub worker: String,
+    pub pending_jobs: usize,     // Sent by the Actor, not yet picked up by a worker
+    pub pending_results: usize,  // Produced by a worker, not yet collected by the Actor
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActorMetrics {
+    pub tick_count: u64,
+    pub tick: TimingStats,  // Whole tick
+    pub phases: Vec<PhaseTiming>,
+    pub request_queue: usize,  // Godot -> Actor requests waiting for the next tick
+    pub event_queue: usize,    // Actor -> Godot events not yet drained by the bridge
+    pub worker_queues: Vec<WorkerQueueDepth>,
+    pub path_latency: TimingStats,  // Request accepted -> result collected, per path request
+    pub path_backlog: usize,        // Path requests waiting for room in the pool queue
+    pub superseded_paths: u64,      // Path jobs/results dropped for a newer request (since startup)
+    pub coalesced_requests: u64,    // Position/path requests merged into a newer one for the same ULID (since startup)
+}
+
+impl ActorMetrics {
+    /// Add a phase slot, returning its index for `record_phase`
+    pub fn add_phase(&mut self, phase: impl Into<String>) -> usize {
+        self.phases.push(PhaseTiming {
+            phase: phase.into(),
+            timing: TimingStats::default(),
+        });
+        self.phases.len() - 1
+    }
+
+    pub fn record_phase(&mut self, index: usize, start: Instant) {
+        if let Some(phase) = self.phases.get_mut(index) {
+            phase.timing.record_since(start);
+        }
+    }
+
+    /// Update a worker's queue depths in place (allocates only the first time a worker reports)
+    pub fn set_worker_queue(&mut self, worker: &str, pending_jobs: usize, pending_results: usize) {
+        match self.worker_queues.iter_mut().find(|queue| queue.worker == worker) {
+            Some(queue) => {
+                queue.pending_jobs = pending_jobs;
+                queue.pending_results = pending_results;
+            }
+            None => self.worker_queues.push(WorkerQueueDepth {
+                worker: worker.to_string(),
+                pending_jobs,
+                pending_results,
+            }),
+        }
+    }
+}
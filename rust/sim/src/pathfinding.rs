@@ -0,0 +1,441 @@
+//! Unified hex pathfinding core (A*, random destinations)
+//!
+//! Pure functions over the shared terrain cache. The Godot-side SegQueue worker
+//! pool and UnifiedPathfindingBridge live in the godo crate and call into this.
+
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::cmp::Ordering;
+use crate::terrain_cache;
+use crate::terrain_cache::TerrainType;
+
+
+/// Hex coordinate (axial coordinates)
+pub type HexCoord = (i32, i32);
+
+// ============================================================================
+// UNIFIED PATHFINDING SYSTEM
+// Single system for both water and land entities
+// ============================================================================
+
+/// Pathfinding request (unified for all terrain types)
+#[derive(Debug, Clone)]
+pub struct PathfindingRequest {
+    pub entity_ulid: Vec<u8>,
+    pub terrain_type: TerrainType,
+    pub start: HexCoord,
+    pub goal: HexCoord,
+    pub avoid_entities: bool,
+}
+
+/// Pathfinding result (unified for all terrain types)
+#[derive(Debug, Clone)]
+pub struct PathfindingResult {
+    pub entity_ulid: Vec<u8>,
+    pub path: Vec<HexCoord>,
+    pub success: bool,
+    pub cost: f32,
+}
+
+// Chunk loading queue (to avoid blocking main thread during chunk loads)
+#[derive(Debug, Clone)]
+pub struct ChunkLoadRequest {
+    pub chunk_coords: (i32, i32),
+    pub tiles: Vec<(i32, i32, TerrainType)>,
+}
+
+// Random destination request/result queues (to avoid blocking main thread)
+#[derive(Debug, Clone)]
+pub struct RandomDestRequest {
+    pub entity_ulid: Vec<u8>,
+    pub terrain_type: TerrainType,
+    pub start: HexCoord,
+    pub min_distance: i32,
+    pub max_distance: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct RandomDestResult {
+    pub entity_ulid: Vec<u8>,
+    pub destination: Option<HexCoord>,
+}
+
+// ============================================================================
+// HEX GRID GEOMETRY
+// ============================================================================
+
+/// Calculate hex distance using axial coordinates
+fn hex_distance(a: HexCoord, b: HexCoord) -> f32 {
+    let (q1, r1) = a;
+    let (q2, r2) = b;
+
+    let dq = (q1 - q2).abs();
+    let dr = (r1 - r2).abs();
+    let ds = (q1 + r1 - q2 - r2).abs();
+
+    ((dq + dr + ds) / 2) as f32
+}
+
+/// Get all 6 neighbors of a hex coordinate
+fn hex_neighbors(coord: HexCoord) -> Vec<HexCoord> {
+    let (q, r) = coord;
+    vec![
+        (q + 1, r),     // East
+        (q - 1, r),     // West
+        (q, r + 1),     // Southeast
+        (q, r - 1),     // Northwest
+        (q + 1, r - 1), // Northeast
+        (q - 1, r + 1), // Southwest
+    ]
+}
+
+/// Get the two flanking tiles between two neighboring hex coordinates
+/// Used to prevent "corner-cutting" in pathfinding
+/// Returns None if the coordinates are not neighbors
+fn get_flankers(from: HexCoord, to: HexCoord) -> Option<[HexCoord; 2]> {
+    let (q, r) = from;
+    let dq = to.0 - q;
+    let dr = to.1 - r;
+
+    match (dq, dr) {
+        ( 1,  0) => Some([(q + 1, r - 1), (q,     r + 1)]), // E:  flankers are NE & SE
+        (-1,  0) => Some([(q,     r - 1), (q - 1, r + 1)]), // W:  flankers are NW & SW
+        ( 0,  1) => Some([(q + 1, r    ), (q,     r + 1)]), // SE: flankers are E  & SE (axis)
+        ( 0, -1) => Some([(q,     r - 1), (q + 1, r    )]), // NW: flankers are NW & E  (axis)
+        ( 1, -1) => Some([(q + 1, r    ), (q,     r - 1)]), // NE: flankers are E  & NW
+        (-1,  1) => Some([(q - 1, r    ), (q,     r + 1)]), // SW: flankers are W  & SE
+        _ => None, // Not neighbors
+    }
+}
+
+/// Check if coordinate is within reasonable bounds
+fn is_in_bounds(coord: HexCoord) -> bool {
+    let (q, r) = coord;
+    q.abs() < 100000 && r.abs() < 100000
+}
+
+// ============================================================================
+// UNIFIED A* PATHFINDING
+// ============================================================================
+
+/// A* node for priority queue
+#[derive(Debug, Clone)]
+struct AStarNode {
+    coord: HexCoord,
+    g_cost: f32,
+    h_cost: f32,
+}
+
+impl AStarNode {
+    fn f_cost(&self) -> f32 {
+        self.g_cost + self.h_cost
+    }
+}
+
+impl PartialEq for AStarNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.f_cost() == other.f_cost()
+    }
+}
+
+impl Eq for AStarNode {}
+
+impl PartialOrd for AStarNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AStarNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f_cost().partial_cmp(&self.f_cost()).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Unified A* pathfinding with generic walkability checker
+pub fn find_path_astar_generic<F>(
+    start: HexCoord,
+    goal: HexCoord,
+    is_walkable: F,
+) -> Option<Vec<HexCoord>>
+where
+    F: Fn(HexCoord) -> bool,
+{
+    if !is_in_bounds(start) || !is_in_bounds(goal) {
+        return None;
+    }
+
+    if !is_walkable(start) || !is_walkable(goal) {
+        return None;
+    }
+
+    let mut open_set = BinaryHeap::new();
+    let mut came_from: HashMap<HexCoord, HexCoord> = HashMap::new();
+    let mut g_score: HashMap<HexCoord, f32> = HashMap::new();
+    let mut closed_set: HashSet<HexCoord> = HashSet::new();
+
+    g_score.insert(start, 0.0);
+    open_set.push(AStarNode {
+        coord: start,
+        g_cost: 0.0,
+        h_cost: hex_distance(start, goal),
+    });
+
+    while let Some(current_node) = open_set.pop() {
+        let current = current_node.coord;
+
+        if current == goal {
+            return Some(reconstruct_path(&came_from, current));
+        }
+
+        if closed_set.contains(&current) {
+            continue;
+        }
+
+        closed_set.insert(current);
+
+        for neighbor in hex_neighbors(current) {
+            if closed_set.contains(&neighbor) || !is_walkable(neighbor) {
+                continue;
+            }
+
+            // NO CORNER-CUTTING: Check flanking tiles to prevent diagonal squeezes
+            // Require at least one flanker to be walkable
+            if let Some(flankers) = get_flankers(current, neighbor) {
+                let flanker1_walkable = is_walkable(flankers[0]);
+                let flanker2_walkable = is_walkable(flankers[1]);
+
+                // If BOTH flankers are blocked, disallow this diagonal move
+                if !flanker1_walkable && !flanker2_walkable {
+                    continue; // Skip this neighbor - would squeeze through corner
+                }
+            }
+
+            let tentative_g_score = g_score.get(&current).unwrap_or(&f32::MAX) + 1.0;
+
+            if tentative_g_score < *g_score.get(&neighbor).unwrap_or(&f32::MAX) {
+                came_from.insert(neighbor, current);
+                g_score.insert(neighbor, tentative_g_score);
+                open_set.push(AStarNode {
+                    coord: neighbor,
+                    g_cost: tentative_g_score,
+                    h_cost: hex_distance(neighbor, goal),
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Reconstruct path from A* came_from map
+fn reconstruct_path(came_from: &HashMap<HexCoord, HexCoord>, mut current: HexCoord) -> Vec<HexCoord> {
+    let mut path = vec![current];
+    while let Some(&prev) = came_from.get(&current) {
+        path.push(prev);
+        current = prev;
+    }
+    path.reverse();
+    path
+}
+
+// ============================================================================
+// UNIFIED PATHFINDING CORE
+// ============================================================================
+
+/// Check if an entity occupies a coordinate (for collision avoidance)
+/// DEPRECATED: Always returns false since UnifiedEventBridge handles collision now
+fn is_entity_at(_coord: HexCoord, _requesting_ulid: &[u8]) -> bool {
+    false
+}
+
+/// Unified pathfinding function - works for both water and land entities
+pub fn find_path_unified(request: &PathfindingRequest) -> PathfindingResult {
+    // DEBUG: Verify start and goal terrain types
+    let start_terrain = terrain_cache::get_terrain(request.start.0, request.start.1);
+    let goal_terrain = terrain_cache::get_terrain(request.goal.0, request.goal.1);
+
+    // CRITICAL: Reject pathfinding if start terrain is wrong
+    if start_terrain != request.terrain_type {
+        sim_error!("find_path_unified: START {:?} has terrain {:?} but requested {:?}! Rejecting pathfinding.",
+            request.start, start_terrain, request.terrain_type);
+        return PathfindingResult {
+            entity_ulid: request.entity_ulid.clone(),
+            path: vec![],
+            success: false,
+            cost: 0.0,
+        };
+    }
+
+    // CRITICAL: Reject pathfinding if goal terrain is wrong
+    if goal_terrain != request.terrain_type {
+        sim_error!("find_path_unified: GOAL {:?} has terrain {:?} but requested {:?}! Rejecting pathfinding.",
+            request.goal, goal_terrain, request.terrain_type);
+        return PathfindingResult {
+            entity_ulid: request.entity_ulid.clone(),
+            path: vec![],
+            success: false,
+            cost: 0.0,
+        };
+    }
+
+    let is_walkable = |coord: HexCoord| -> bool {
+        // Check terrain type matches
+        let terrain = terrain_cache::get_terrain(coord.0, coord.1);
+        let terrain_matches = terrain == request.terrain_type;
+
+        if !terrain_matches {
+            return false;
+        }
+
+        // Optional entity avoidance
+        if request.avoid_entities {
+            !is_entity_at(coord, &request.entity_ulid)
+        } else {
+            true
+        }
+    };
+
+    // Run A* with unified walkability checker
+    match find_path_astar_generic(request.start, request.goal, is_walkable) {
+        Some(path) => {
+            // DEBUG: Validate entire path has correct terrain type
+            let mut invalid_tiles = Vec::new();
+            for coord in &path {
+                let terrain = terrain_cache::get_terrain(coord.0, coord.1);
+                if terrain != request.terrain_type {
+                    invalid_tiles.push((coord, terrain));
+                }
+            }
+
+            // CRITICAL: Reject path if it contains invalid terrain tiles
+            if !invalid_tiles.is_empty() {
+                sim_error!("find_path_unified: Path contains {} INVALID terrain tiles for {:?}! Rejecting path.",
+                    invalid_tiles.len(), request.terrain_type);
+                for (coord, terrain) in invalid_tiles.iter().take(3) {
+                    sim_error!("  -> {:?} has {:?} (expected {:?})", coord, terrain, request.terrain_type);
+                }
+                // Return failure - this path is invalid!
+                return PathfindingResult {
+                    entity_ulid: request.entity_ulid.clone(),
+                    path: vec![],
+                    success: false,
+                    cost: 0.0,
+                };
+            }
+
+            let cost = path.len() as f32;
+
+            // FINAL VERIFICATION: Check start, goal, and last waypoint of path
+            if !path.is_empty() {
+                let first_waypoint = path[0];
+                let last_waypoint = path[path.len() - 1];
+                let first_terrain = terrain_cache::get_terrain(first_waypoint.0, first_waypoint.1);
+                let last_terrain = terrain_cache::get_terrain(last_waypoint.0, last_waypoint.1);
+
+                if first_terrain != request.terrain_type {
+                    sim_error!("find_path_unified: CRITICAL - First waypoint {:?} has terrain {:?} (expected {:?})!",
+                        first_waypoint, first_terrain, request.terrain_type);
+                    return PathfindingResult {
+                        entity_ulid: request.entity_ulid.clone(),
+                        path: vec![],
+                        success: false,
+                        cost: 0.0,
+                    };
+                }
+
+                if last_terrain != request.terrain_type {
+                    sim_error!("find_path_unified: CRITICAL - Last waypoint {:?} has terrain {:?} (expected {:?})!",
+                        last_waypoint, last_terrain, request.terrain_type);
+                    return PathfindingResult {
+                        entity_ulid: request.entity_ulid.clone(),
+                        path: vec![],
+                        success: false,
+                        cost: 0.0,
+                    };
+                }
+            }
+
+            PathfindingResult {
+                entity_ulid: request.entity_ulid.clone(),
+                path,
+                success: true,
+                cost,
+            }
+        }
+        None => {
+            sim_error!("find_path_unified: FAILED - no path found from {:?} to {:?} (terrain={:?})",
+                request.start, request.goal, request.terrain_type);
+            PathfindingResult {
+                entity_ulid: request.entity_ulid.clone(),
+                path: vec![],
+                success: false,
+                cost: 0.0,
+            }
+        }
+    }
+}
+
+// ============================================================================
+// UNIFIED RANDOM DESTINATION FINDER
+// ============================================================================
+
+/// Find a random reachable destination within range (unified for all terrain types)
+pub fn find_random_destination(
+    start: HexCoord,
+    terrain_type: TerrainType,
+    _entity_ulid: &[u8],
+    min_distance: i32,
+    max_distance: i32,
+) -> Option<HexCoord> {
+    use rand::Rng;
+    let mut rng = rand::rng();
+    let mut valid_destinations = Vec::new();
+
+    // Search in expanding rings
+    for distance in min_distance..=max_distance {
+        for dx in -distance..=distance {
+            for dy in -distance..=distance {
+                let candidate = (start.0 + dx, start.1 + dy);
+
+                // Calculate hex distance using proper axial coordinate formula
+                let hex_dist = hex_distance(start, candidate) as i32;
+
+                // Check if within overall distance range
+                if hex_dist < min_distance || hex_dist > max_distance {
+                    continue;
+                }
+
+                // Check terrain type matches
+                let terrain = terrain_cache::get_terrain(candidate.0, candidate.1);
+                let terrain_matches = terrain == terrain_type;
+
+                if !terrain_matches {
+                    continue;
+                }
+
+                // DEPRECATED: Collision check removed (UnifiedEventBridge handles this now)
+                // Always consider tiles as not occupied
+                valid_destinations.push(candidate);
+            }
+        }
+    }
+
+    if valid_destinations.is_empty() {
+        None
+    } else {
+        let idx = rng.random_range(0..valid_destinations.len());
+        let selected = valid_destinations[idx];
+
+        // CRITICAL: Verify selected destination has correct terrain
+        let dest_terrain = terrain_cache::get_terrain(selected.0, selected.1);
+        if dest_terrain != terrain_type {
+            sim_error!("find_random_destination: CRITICAL BUG - Selected destination {:?} has terrain {:?} but requested {:?}!",
+                selected, dest_terrain, terrain_type);
+            // This should never happen! Log all valid destinations for debugging
+            sim_error!("  -> All valid destinations: {:?}", valid_destinations);
+            return None; // Don't return invalid destination
+        }
+
+        Some(selected)
+    }
+}
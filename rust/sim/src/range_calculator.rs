@@ -0,0 +1,185 @@
+// Range, line of sight and high ground for combat
+
+use crate::terrain_cache::{get_terrain_cache, HexCoord};
+use crate::world_gen::{BiomeGenerator, NOISE_CACHE};
+
+/// Elevation (-1.0 to 1.0, sea level 0.0) from which a tile counts as a mountain
+pub const MOUNTAIN_ELEVATION: f32 = 0.6;
+/// How far a tile must rise above the line of fire to give cover
+pub const COVER_RISE: f32 = 0.1;
+/// Evasion a target gains per covering tile between it and the shooter
+pub const COVER_EVASION: f32 = 10.0;
+/// Most Evasion cover can add, however many tiles are in the way
+pub const MAX_COVER_EVASION: f32 = 30.0;
+/// Power gained per unit of elevation the shooter stands above its target
+pub const HIGH_GROUND_BONUS: f32 = 0.5;
+/// Most power high ground can add (or low ground take away), as a fraction
+pub const MAX_HIGH_GROUND_BONUS: f32 = 0.25;
+
+/// What stands between a shooter and its target
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sightline {
+    pub blocked: bool,              // A mountain is in the way
+    pub cover: u32,                 // Tiles rising clearly above the line of fire
+    pub elevation_advantage: f32,   // Shooter elevation minus target elevation
+}
+
+/// Calculate hex distance using cube coordinates
+/// Distance = max(|dx|, |dy|, |dz|) where z = -x - y
+pub fn hex_distance(a: HexCoord, b: HexCoord) -> i32 {
+    let (ax, ay) = a;
+    let (bx, by) = b;
+
+    let dx = (ax - bx).abs();
+    let dy = (ay - by).abs();
+
+    // Convert axial to cube coordinates
+    let az = -ax - ay;
+    let bz = -bx - by;
+    let dz = (az - bz).abs();
+
+    dx.max(dy).max(dz)
+}
+
+/// Check if two positions are within attack range
+pub fn is_in_range(attacker_pos: HexCoord, target_pos: HexCoord, range: i32) -> bool {
+    hex_distance(attacker_pos, target_pos) <= range
+}
+
+/// Tiles on the straight line from `a` to `b`, both ends included (one tile per step of distance)
+pub fn hex_line(a: HexCoord, b: HexCoord) -> Vec<HexCoord> {
+    let steps = hex_distance(a, b);
+    if steps == 0 {
+        return vec![a];
+    }
+
+    // Nudge the line off hex edges so ties always round the same way
+    let (aq, ar) = (a.0 as f32 + 1e-6, a.1 as f32 + 2e-6);
+    let (bq, br) = (b.0 as f32 + 1e-6, b.1 as f32 + 2e-6);
+    (0..=steps)
+        .map(|step| {
+            let t = step as f32 / steps as f32;
+            cube_round(aq + (bq - aq) * t, ar + (br - ar) * t)
+        })
+        .collect()
+}
+
+/// Round fractional axial coordinates to the hex containing them
+fn cube_round(q: f32, r: f32) -> HexCoord {
+    let s = -q - r;
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+    (rq as i32, rr as i32)
+}
+
+/// Trace the line of fire from `from` to `to` over the given elevation
+/// The line runs from the shooter's elevation to the target's; only tiles between them can block it
+pub fn sightline(from: HexCoord, to: HexCoord, elevation: impl Fn(HexCoord) -> f32) -> Sightline {
+    let line = hex_line(from, to);
+    let (from_height, to_height) = (elevation(from), elevation(to));
+    let mut sight = Sightline { blocked: false, cover: 0, elevation_advantage: from_height - to_height };
+
+    let steps = (line.len() - 1) as f32;
+    for (step, &tile) in line.iter().enumerate().skip(1).take(line.len().saturating_sub(2)) {
+        let line_height = from_height + (to_height - from_height) * step as f32 / steps;
+        let height = elevation(tile);
+        if height >= MOUNTAIN_ELEVATION && height > line_height {
+            sight.blocked = true;
+            return sight;
+        }
+        if height > line_height + COVER_RISE {
+            sight.cover += 1;
+        }
+    }
+    sight
+}
+
+/// Evasion added to the target of a shot through `cover` covering tiles
+pub fn cover_evasion(cover: u32) -> f32 {
+    (cover as f32 * COVER_EVASION).min(MAX_COVER_EVASION)
+}
+
+/// Power multiplier of a shot fired from `elevation_advantage` above (or below) its target
+pub fn high_ground_multiplier(elevation_advantage: f32) -> f32 {
+    1.0 + (elevation_advantage * HIGH_GROUND_BONUS).clamp(-MAX_HIGH_GROUND_BONUS, MAX_HIGH_GROUND_BONUS)
+}
+
+/// Elevation sampler for the current world: the generator of the terrain cache's seed
+/// (the cache itself only knows water, land and ungenerated tiles)
+/// Returns sea level everywhere when that generator isn't loaded (headless, tests)
+pub fn world_elevation() -> impl Fn(HexCoord) -> f32 {
+    let seed = get_terrain_cache().seed();
+    let noise = NOISE_CACHE.read().get(&seed).cloned();
+    move |(x, y)| {
+        noise.as_ref().map_or(0.0, |noise| {
+            let (world_x, world_y) = BiomeGenerator::tile_to_hex_world_pos(x, y);
+            noise.get_elevation(world_x, world_y)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_hex_distance_same_tile() {
+        assert_eq!(hex_distance((0, 0), (0, 0)), 0);
+    }
+
+    #[test]
+    fn test_hex_distance_adjacent() {
+        assert_eq!(hex_distance((0, 0), (1, 0)), 1);
+        assert_eq!(hex_distance((0, 0), (0, 1)), 1);
+        assert_eq!(hex_distance((0, 0), (-1, 1)), 1);
+    }
+
+    #[test]
+    fn test_hex_distance_diagonal() {
+        assert_eq!(hex_distance((0, 0), (2, 2)), 4);
+    }
+
+    #[test]
+    fn test_is_in_range() {
+        assert!(is_in_range((0, 0), (1, 0), 1));
+        assert!(is_in_range((0, 0), (2, 0), 3));
+        assert!(!is_in_range((0, 0), (5, 0), 3));
+    }
+
+    #[test]
+    fn test_mountains_block_ridges_cover_and_high_ground_counts() {
+        assert_eq!(hex_line((0, 0), (3, 0)), vec![(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(hex_line((0, 0), (2, -1)).len(), 3);
+        let line = hex_line((0, 0), (-2, 4));
+        assert!(line.windows(2).all(|pair| hex_distance(pair[0], pair[1]) == 1));
+
+        let terrain = |tiles: &[(HexCoord, f32)]| {
+            let heights: HashMap<HexCoord, f32> = tiles.iter().copied().collect();
+            move |tile: HexCoord| heights.get(&tile).copied().unwrap_or(0.0)
+        };
+
+        // Flat ground: clear shot, nothing to gain
+        let flat = sightline((0, 0), (3, 0), terrain(&[]));
+        assert_eq!(flat, Sightline { blocked: false, cover: 0, elevation_advantage: 0.0 });
+        assert_eq!(high_ground_multiplier(flat.elevation_advantage), 1.0);
+
+        // A mountain in between blocks; a ridge only covers
+        assert!(sightline((0, 0), (3, 0), terrain(&[((2, 0), 0.7)])).blocked);
+        let ridge = sightline((0, 0), (3, 0), terrain(&[((1, 0), 0.3), ((2, 0), 0.3)]));
+        assert_eq!((ridge.blocked, ridge.cover), (false, 2));
+        assert_eq!(cover_evasion(ridge.cover), 20.0);
+        assert_eq!(cover_evasion(5), MAX_COVER_EVASION);
+
+        // From a peak the line clears a lower mountain, and shooting down hits harder
+        let peak = sightline((0, 0), (3, 0), terrain(&[((0, 0), 0.9), ((1, 0), 0.7), ((3, 0), 0.5)]));
+        assert!(!peak.blocked);
+        assert!((high_ground_multiplier(peak.elevation_advantage) - 1.2).abs() < 1e-4);
+        assert_eq!(high_ground_multiplier(-2.0), 1.0 - MAX_HIGH_GROUND_BONUS);
+    }
+}
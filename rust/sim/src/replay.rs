@@ -0,0 +1,337 @@
+// Deterministic replay recorder
+// Records every GameRequest the Actor processes (with its tick number) plus the
+// GameEvents it emitted, so bug reports can attach a replay instead of repro steps.
+// `verify()` feeds the requests into a fresh seeded Actor and reports the first
+// tick whose events diverge.
+
+use std::cell::RefCell;
+use std::path::Path;
+use crossbeam_channel::{unbounded, SendError, Sender};
+use serde::{Serialize, Deserialize};
+
+use crate::actor::GameActor;
+use crate::types::{GameEvent, GameRequest};
+
+/// File header magic ("Godo RePLay")
+const REPLAY_MAGIC: [u8; 4] = *b"GRPL";
+
+/// Bump when the encoded layout changes; older files are rejected with UnsupportedVersion
+pub const REPLAY_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(String),
+    Encode(String),
+    Decode(String),
+    NotAReplay,
+    UnsupportedVersion(u32),
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::Io(msg) => write!(f, "Replay I/O failed: {}", msg),
+            ReplayError::Encode(msg) => write!(f, "Failed to encode replay: {}", msg),
+            ReplayError::Decode(msg) => write!(f, "Failed to decode replay: {}", msg),
+            ReplayError::NotAReplay => write!(f, "File is not a replay (bad header)"),
+            ReplayError::UnsupportedVersion(v) => write!(
+                f, "Unsupported replay version {} (expected {})", v, REPLAY_FORMAT_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Everything the Actor received and emitted during one tick
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReplayTick {
+    pub tick: u64,
+    pub delta: f64,
+    pub requests: Vec<GameRequest>,
+    pub events: Vec<GameEvent>,
+}
+
+/// A recorded session: RNG seed plus every tick since recording started
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Replay {
+    pub seed: u64,
+    pub ticks: Vec<ReplayTick>,
+}
+
+impl Replay {
+    /// Encode as header (magic + version) followed by the bincode body
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ReplayError> {
+        let body = bincode::serde::encode_to_vec(self, bincode::config::standard())
+            .map_err(|e| ReplayError::Encode(e.to_string()))?;
+
+        let mut bytes = Vec::with_capacity(8 + body.len());
+        bytes.extend_from_slice(&REPLAY_MAGIC);
+        bytes.extend_from_slice(&REPLAY_FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&body);
+        Ok(bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayError> {
+        if bytes.len() < 8 || bytes[0..4] != REPLAY_MAGIC {
+            return Err(ReplayError::NotAReplay);
+        }
+
+        let version = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        if version != REPLAY_FORMAT_VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+
+        let (replay, _) = bincode::serde::decode_from_slice(&bytes[8..], bincode::config::standard())
+            .map_err(|e| ReplayError::Decode(e.to_string()))?;
+        Ok(replay)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ReplayError> {
+        let bytes = self.to_bytes()?;
+        std::fs::write(path, bytes).map_err(|e| ReplayError::Io(e.to_string()))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplayError> {
+        let bytes = std::fs::read(path).map_err(|e| ReplayError::Io(e.to_string()))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Accumulates ticks while recording is active
+#[derive(Debug)]
+pub struct ReplayRecorder {
+    replay: Replay,
+}
+
+impl ReplayRecorder {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            replay: Replay { seed, ticks: Vec::new() },
+        }
+    }
+
+    /// Open a new tick (tick numbers count from 0 at recording start)
+    pub fn begin_tick(&mut self, delta: f64) {
+        let tick = self.replay.ticks.len() as u64;
+        self.replay.ticks.push(ReplayTick { tick, delta, ..Default::default() });
+    }
+
+    pub fn record_request(&mut self, request: &GameRequest) {
+        if let Some(tick) = self.replay.ticks.last_mut() {
+            tick.requests.push(request.clone());
+        }
+    }
+
+    pub fn record_event(&mut self, event: &GameEvent) {
+        if let Some(tick) = self.replay.ticks.last_mut() {
+            tick.events.push(event.clone());
+        }
+    }
+
+    pub fn finish(self) -> Replay {
+        self.replay
+    }
+}
+
+/// The Actor's outgoing event channel, with an optional replay tap
+/// Every emitted GameEvent goes through `send()`, so recording sees all of them
+pub struct EventSink {
+    tx: Sender<GameEvent>,
+    recorder: RefCell<Option<ReplayRecorder>>,
+}
+
+impl EventSink {
+    pub fn new(tx: Sender<GameEvent>) -> Self {
+        Self {
+            tx,
+            recorder: RefCell::new(None),
+        }
+    }
+
+    pub fn send(&self, event: GameEvent) -> Result<(), SendError<GameEvent>> {
+        if let Some(recorder) = self.recorder.borrow_mut().as_mut() {
+            recorder.record_event(&event);
+        }
+        self.tx.send(event)
+    }
+
+    /// Events sent but not yet received on the other end
+    pub fn len(&self) -> usize {
+        self.tx.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tx.is_empty()
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.borrow().is_some()
+    }
+
+    pub fn start_recording(&self, seed: u64) {
+        *self.recorder.borrow_mut() = Some(ReplayRecorder::new(seed));
+    }
+
+    pub fn stop_recording(&self) -> Option<Replay> {
+        self.recorder.borrow_mut().take().map(ReplayRecorder::finish)
+    }
+
+    pub fn begin_tick(&self, delta: f64) {
+        if let Some(recorder) = self.recorder.borrow_mut().as_mut() {
+            recorder.begin_tick(delta);
+        }
+    }
+
+    pub fn record_request(&self, request: &GameRequest) {
+        if let Some(recorder) = self.recorder.borrow_mut().as_mut() {
+            recorder.record_request(request);
+        }
+    }
+}
+
+/// First tick where the replayed Actor's events differ from the recording
+#[derive(Debug, Clone)]
+pub struct ReplayDivergence {
+    pub tick: u64,
+    pub expected: Vec<GameEvent>,
+    pub actual: Vec<GameEvent>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplayReport {
+    pub ticks_replayed: u64,
+    pub divergence: Option<ReplayDivergence>,
+}
+
+impl ReplayReport {
+    pub fn is_match(&self) -> bool {
+        self.divergence.is_none()
+    }
+}
+
+/// Replay a recording into a fresh deterministic Actor and compare emitted events tick by tick
+/// Events within a tick are compared as a multiset (HashMap iteration order is not stable)
+/// Terrain comes from the global terrain cache, so load the same map before verifying
+pub fn verify(replay: &Replay) -> ReplayReport {
+    let (request_tx, request_rx) = unbounded();
+    let (event_tx, event_rx) = unbounded();
+    let mut actor = GameActor::with_seed(request_rx, event_tx, replay.seed);
+
+    // Initial resource snapshot is emitted at construction, before any recorded tick
+    event_rx.try_iter().for_each(drop);
+
+    let mut ticks_replayed = 0;
+    for recorded in &replay.ticks {
+        for request in &recorded.requests {
+            let _ = request_tx.send(request.clone());
+        }
+        actor.tick(recorded.delta);
+        ticks_replayed += 1;
+
+        let actual: Vec<GameEvent> = event_rx.try_iter().collect();
+        if !events_match(&recorded.events, &actual) {
+            return ReplayReport {
+                ticks_replayed,
+                divergence: Some(ReplayDivergence {
+                    tick: recorded.tick,
+                    expected: recorded.events.clone(),
+                    actual,
+                }),
+            };
+        }
+    }
+
+    ReplayReport {
+        ticks_replayed,
+        divergence: None,
+    }
+}
+
+/// Order-insensitive comparison using each event's encoded bytes
+fn events_match(expected: &[GameEvent], actual: &[GameEvent]) -> bool {
+    if expected.len() != actual.len() {
+        return false;
+    }
+
+    let encode = |events: &[GameEvent]| -> Vec<Vec<u8>> {
+        let mut encoded: Vec<Vec<u8>> = events
+            .iter()
+            .map(|e| bincode::serde::encode_to_vec(e, bincode::config::standard()).unwrap_or_default())
+            .collect();
+        encoded.sort();
+        encoded
+    };
+
+    encode(expected) == encode(actual)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_replay() -> Replay {
+        let mut recorder = ReplayRecorder::new(42);
+        recorder.begin_tick(0.016);
+        recorder.record_request(&GameRequest::AddResources { player_ulid: Vec::new(), resource_type: 0, amount: 25.0 });
+        recorder.record_event(&GameEvent::ResourceChanged {
+            player_ulid: Vec::new(),
+            resource_type: 0,
+            current: 1025.0,
+            cap: 10000.0,
+            rate: 0.0,
+        });
+        recorder.record_event(&GameEvent::TransactionApplied {
+            request_id: 0,
+            entry: crate::types::LedgerEntry {
+                transaction_id: 1,
+                player_ulid: Vec::new(),
+                reason: crate::types::TransactionReason::Adjustment,
+                changes: vec![(0, 25.0)],
+                reverts: None,
+                rolled_back: false,
+            },
+        });
+        recorder.begin_tick(0.016);
+        recorder.finish()
+    }
+
+    #[test]
+    fn test_replay_roundtrip_bytes() {
+        let replay = sample_replay();
+        let bytes = replay.to_bytes().unwrap();
+        let decoded = Replay::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded.seed, 42);
+        assert_eq!(decoded.ticks.len(), 2);
+        assert_eq!(decoded.ticks[0].requests.len(), 1);
+        assert_eq!(decoded.ticks[1].tick, 1);
+    }
+
+    #[test]
+    fn test_replay_rejects_unknown_version() {
+        let mut bytes = sample_replay().to_bytes().unwrap();
+        bytes[4..8].copy_from_slice(&99u32.to_le_bytes());
+
+        assert!(matches!(Replay::from_bytes(&bytes), Err(ReplayError::UnsupportedVersion(99))));
+        assert!(matches!(Replay::from_bytes(b"nope"), Err(ReplayError::NotAReplay)));
+    }
+
+    #[test]
+    fn test_verify_matching_replay() {
+        let report = verify(&sample_replay());
+        assert!(report.is_match());
+        assert_eq!(report.ticks_replayed, 2);
+    }
+
+    #[test]
+    fn test_verify_flags_first_divergent_tick() {
+        let mut replay = sample_replay();
+        replay.ticks[1].events.push(GameEvent::EntityDied { ulid: vec![1; 16] });
+
+        let report = verify(&replay);
+        let divergence = report.divergence.expect("tick 1 should diverge");
+        assert_eq!(divergence.tick, 1);
+        assert!(divergence.actual.is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+// Resource registry - every resource type the economy knows, loaded from data
+
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+use crate::types::DecayRule;
+
+/// Built-in resource definitions
+const BUILTIN_RESOURCES: &str = include_str!("../data/resources.json");
+
+/// One resource type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceDef {
+    pub id: i64,              // Economy resource_type
+    pub key: String,          // Stable lowercase name (loot tables, i18n keys: resource.<key>)
+    pub name: String,         // Display name
+    #[serde(default)]
+    pub default_amount: f64,  // Starting amount of a new (or reset) pool
+    pub default_cap: f64,
+    #[serde(default)]
+    pub decay: DecayRule,     // Spoilage/drift every turn (default: keeps)
+    #[serde(default = "default_base_price")]
+    pub base_price: f64,      // Market value of one unit at a trading post's normal stock (gold = 1.0)
+    #[serde(default)]
+    pub suit: Option<u8>,     // Card suit whose combos pay out in this resource (0=Clubs, 1=Diamonds, 2=Hearts, 3=Spades)
+}
+
+fn default_base_price() -> f64 {
+    1.0
+}
+
+/// Ids of the resources the economy's own rules are written against, found by key
+/// (every registry must define gold, food, labor and faith)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CoreResources {
+    pub gold: i64,   // Taxes are paid in it
+    pub food: i64,   // Units eat it every upkeep; running out starves them
+    pub labor: i64,  // Running out pauses production
+    pub faith: i64,  // Running out costs cities reputation
+}
+
+/// Every known resource type, sorted by id
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceRegistry {
+    resources: Vec<ResourceDef>,
+    #[serde(skip)]
+    core: CoreResources,  // Resolved from the keys when validated
+}
+
+impl ResourceRegistry {
+    /// The registry shipped with the game (data/resources.json)
+    pub fn builtin() -> Self {
+        Self::from_json(BUILTIN_RESOURCES).expect("built-in resources.json is valid")
+    }
+
+    /// Parse and validate a registry: { "resources": [ { id, key, name, default_amount, default_cap, decay, base_price, suit } ] }
+    /// decay: { "kind": "none" } | { "kind": "spoil", rate, threshold } | { "kind": "drift", rate, baseline }
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let registry: Self = serde_json::from_str(json).map_err(|e| format!("Invalid resource registry: {}", e))?;
+        registry.validated()
+    }
+
+    /// Sorted by id, or why the definitions don't make a usable registry
+    fn validated(mut self) -> Result<Self, String> {
+        self.resources.sort_by_key(|def| def.id);
+        for (index, def) in self.resources.iter().enumerate() {
+            let earlier = &self.resources[..index];
+            if earlier.iter().any(|other| other.id == def.id) {
+                return Err(format!("Duplicate resource id {}", def.id));
+            }
+            if def.key.is_empty() || earlier.iter().any(|other| other.key.eq_ignore_ascii_case(&def.key)) {
+                return Err(format!("Resource {} has an empty or duplicate key '{}'", def.id, def.key));
+            }
+            if def.suit.is_some() && earlier.iter().any(|other| other.suit == def.suit) {
+                return Err(format!("Resource {} maps suit {:?} already used by another resource", def.id, def.suit));
+            }
+            if !(def.default_cap.is_finite() && def.default_cap >= 0.0) {
+                return Err(format!("Resource {} has an invalid cap {}", def.id, def.default_cap));
+            }
+            if !(def.default_amount.is_finite() && (0.0..=def.default_cap).contains(&def.default_amount)) {
+                return Err(format!("Resource {} starts at {} outside [0, {}]", def.id, def.default_amount, def.default_cap));
+            }
+            if !(def.base_price.is_finite() && def.base_price > 0.0) {
+                return Err(format!("Resource {} has an invalid base price {}", def.id, def.base_price));
+            }
+            let (rate, level) = match def.decay {
+                DecayRule::None => (0.0, 0.0),
+                DecayRule::Spoil { rate, threshold } => (rate, threshold),
+                DecayRule::Drift { rate, baseline } => (rate, baseline),
+            };
+            if !((0.0..=1.0).contains(&rate) && level.is_finite() && level >= 0.0) {
+                return Err(format!("Resource {} has an invalid decay rule {:?}", def.id, def.decay));
+            }
+        }
+
+        let core_id = |key: &str| self.by_key(key)
+            .map(|def| def.id)
+            .ok_or_else(|| format!("Resource registry has no '{}' resource (the economy needs gold, food, labor and faith)", key));
+        self.core = CoreResources {
+            gold: core_id("gold")?,
+            food: core_id("food")?,
+            labor: core_id("labor")?,
+            faith: core_id("faith")?,
+        };
+        Ok(self)
+    }
+
+    /// Definition of a resource type
+    pub fn get(&self, id: i64) -> Option<&ResourceDef> {
+        self.resources.iter().find(|def| def.id == id)
+    }
+
+    /// Definition by key (case-insensitive)
+    pub fn by_key(&self, key: &str) -> Option<&ResourceDef> {
+        self.resources.iter().find(|def| def.key.eq_ignore_ascii_case(key))
+    }
+
+    /// Resource a card suit pays out in (None = the suit gives no resource)
+    pub fn for_suit(&self, suit: u8) -> Option<&ResourceDef> {
+        self.resources.iter().find(|def| def.suit == Some(suit))
+    }
+
+    /// Ids of gold, food, labor and faith (every valid registry has them)
+    pub fn core(&self) -> CoreResources {
+        self.core
+    }
+
+    /// All definitions, by id
+    pub fn iter(&self) -> impl Iterator<Item = &ResourceDef> {
+        self.resources.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+}
+
+// Global instance (read by the economy, card combos and loot tables)
+static RESOURCES: Lazy<RwLock<Arc<ResourceRegistry>>> = Lazy::new(|| RwLock::new(Arc::new(ResourceRegistry::builtin())));
+
+/// The current registry
+pub fn resources() -> Arc<ResourceRegistry> {
+    RESOURCES.read().clone()
+}
+
+/// Replace the registry with definitions from a data file (the old one stays on error)
+/// A new resource (Wood, Stone, ...) is a data change rather than a code change
+/// Load before the session starts: pools pick up newly defined resources on their next use,
+/// resources dropped from the data stay in existing pools
+pub fn load_resources(json: &str) -> Result<(), String> {
+    let registry = ResourceRegistry::from_json(json)?;
+    *RESOURCES.write() = Arc::new(registry);
+    Ok(())
+}
+
+/// Display name of a resource type (its id for unknown types)
+pub fn resource_name(id: i64) -> String {
+    resources().get(id).map(|def| def.name.clone()).unwrap_or_else(|| id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_and_custom_registries() {
+        let builtin = ResourceRegistry::builtin();
+        assert_eq!(builtin.iter().map(|def| def.id).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(builtin.for_suit(0).map(|def| def.key.as_str()), Some("faith"));
+        assert_eq!(builtin.by_key("Gold").map(|def| def.id), Some(0));
+
+        // A new resource is just another entry; suit and decay are optional
+        let custom = ResourceRegistry::from_json(r#"{ "resources": [
+            { "id": 4, "key": "wood", "name": "Wood", "default_amount": 50.0, "default_cap": 500.0 },
+            { "id": 0, "key": "gold", "name": "Gold", "default_cap": 100.0, "suit": 1 },
+            { "id": 7, "key": "food", "name": "Food", "default_cap": 100.0 },
+            { "id": 5, "key": "labor", "name": "Labor", "default_cap": 100.0 },
+            { "id": 6, "key": "faith", "name": "Faith", "default_cap": 100.0 }
+        ] }"#).unwrap();
+        assert_eq!(custom.iter().map(|def| def.id).collect::<Vec<_>>(), vec![0, 4, 5, 6, 7]);
+        assert_eq!(custom.get(4).map(|def| (def.default_amount, def.suit, def.decay)), Some((50.0, None, DecayRule::None)));
+
+        // The economy's rules follow their resources to whatever ids the data gives them
+        assert_eq!(builtin.core(), CoreResources { gold: 0, food: 1, labor: 2, faith: 3 });
+        assert_eq!(custom.core(), CoreResources { gold: 0, food: 7, labor: 5, faith: 6 });
+        let faithless = r#"{ "resources": [
+            { "id": 0, "key": "gold", "name": "Gold", "default_cap": 100.0 },
+            { "id": 1, "key": "food", "name": "Food", "default_cap": 100.0 },
+            { "id": 2, "key": "labor", "name": "Labor", "default_cap": 100.0 }
+        ] }"#;
+        assert!(ResourceRegistry::from_json(faithless).unwrap_err().contains("'faith'"));
+        assert_eq!(builtin.get(1).map(|def| def.decay), Some(DecayRule::Spoil { rate: 0.1, threshold: 2000.0 }));
+
+        let duplicate = r#"{ "resources": [
+            { "id": 0, "key": "gold", "name": "Gold", "default_cap": 100.0, "suit": 1 },
+            { "id": 1, "key": "ore", "name": "Ore", "default_cap": 100.0, "suit": 1 }
+        ] }"#;
+        assert!(ResourceRegistry::from_json(duplicate).is_err());
+        assert!(ResourceRegistry::from_json(r#"{ "resources": [ { "id": 0, "key": "gold", "name": "Gold", "default_amount": 5.0, "default_cap": 1.0 } ] }"#).is_err());
+    }
+}
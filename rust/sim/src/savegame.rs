@@ -0,0 +1,478 @@
+// Save/load snapshot of Actor-owned game state
+// Covers everything the Actor owns (entities, stats, teams, cards, resources,
+// producers/consumers, in-flight spawns, active combats, status effects, threat and projectile splash). Saves are JSON so older
+// versions can be migrated field-by-field before being decoded into current types.
+
+use std::path::Path;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+
+use crate::card::CardData;
+use crate::entity::{EntityData, EntityStats};
+use crate::types::{ActiveCombat, CombatProfile, LedgerEntry, Recipe, ShortageConfig, SplashProfile, StatusEffect, StatusKind};
+use crate::workers::PendingSpawn;
+
+/// Identifies a file as a godo save (checked before version/migrations)
+const SAVE_FORMAT_NAME: &str = "godo-save";
+
+/// Bump when `GameSnapshot` changes shape, and add a migration for the previous version
+pub const SAVE_FORMAT_VERSION: u32 = 12;
+
+/// Schema migration: rewrites a `state` object from version N to N+1 in place
+pub type Migration = fn(&mut Value) -> Result<(), SaveError>;
+
+/// Migrations indexed by source version: `MIGRATIONS[0]` upgrades v1 -> v2, and so on
+/// Must always hold `SAVE_FORMAT_VERSION - 1` entries
+const MIGRATIONS: &[Migration] = &[add_turn_state, add_player_pools, add_transaction_ledger, add_shortage_config, add_recipes, add_storages, add_markets, add_cities, add_status_effects, add_threat, add_projectile_splash];
+
+/// v1 -> v2: turn mode and turn counter (v1 saves were always real-time)
+fn add_turn_state(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    object.insert("turn_mode".to_string(), Value::Bool(false));
+    object.insert("turn".to_string(), Value::from(0u64));
+    Ok(())
+}
+
+/// v2 -> v3: per-player pools (v2 had one shared pool, which becomes the AI/neutral pool)
+fn add_player_pools(state: &mut Value) -> Result<(), SaveError> {
+    for key in ["resources", "producers", "consumers"] {
+        let entries = state.get_mut(key)
+            .and_then(Value::as_array_mut)
+            .ok_or_else(|| SaveError::Decode(format!("{} is not a list", key)))?;
+        for entry in entries {
+            let entry = entry.as_object_mut().ok_or_else(|| SaveError::Decode(format!("{} entry is not an object", key)))?;
+            entry.insert("player_ulid".to_string(), Value::Array(Vec::new()));
+        }
+    }
+    Ok(())
+}
+
+/// v3 -> v4: transaction ledger (v3 kept no history)
+fn add_transaction_ledger(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    object.insert("ledger".to_string(), Value::Array(Vec::new()));
+    object.insert("next_transaction_id".to_string(), Value::from(1u64));
+    Ok(())
+}
+
+/// v4 -> v5: shortage consequences (older saves get the defaults)
+fn add_shortage_config(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    let config = serde_json::to_value(ShortageConfig::default()).map_err(|e| SaveError::Encode(e.to_string()))?;
+    object.insert("shortage_config".to_string(), config);
+    Ok(())
+}
+
+/// v5 -> v6: recipe producers
+fn add_recipes(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    object.insert("recipes".to_string(), Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v6 -> v7: decay-protecting storage
+fn add_storages(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    object.insert("storages".to_string(), Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v7 -> v8: trading post markets
+fn add_markets(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    object.insert("markets".to_string(), Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v8 -> v9: taxed cities
+fn add_cities(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    object.insert("cities".to_string(), Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v9 -> v10: status effects and immunities
+fn add_status_effects(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    object.insert("status_effects".to_string(), Value::Array(Vec::new()));
+    object.insert("status_immunities".to_string(), Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v10 -> v11: combat profiles and threat tables
+fn add_threat(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    object.insert("combat_profiles".to_string(), Value::Array(Vec::new()));
+    object.insert("threat".to_string(), Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v11 -> v12: projectile splash definitions (older saves use the defaults)
+fn add_projectile_splash(state: &mut Value) -> Result<(), SaveError> {
+    let object = state.as_object_mut().ok_or_else(|| SaveError::Decode("state is not an object".to_string()))?;
+    object.insert("projectile_splash".to_string(), Value::Array(Vec::new()));
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(String),
+    Encode(String),
+    Decode(String),
+    NotASave,
+    UnsupportedVersion(u32),
+    Migration { from_version: u32, error: String },
+    Invalid(String),
+}
+
+impl std::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Io(msg) => write!(f, "Save I/O failed: {}", msg),
+            SaveError::Encode(msg) => write!(f, "Failed to encode save: {}", msg),
+            SaveError::Decode(msg) => write!(f, "Failed to decode save: {}", msg),
+            SaveError::NotASave => write!(f, "File is not a save (missing header)"),
+            SaveError::UnsupportedVersion(v) => write!(
+                f, "Unsupported save version {} (newest known is {})", v, SAVE_FORMAT_VERSION
+            ),
+            SaveError::Migration { from_version, error } => write!(
+                f, "Failed to migrate save from version {}: {}", from_version, error
+            ),
+            SaveError::Invalid(msg) => write!(f, "Save is inconsistent: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+/// Resource pool entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceSnapshot {
+    pub player_ulid: Vec<u8>,  // Pool owner (empty = AI/neutral pool)
+    pub resource_type: i64,
+    pub current: f64,
+    pub cap: f64,
+    pub rate: f64,
+}
+
+/// Registered producer or consumer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlowSnapshot {
+    pub ulid: Vec<u8>,
+    pub player_ulid: Vec<u8>,
+    pub resource_type: i64,
+    pub rate: f64,
+    pub active: bool,
+}
+
+/// Registered recipe producer, mid-cycle progress included
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecipeSnapshot {
+    pub ulid: Vec<u8>,
+    pub player_ulid: Vec<u8>,
+    pub recipe: Recipe,
+    pub active: bool,
+    pub progress: f64,
+    pub inputs_paid: bool,
+    pub cycles_completed: u64,
+}
+
+/// Storage structure and the decay it prevents per resource type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageSnapshot {
+    pub ulid: Vec<u8>,
+    pub player_ulid: Vec<u8>,
+    pub protection: Vec<(i64, f64)>,
+}
+
+/// Trading post market: terms and stock per resource type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketSnapshot {
+    pub market_id: i64,
+    pub trade_modifier: f64,
+    pub wealth: f64,
+    pub stock: Vec<(i64, f64)>,
+}
+
+/// Taxed city: owner, tax base and rate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CitySnapshot {
+    pub city_id: i64,
+    pub player_ulid: Vec<u8>,
+    pub population: i64,
+    pub wealth: f64,
+    pub reputation: f64,
+    pub tax_rate: f64,
+}
+
+/// Everything the Actor needs to resume a session
+/// Maps are stored as lists so every key type survives JSON
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub entities: Vec<EntityData>,
+    pub entity_stats: Vec<(Vec<u8>, EntityStats)>,
+    pub entity_player_ulids: Vec<(Vec<u8>, Vec<u8>)>,
+    pub cards: Vec<CardData>,  // Board cards (CardData::position holds the hex)
+    pub resources: Vec<ResourceSnapshot>,
+    pub producers: Vec<FlowSnapshot>,
+    pub consumers: Vec<FlowSnapshot>,
+    pub recipes: Vec<RecipeSnapshot>,
+    pub storages: Vec<StorageSnapshot>,
+    pub markets: Vec<MarketSnapshot>,
+    pub cities: Vec<CitySnapshot>,
+    pub pending_spawns: Vec<(Vec<u8>, PendingSpawn)>,
+    pub active_combats: Vec<ActiveCombat>,
+    pub status_effects: Vec<(Vec<u8>, StatusEffect)>,  // (entity ulid, effect), sorted by ulid
+    pub status_immunities: Vec<(Vec<u8>, Vec<StatusKind>)>,
+    pub combat_profiles: Vec<(String, CombatProfile)>,  // entity_type -> profile
+    pub projectile_splash: Vec<(u8, SplashProfile)>,  // projectile_type -> burst (redefined types only)
+    pub threat: Vec<(Vec<u8>, Vec<u8>, f32)>,  // (holder, enemy, threat)
+    pub turn_mode: bool,
+    pub turn: u64,  // Turns completed (StepTurn)
+    pub ledger: Vec<LedgerEntry>,  // Oldest first
+    pub next_transaction_id: u64,
+    pub shortage_config: ShortageConfig,
+}
+
+impl GameSnapshot {
+    /// Encode with the current version header
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SaveError> {
+        let state = serde_json::to_value(self).map_err(|e| SaveError::Encode(e.to_string()))?;
+        let file = serde_json::json!({
+            "format": SAVE_FORMAT_NAME,
+            "version": SAVE_FORMAT_VERSION,
+            "state": state,
+        });
+        serde_json::to_vec(&file).map_err(|e| SaveError::Encode(e.to_string()))
+    }
+
+    /// Decode, migrating older versions up to `SAVE_FORMAT_VERSION`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SaveError> {
+        Self::from_bytes_with(bytes, MIGRATIONS)
+    }
+
+    fn from_bytes_with(bytes: &[u8], migrations: &[Migration]) -> Result<Self, SaveError> {
+        let mut file: Value = serde_json::from_slice(bytes).map_err(|e| SaveError::Decode(e.to_string()))?;
+
+        if file.get("format").and_then(Value::as_str) != Some(SAVE_FORMAT_NAME) {
+            return Err(SaveError::NotASave);
+        }
+
+        let version = file.get("version")
+            .and_then(Value::as_u64)
+            .ok_or(SaveError::NotASave)? as u32;
+        let newest = migrations.len() as u32 + 1;
+        if version == 0 || version > newest {
+            return Err(SaveError::UnsupportedVersion(version));
+        }
+
+        let mut state = file.get_mut("state").map(Value::take).ok_or(SaveError::NotASave)?;
+        for (from_version, migrate) in (version..newest).zip(&migrations[version as usize - 1..]) {
+            migrate(&mut state).map_err(|e| SaveError::Migration {
+                from_version,
+                error: e.to_string(),
+            })?;
+        }
+
+        serde_json::from_value(state).map_err(|e| SaveError::Decode(e.to_string()))
+    }
+
+    /// Write via a temporary file so a failed save never clobbers the previous one
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SaveError> {
+        let path = path.as_ref();
+        let bytes = self.to_bytes()?;
+        let tmp_path = path.with_extension("tmp");
+
+        std::fs::write(&tmp_path, bytes).map_err(|e| SaveError::Io(e.to_string()))?;
+        std::fs::rename(&tmp_path, path).map_err(|e| SaveError::Io(e.to_string()))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SaveError> {
+        let bytes = std::fs::read(path).map_err(|e| SaveError::Io(e.to_string()))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::TerrainType;
+
+    fn sample_snapshot() -> GameSnapshot {
+        let ulid = vec![7; 16];
+        let entity = EntityData::new(ulid.clone(), (3, 4), TerrainType::Land, "viking".to_string());
+        let mut card = CardData::new_standard(vec![9; 16], 2, 12);
+        card.place_on_board(1, -1);
+
+        GameSnapshot {
+            entity_stats: vec![(ulid.clone(), entity.stats.clone())],
+            entity_player_ulids: vec![(ulid.clone(), vec![1; 16])],
+            entities: vec![entity],
+            cards: vec![card],
+            resources: vec![ResourceSnapshot { player_ulid: vec![1; 16], resource_type: 0, current: 50.0, cap: 100.0, rate: 1.5 }],
+            producers: vec![FlowSnapshot { ulid: ulid.clone(), player_ulid: vec![1; 16], resource_type: 0, rate: 2.0, active: true }],
+            consumers: Vec::new(),
+            recipes: vec![RecipeSnapshot {
+                ulid: vec![6; 16],
+                player_ulid: vec![1; 16],
+                recipe: Recipe { inputs: vec![(2, 1.0)], outputs: vec![(0, 3.0)], cycle_secs: 10.0 },
+                active: true,
+                progress: 4.0,
+                inputs_paid: true,
+                cycles_completed: 2,
+            }],
+            storages: vec![StorageSnapshot { ulid: vec![8; 16], player_ulid: vec![1; 16], protection: vec![(1, 0.5)] }],
+            markets: vec![MarketSnapshot { market_id: 4, trade_modifier: -0.25, wealth: 75.0, stock: vec![(1, 1500.0)] }],
+            cities: vec![CitySnapshot { city_id: 4, player_ulid: vec![1; 16], population: 5000, wealth: 75.0, reputation: 50.0, tax_rate: 0.2 }],
+            pending_spawns: vec![(vec![8; 16], PendingSpawn {
+                entity_type: "king".to_string(),
+                terrain_type: crate::terrain_cache::TerrainType::Land,
+                preferred_location: (0, 0),
+                search_radius: 5,
+            })],
+            active_combats: vec![ActiveCombat {
+                attacker_ulid: ulid,
+                defender_ulid: vec![2; 16],
+                time_since_last_attack: 0.5,
+                attack_interval: 1.5,
+            }],
+            status_effects: vec![(vec![2; 16], StatusEffect {
+                kind: StatusKind::Poison,
+                stacks: 3,
+                remaining: 4.5,
+                magnitude: 2.0,
+                tick_timer: 0.5,
+                source_ulid: vec![7; 16],
+            })],
+            status_immunities: vec![(vec![7; 16], vec![StatusKind::Stun])],
+            combat_profiles: vec![("tower".to_string(), CombatProfile {
+                targeting: crate::types::TargetingPolicy::LowestHp,
+                is_structure: true,
+            })],
+            projectile_splash: vec![(3, SplashProfile { radius: 2, falloff: 0.25, friendly_fire: true })],
+            threat: vec![(vec![2; 16], vec![7; 16], 12.5)],
+            turn_mode: true,
+            turn: 12,
+            ledger: vec![LedgerEntry {
+                transaction_id: 3,
+                player_ulid: vec![1; 16],
+                reason: crate::types::TransactionReason::Loot,
+                changes: vec![(0, 25.0)],
+                reverts: None,
+                rolled_back: false,
+            }],
+            next_transaction_id: 4,
+            shortage_config: ShortageConfig { starvation_hp: 0.0, ..Default::default() },
+        }
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let bytes = sample_snapshot().to_bytes().unwrap();
+        let decoded = GameSnapshot::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded.entities.len(), 1);
+        assert_eq!(decoded.entities[0].position, (3, 4));
+        assert_eq!(decoded.entity_stats[0].1.get(crate::entity::StatType::HP), 50.0);
+        assert_eq!(decoded.cards[0].position, Some((1, -1)));
+        assert_eq!(decoded.pending_spawns[0].1.search_radius, 5);
+        assert_eq!(decoded.active_combats[0].time_since_last_attack, 0.5);
+        assert_eq!((decoded.turn_mode, decoded.turn), (true, 12));
+        assert_eq!(decoded.ledger[0].changes, vec![(0, 25.0)]);
+        assert_eq!(decoded.shortage_config.starvation_hp, 0.0);
+        assert_eq!(decoded.recipes[0].progress, 4.0);
+        assert_eq!(decoded.storages[0].protection, vec![(1, 0.5)]);
+        assert_eq!(decoded.markets[0].stock, vec![(1, 1500.0)]);
+        assert_eq!(decoded.cities[0].population, 5000);
+        assert_eq!(decoded.status_effects[0].1.stacks, 3);
+        assert_eq!(decoded.status_immunities[0].1, vec![StatusKind::Stun]);
+        assert!(decoded.combat_profiles[0].1.is_structure);
+        assert_eq!(decoded.threat[0].2, 12.5);
+        assert!(decoded.projectile_splash[0].1.friendly_fire);
+    }
+
+    #[test]
+    fn test_v1_saves_load_in_real_time_mode() {
+        let mut state = serde_json::to_value(sample_snapshot()).unwrap();
+        state.as_object_mut().unwrap().remove("turn_mode");
+        state.as_object_mut().unwrap().remove("turn");
+        state.as_object_mut().unwrap().remove("ledger");
+        state.as_object_mut().unwrap().remove("next_transaction_id");
+        state.as_object_mut().unwrap().remove("shortage_config");
+        state.as_object_mut().unwrap().remove("recipes");
+        state.as_object_mut().unwrap().remove("storages");
+        state.as_object_mut().unwrap().remove("markets");
+        state.as_object_mut().unwrap().remove("cities");
+        state.as_object_mut().unwrap().remove("status_effects");
+        state.as_object_mut().unwrap().remove("status_immunities");
+        state.as_object_mut().unwrap().remove("combat_profiles");
+        state.as_object_mut().unwrap().remove("threat");
+        state.as_object_mut().unwrap().remove("projectile_splash");
+
+        let file = serde_json::json!({ "format": SAVE_FORMAT_NAME, "version": 1, "state": state });
+        let decoded = GameSnapshot::from_bytes(&serde_json::to_vec(&file).unwrap()).unwrap();
+        assert_eq!((decoded.turn_mode, decoded.turn), (false, 0));
+        assert_eq!(decoded.shortage_config, ShortageConfig::default());
+    }
+
+    #[test]
+    fn test_v2_pools_become_the_neutral_pool() {
+        let mut state = serde_json::to_value(sample_snapshot()).unwrap();
+        state.as_object_mut().unwrap().remove("ledger");
+        state.as_object_mut().unwrap().remove("next_transaction_id");
+        state.as_object_mut().unwrap().remove("shortage_config");
+        state.as_object_mut().unwrap().remove("recipes");
+        state.as_object_mut().unwrap().remove("storages");
+        state.as_object_mut().unwrap().remove("markets");
+        state.as_object_mut().unwrap().remove("cities");
+        state.as_object_mut().unwrap().remove("status_effects");
+        state.as_object_mut().unwrap().remove("status_immunities");
+        state.as_object_mut().unwrap().remove("combat_profiles");
+        state.as_object_mut().unwrap().remove("threat");
+        state.as_object_mut().unwrap().remove("projectile_splash");
+        for key in ["resources", "producers"] {
+            state[key][0].as_object_mut().unwrap().remove("player_ulid");
+        }
+
+        let file = serde_json::json!({ "format": SAVE_FORMAT_NAME, "version": 2, "state": state });
+        let decoded = GameSnapshot::from_bytes(&serde_json::to_vec(&file).unwrap()).unwrap();
+        assert!(decoded.resources[0].player_ulid.is_empty());
+        assert!(decoded.producers[0].player_ulid.is_empty());
+    }
+
+    #[test]
+    fn test_rejects_foreign_and_future_files() {
+        assert!(matches!(GameSnapshot::from_bytes(b"{\"hello\": 1}"), Err(SaveError::NotASave)));
+
+        let future = serde_json::json!({ "format": SAVE_FORMAT_NAME, "version": 99, "state": {} });
+        let bytes = serde_json::to_vec(&future).unwrap();
+        assert!(matches!(GameSnapshot::from_bytes(&bytes), Err(SaveError::UnsupportedVersion(99))));
+    }
+
+    #[test]
+    fn test_migrations_run_in_order() {
+        // Pretend v1 stored resources under an old key and v2 lacked consumers
+        fn rename_resources(state: &mut Value) -> Result<(), SaveError> {
+            let old = state.as_object_mut().unwrap().remove("pools").unwrap_or_default();
+            state["resources"] = old;
+            Ok(())
+        }
+        fn add_consumers(state: &mut Value) -> Result<(), SaveError> {
+            state["consumers"] = serde_json::json!([]);
+            Ok(())
+        }
+
+        let mut state = serde_json::to_value(sample_snapshot()).unwrap();
+        let resources = state.as_object_mut().unwrap().remove("resources").unwrap();
+        state.as_object_mut().unwrap().remove("consumers");
+        state["pools"] = resources;
+
+        let file = serde_json::json!({ "format": SAVE_FORMAT_NAME, "version": 1, "state": state });
+        let bytes = serde_json::to_vec(&file).unwrap();
+        let migrations: &[Migration] = &[rename_resources, add_consumers];
+
+        let decoded = GameSnapshot::from_bytes_with(&bytes, migrations).unwrap();
+        assert_eq!(decoded.resources[0].current, 50.0);
+        assert!(decoded.consumers.is_empty());
+    }
+}
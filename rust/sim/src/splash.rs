@@ -0,0 +1,79 @@
+// Splash damage - projectiles that burst where they land
+
+use crate::entity::ProjectileType;
+use crate::range_calculator::hex_distance;
+use crate::targeting::are_enemies;
+use crate::terrain_cache::HexCoord;
+use crate::types::SplashProfile;
+
+/// A living entity near an impact: (ulid, player_ulid, position)
+pub type SplashCandidate = (Vec<u8>, Vec<u8>, HexCoord);
+
+/// How a projectile type bursts when nothing redefines it: fire and lightning splash, the rest don't
+pub fn default_splash(projectile: ProjectileType) -> SplashProfile {
+    let (radius, falloff) = match projectile {
+        ProjectileType::FireBolt => (1, 0.5),
+        ProjectileType::Lightning => (1, 0.25),
+        _ => return SplashProfile::default(),
+    };
+    SplashProfile { radius, falloff, friendly_fire: false }
+}
+
+/// Share of the power that reaches a victim `distance` hexes from the impact (0.0 = out of reach)
+pub fn splash_multiplier(splash: &SplashProfile, distance: i32) -> f32 {
+    if distance > splash.radius {
+        return 0.0;
+    }
+    (1.0 - splash.falloff * distance as f32).clamp(0.0, 1.0)
+}
+
+/// Who a burst at `impact` catches besides the target, with the share of power each takes
+/// Only the shooter's enemies unless the type has friendly fire, and never the shooter itself
+/// `shooter` is (ulid, player_ulid); victims keep the candidates' order
+pub fn splash_victims(
+    splash: &SplashProfile,
+    impact: HexCoord,
+    shooter: (&[u8], &[u8]),
+    target_ulid: &[u8],
+    candidates: &[SplashCandidate],
+) -> Vec<(Vec<u8>, f32)> {
+    let (shooter_ulid, shooter_team) = shooter;
+    candidates.iter()
+        .filter(|(ulid, _, _)| ulid != target_ulid && ulid != shooter_ulid)
+        .filter(|(_, team, _)| splash.friendly_fire || are_enemies(shooter_team, team))
+        .map(|(ulid, _, position)| (ulid.clone(), splash_multiplier(splash, hex_distance(impact, *position))))
+        .filter(|(_, share)| *share > 0.0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_falls_off_and_respects_friendly_fire() {
+        let splash = SplashProfile { radius: 2, falloff: 0.25, friendly_fire: false };
+        let candidates = vec![
+            (vec![1; 16], vec![1; 16], (0, 0)),  // Shooter, standing in its own blast
+            (vec![2; 16], vec![2; 16], (5, 0)),  // Target
+            (vec![3; 16], vec![2; 16], (6, 0)),  // Enemy next to it
+            (vec![4; 16], vec![2; 16], (7, 0)),  // Enemy at the edge
+            (vec![5; 16], vec![2; 16], (8, 0)),  // Enemy out of reach
+            (vec![6; 16], vec![1; 16], (4, 0)),  // Shooter's ally
+        ];
+        let shooter = (&[1u8; 16][..], &[1u8; 16][..]);
+
+        let victims = splash_victims(&splash, (5, 0), shooter, &[2; 16], &candidates);
+        assert_eq!(victims, vec![(vec![3; 16], 0.75), (vec![4; 16], 0.5)]);
+
+        // Friendly fire catches the ally but never the shooter
+        let reckless = SplashProfile { friendly_fire: true, ..splash };
+        let victims = splash_victims(&reckless, (2, 0), shooter, &[2; 16], &candidates);
+        assert_eq!(victims, vec![(vec![6; 16], 0.5)]);
+
+        // Steep falloff fades out before the radius; single-target types don't splash
+        assert_eq!(splash_multiplier(&SplashProfile { radius: 3, falloff: 0.5, friendly_fire: false }, 2), 0.0);
+        assert_eq!(default_splash(ProjectileType::Arrow).radius, 0);
+        assert_eq!(splash_multiplier(&default_splash(ProjectileType::FireBolt), 1), 0.5);
+    }
+}
@@ -0,0 +1,164 @@
+// Status effects - timed conditions on entities (poison, burn, stun, slow, regen)
+
+use crate::entity::ProjectileType;
+use crate::types::{HitOutcome, StatusEffect, StatusKind};
+
+/// Seconds between poison/burn/regen damage or heal ticks
+pub const STATUS_TICK_SECS: f32 = 1.0;
+/// Slowest a Slow can make attacks and movement (fraction of normal speed)
+pub const MIN_SLOW_MULTIPLIER: f32 = 0.1;
+
+/// Effect a projectile may leave on what it hits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusOnHit {
+    pub kind: StatusKind,
+    pub chance: f32,     // 0.0-1.0 per landed hit
+    pub duration: f32,   // Seconds
+    pub magnitude: f32,  // See StatusEffect::magnitude
+}
+
+/// What a projectile type inflicts: fire burns, ice slows, lightning may stun, shadow and arrows poison
+pub fn on_hit_status(projectile: ProjectileType) -> Option<StatusOnHit> {
+    let (kind, chance, duration, magnitude) = match projectile {
+        ProjectileType::FireBolt => (StatusKind::Burn, 1.0, 4.0, 3.0),
+        ProjectileType::ShadowBolt => (StatusKind::Poison, 1.0, 8.0, 2.0),
+        ProjectileType::IceShard => (StatusKind::Slow, 1.0, 3.0, 0.5),
+        ProjectileType::Lightning => (StatusKind::Stun, 0.3, 1.5, 0.0),
+        ProjectileType::Arrow => (StatusKind::Poison, 0.25, 6.0, 1.0),
+        ProjectileType::Spear | ProjectileType::None => return None,
+    };
+    Some(StatusOnHit { kind, chance, duration, magnitude })
+}
+
+/// Whether a hit can carry its projectile's effect
+/// Magic takes hold on anything that connects; physical projectiles need a clean hit (not glancing)
+pub fn hit_applies_status(projectile: ProjectileType, outcome: HitOutcome) -> bool {
+    match outcome {
+        HitOutcome::Dodged => false,
+        HitOutcome::Glancing => projectile.is_magic(),
+        HitOutcome::Normal | HitOutcome::Critical => true,
+    }
+}
+
+/// HP moved by one advance of an entity's effects
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatusTick {
+    pub damage: f32,
+    pub heal: f32,
+    pub expired: Vec<StatusKind>,
+}
+
+/// Add an effect to an entity's effects, returning the effect as it now stands
+/// An effect of the same kind gains a stack (up to max_stacks), keeps the longer duration and the
+/// stronger magnitude, and keeps its tick cadence; anything else starts at one stack
+pub fn apply_status(effects: &mut Vec<StatusEffect>, effect: StatusEffect) -> StatusEffect {
+    if let Some(existing) = effects.iter_mut().find(|e| e.kind == effect.kind) {
+        existing.stacks = (existing.stacks + 1).min(effect.kind.max_stacks());
+        existing.remaining = existing.remaining.max(effect.remaining);
+        existing.magnitude = existing.magnitude.max(effect.magnitude);
+        existing.source_ulid = effect.source_ulid;
+        return existing.clone();
+    }
+
+    let effect = StatusEffect { stacks: 1, tick_timer: 0.0, ..effect };
+    effects.push(effect.clone());
+    effect
+}
+
+/// Advance an entity's effects by `delta` seconds, dropping the ones that run out
+/// Periodic ticks only count time the effect was actually active
+pub fn advance_statuses(effects: &mut Vec<StatusEffect>, delta: f32) -> StatusTick {
+    let mut tick = StatusTick::default();
+
+    for effect in effects.iter_mut() {
+        effect.tick_timer += delta.min(effect.remaining);
+        while effect.tick_timer >= STATUS_TICK_SECS {
+            effect.tick_timer -= STATUS_TICK_SECS;
+            let amount = effect.magnitude * effect.stacks as f32;
+            match effect.kind {
+                StatusKind::Poison | StatusKind::Burn => tick.damage += amount,
+                StatusKind::Regen => tick.heal += amount,
+                StatusKind::Stun | StatusKind::Slow => {}
+            }
+        }
+        effect.remaining -= delta;
+    }
+
+    effects.retain(|effect| {
+        let alive = effect.remaining > 0.0;
+        if !alive {
+            tick.expired.push(effect.kind);
+        }
+        alive
+    });
+    tick
+}
+
+pub fn is_stunned(effects: &[StatusEffect]) -> bool {
+    effects.iter().any(|e| e.kind == StatusKind::Stun)
+}
+
+/// Attack timer speed (1.0 = normal); a Slow's magnitude is the fraction it takes off
+pub fn attack_speed_multiplier(effects: &[StatusEffect]) -> f32 {
+    effects.iter()
+        .filter(|e| e.kind == StatusKind::Slow)
+        .map(|e| (1.0 - e.magnitude).max(MIN_SLOW_MULTIPLIER))
+        .fold(1.0, f32::min)
+}
+
+/// Movement speed (1.0 = normal, 0.0 = stunned in place), for the host to apply
+pub fn move_speed_multiplier(effects: &[StatusEffect]) -> f32 {
+    if is_stunned(effects) {
+        0.0
+    } else {
+        attack_speed_multiplier(effects)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn effect(kind: StatusKind, remaining: f32, magnitude: f32) -> StatusEffect {
+        StatusEffect { kind, stacks: 1, remaining, magnitude, tick_timer: 0.0, source_ulid: vec![1; 16] }
+    }
+
+    #[test]
+    fn test_effects_stack_tick_and_expire() {
+        let mut effects = Vec::new();
+
+        // Poison stacks to its cap and keeps the longest duration
+        for _ in 0..7 {
+            apply_status(&mut effects, effect(StatusKind::Poison, 3.0, 1.0));
+        }
+        let poison = apply_status(&mut effects, effect(StatusKind::Poison, 5.0, 2.0));
+        assert_eq!((poison.stacks, poison.remaining, poison.magnitude), (5, 5.0, 2.0));
+
+        // Stun refreshes instead of stacking; slow and stun both modify speed
+        apply_status(&mut effects, effect(StatusKind::Stun, 1.0, 0.0));
+        assert_eq!(apply_status(&mut effects, effect(StatusKind::Stun, 0.5, 0.0)).stacks, 1);
+        apply_status(&mut effects, effect(StatusKind::Slow, 2.0, 0.5));
+        assert!(is_stunned(&effects));
+        assert_eq!(attack_speed_multiplier(&effects), 0.5);
+        assert_eq!(move_speed_multiplier(&effects), 0.0);
+
+        // 1.5s: one poison tick (5 stacks x 2 HP), stun wears off
+        let tick = advance_statuses(&mut effects, 1.5);
+        assert_eq!(tick, StatusTick { damage: 10.0, heal: 0.0, expired: vec![StatusKind::Stun] });
+        assert_eq!(move_speed_multiplier(&effects), 0.5);
+
+        // Ticks stop with the effect: 3.5s left of poison (plus 0.5s carried over) is four more ticks
+        let tick = advance_statuses(&mut effects, 10.0);
+        assert_eq!(tick.damage, 40.0);
+        assert_eq!(tick.expired, vec![StatusKind::Poison, StatusKind::Slow]);
+        assert!(effects.is_empty());
+
+        // Regen heals; arrows only poison on a clean hit, magic on anything that connects
+        apply_status(&mut effects, effect(StatusKind::Regen, 2.0, 4.0));
+        assert_eq!(advance_statuses(&mut effects, 2.0).heal, 8.0);
+        assert!(!hit_applies_status(ProjectileType::Arrow, HitOutcome::Glancing));
+        assert!(hit_applies_status(ProjectileType::FireBolt, HitOutcome::Glancing));
+        assert!(!hit_applies_status(ProjectileType::FireBolt, HitOutcome::Dodged));
+        assert_eq!(on_hit_status(ProjectileType::FireBolt).map(|s| s.kind), Some(StatusKind::Burn));
+    }
+}
@@ -0,0 +1,266 @@
+// Card system - owns the card registry (SINGLE SOURCE OF TRUTH for card placement) and combo detection
+
+use std::collections::HashSet;
+
+use crate::card::CardData;
+use crate::card_combo::ComboResult;
+use crate::card_registry::CardRegistry;
+use crate::savegame::{GameSnapshot, SaveError};
+use crate::types::{GameEvent, GameRequest, TransactionReason, TurnCombo, TurnSummary};
+use super::{GameSystem, SystemContext, TurnPhase, World};
+
+/// Combo radius checked around cards placed during a turn (same as the hand UI uses)
+const TURN_COMBO_RADIUS: i32 = 7;
+
+pub struct CardSystem {
+    card_registry: CardRegistry,
+    turn_mode: bool,
+    placed_this_turn: Vec<(i32, i32, Vec<u8>)>,  // Turn mode: (x, y, placing player) combo centers for the next combo rewards phase
+}
+
+impl Default for CardSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CardSystem {
+    pub fn new() -> Self {
+        Self {
+            card_registry: CardRegistry::new(),
+            turn_mode: false,
+            placed_this_turn: Vec::new(),
+        }
+    }
+
+    pub fn registry(&self) -> &CardRegistry {
+        &self.card_registry
+    }
+
+    /// Build a registry from saved cards, rejecting cards without a position or on the same hex
+    fn registry_from(cards: &[CardData]) -> Result<CardRegistry, SaveError> {
+        let card_registry = CardRegistry::new();
+        for card in cards {
+            let Some((x, y)) = card.position else {
+                return Err(SaveError::Invalid("card on board has no position".to_string()));
+            };
+            if !card_registry.place_card(x, y, card.clone()) {
+                return Err(SaveError::Invalid(format!("duplicate card at ({}, {})", x, y)));
+            }
+        }
+        Ok(card_registry)
+    }
+
+    /// Detect the combo around a position, with optional hypothetical cards on top of the board
+    /// Returns None when there are fewer than 5 cards or only a High Card
+    pub fn evaluate_combo(
+        &self,
+        center_x: i32,
+        center_y: i32,
+        radius: i32,
+        extra_cards: &[(i32, i32, u8, u8)],
+    ) -> Option<ComboResult> {
+        use crate::card_combo::{PositionedCard, ComboDetector};
+
+        // Get cards in radius from the card registry (SINGLE SOURCE OF TRUTH)
+        let mut cards_in_radius = self.card_registry.get_cards_in_radius(center_x, center_y, radius);
+
+        // Hypothetical cards only count inside the radius and on empty hexes
+        for &(x, y, suit, value) in extra_cards {
+            let (dx, dy) = (x - center_x, y - center_y);
+            let in_radius = dx * dx + dy * dy <= radius * radius;  // Same metric as get_cards_in_radius
+            if in_radius && !self.card_registry.has_card_at(x, y) && suit <= 3 && (1..=13).contains(&value) {
+                cards_in_radius.push((x, y, CardData::new_standard(Vec::new(), suit, value)));
+            }
+        }
+
+        sim_print!("Actor: Combo evaluation at ({}, {}) radius {} - found {} cards",
+            center_x, center_y, radius, cards_in_radius.len());
+
+        // Need at least 5 cards for a combo
+        if cards_in_radius.len() < 5 {
+            sim_print!("Actor: Not enough cards for combo (need 5, have {})", cards_in_radius.len());
+            return None;
+        }
+
+        // Convert to PositionedCard structs
+        let positioned_cards: Vec<PositionedCard> = cards_in_radius
+            .iter()
+            .enumerate()
+            .map(|(index, (x, y, card))| PositionedCard {
+                card: card.clone(),
+                x: *x,
+                y: *y,
+                index,
+            })
+            .collect();
+
+        // Detect combo using the spatial poker hand detection
+        let combo_result = ComboDetector::detect_combo(&positioned_cards);
+
+        sim_print!("Actor: Combo detection result: {:?} (rank: {:?})",
+            combo_result.hand.to_string(), combo_result.hand as i32);
+
+        // Skip "High Card" (rank 0) - not a real combo
+        if combo_result.hand as i32 == 0 {
+            return None;
+        }
+
+        Some(combo_result)
+    }
+}
+
+impl GameSystem for CardSystem {
+    fn name(&self) -> &'static str {
+        "cards"
+    }
+
+    fn handle_request(&mut self, request: GameRequest, ctx: &mut SystemContext) -> Option<GameRequest> {
+        match request {
+            GameRequest::PlaceCard { x, y, ulid, player_ulid, suit, value, card_id, is_custom } => {
+                let card = CardData {
+                    ulid,
+                    suit,
+                    value,
+                    card_id,
+                    is_custom,
+                    state: crate::card::CardState::OnBoard,
+                    position: Some((x, y)),
+                    owner_id: None,  // No owner for placed cards
+                };
+
+                let success = self.card_registry.place_card(x, y, card);
+                if !success {
+                    sim_warn!("Actor: Failed to place card at ({}, {}) - position occupied", x, y);
+                } else if self.turn_mode {
+                    self.placed_this_turn.push((x, y, player_ulid));
+                }
+                // Note: We could emit a CardPlaced event here for GDScript to sync visuals
+            }
+
+            GameRequest::RemoveCardAt { x, y } => {
+                let _removed = self.card_registry.remove_card_at(x, y);
+                // Could emit CardRemoved event
+            }
+
+            GameRequest::RemoveCardByUlid { ulid } => {
+                let _removed = self.card_registry.remove_card_by_ulid(&ulid);
+            }
+
+            GameRequest::DetectCombo { center_x, center_y, radius } => {
+                if let Some(combo_result) = self.evaluate_combo(center_x, center_y, radius, &[]) {
+                    // Convert resource bonuses to (resource_type, amount) tuples
+                    let resource_bonuses: Vec<(i32, f32)> = combo_result.resource_bonuses
+                        .iter()
+                        .map(|bonus| (bonus.resource_type as i32, bonus.amount))
+                        .collect();
+
+                    // Emit ComboDetected event
+                    let _ = ctx.events.send(GameEvent::ComboDetected {
+                        hand_rank: combo_result.hand as i32,
+                        hand_name: combo_result.hand.to_string(),
+                        card_positions: combo_result.positions.clone(),
+                        resource_bonuses,
+                    });
+                }
+            }
+
+            GameRequest::QueryCombo { request_id, center_x, center_y, radius, extra_cards } => {
+                let reply = match self.evaluate_combo(center_x, center_y, radius, &extra_cards) {
+                    Some(combo_result) => GameEvent::ComboReply {
+                        request_id,
+                        hand_rank: combo_result.hand as i32,
+                        hand_name: combo_result.hand.to_string(),
+                        card_positions: combo_result.positions.clone(),
+                        resource_bonuses: combo_result.resource_bonuses
+                            .iter()
+                            .map(|bonus| (bonus.resource_type as i32, bonus.amount))
+                            .collect(),
+                    },
+                    None => GameEvent::ComboReply {
+                        request_id,
+                        hand_rank: 0,
+                        hand_name: String::new(),
+                        card_positions: Vec::new(),
+                        resource_bonuses: Vec::new(),
+                    },
+                };
+                let _ = ctx.events.send(reply);
+            }
+
+            other => return Some(other),
+        }
+
+        None
+    }
+
+    fn snapshot(&self, _world: &World, out: &mut GameSnapshot) {
+        out.cards = self.card_registry.get_all_cards().into_iter().map(|(_, _, card)| card).collect();
+        out.cards.sort_by(|a, b| a.ulid.cmp(&b.ulid));
+    }
+
+    fn validate(&self, snapshot: &GameSnapshot) -> Result<(), SaveError> {
+        Self::registry_from(&snapshot.cards).map(|_| ())
+    }
+
+    fn restore(&mut self, snapshot: &GameSnapshot, _ctx: &mut SystemContext) {
+        // Already validated, so this cannot fail
+        if let Ok(card_registry) = Self::registry_from(&snapshot.cards) {
+            self.card_registry = card_registry;
+        }
+        self.placed_this_turn.clear();
+    }
+
+    fn set_turn_mode(&mut self, enabled: bool, _ctx: &mut SystemContext) {
+        self.turn_mode = enabled;
+        self.placed_this_turn.clear();
+    }
+
+    fn turn_phase(&mut self, phase: TurnPhase, summary: &mut TurnSummary, ctx: &mut SystemContext) {
+        if phase != TurnPhase::ComboRewards {
+            return;
+        }
+
+        // Two placed cards in the same hand must only pay out once
+        let mut rewarded: HashSet<Vec<(i32, i32)>> = HashSet::new();
+        for (x, y, player_ulid) in std::mem::take(&mut self.placed_this_turn) {
+            let Some(combo_result) = self.evaluate_combo(x, y, TURN_COMBO_RADIUS, &[]) else {
+                continue;
+            };
+            let mut hand = combo_result.positions.clone();
+            hand.sort_unstable();
+            if !rewarded.insert(hand) {
+                continue;
+            }
+
+            let resource_bonuses: Vec<(i32, f32)> = combo_result.resource_bonuses
+                .iter()
+                .map(|bonus| (bonus.resource_type as i32, bonus.amount))
+                .collect();
+
+            // Credited to the placing player's pool by the economy right after this phase
+            ctx.queue_request(GameRequest::ApplyTransaction {
+                request_id: 0,
+                player_ulid: player_ulid.clone(),
+                reason: TransactionReason::ComboReward,
+                changes: resource_bonuses.iter()
+                    .map(|&(resource_type, amount)| (resource_type as i64, amount as f64))
+                    .collect(),
+            });
+
+            let _ = ctx.events.send(GameEvent::ComboDetected {
+                hand_rank: combo_result.hand as i32,
+                hand_name: combo_result.hand.to_string(),
+                card_positions: combo_result.positions.clone(),
+                resource_bonuses: resource_bonuses.clone(),
+            });
+            summary.combos.push(TurnCombo {
+                player_ulid,
+                hand_rank: combo_result.hand as i32,
+                hand_name: combo_result.hand.to_string(),
+                card_positions: combo_result.positions,
+                resource_bonuses,
+            });
+        }
+    }
+}
@@ -0,0 +1,742 @@
+// Combat system - periodic combat ticks on the combat worker, damage/heal application
+
+use std::collections::{HashMap, HashSet};
+
+use rand::Rng;
+
+use crate::damage::resolve_hit;
+use crate::entity::{ProjectileType, StatType, ENTITY_STATS};
+use crate::metrics::ActorMetrics;
+use crate::savegame::GameSnapshot;
+use crate::splash::{default_splash, splash_victims, SplashCandidate};
+use crate::status::{hit_applies_status, on_hit_status};
+use crate::targeting::{are_enemies, ThreatTable, THREAT_PER_DAMAGE};
+use crate::types::{
+    ActiveCombat, CombatEntitySnapshot, CombatProfile, CombatWorkRequest, CombatWorkResult, GameEvent, GameRequest,
+    SplashProfile, StatusEffect, StatusKind, TurnSummary,
+};
+use crate::range_calculator::{cover_evasion, hex_distance};
+use crate::workers::{process_combat_tick, restore_combats, restore_statuses, spawn_combat_worker, CombatInstance};
+use super::{GameSystem, SystemContext, TurnPhase, World, WorkerLink};
+
+/// Combat worker cadence (simulated seconds); each combat tick covers all time since the last one
+const COMBAT_TICK_INTERVAL: f64 = 0.5;
+/// Combat ticks that may wait for the worker
+const COMBAT_QUEUE_CAPACITY: usize = 4;
+/// Combat ticks per turn (one 1.5s attack interval, so every engaged unit attacks once)
+const TURN_COMBAT_ROUNDS: usize = 3;
+
+pub struct CombatSystem {
+    worker: WorkerLink<CombatWorkRequest, CombatWorkResult>,
+    inline_combats: HashMap<Vec<u8>, CombatInstance>,  // Deterministic or turn mode only
+    inline_statuses: HashMap<Vec<u8>, Vec<StatusEffect>>,  // Deterministic or turn mode only
+    active_combats: Vec<ActiveCombat>,  // Latest combat worker state (for SaveGame)
+    active_statuses: Vec<(Vec<u8>, StatusEffect)>,  // Latest combat worker status effects (for SaveGame)
+    pending_statuses: Vec<(Vec<u8>, StatusEffect)>,  // Applied since the last combat tick
+    immunities: HashMap<Vec<u8>, Vec<StatusKind>>,
+    profiles: HashMap<String, CombatProfile>,  // entity_type -> targeting (SetCombatProfile)
+    splash: HashMap<u8, SplashProfile>,  // projectile_type -> burst (SetProjectileSplash)
+    threat: ThreatTable,
+    combat_timer: f64,
+    turn_mode: bool,
+}
+
+impl Default for CombatSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CombatSystem {
+    pub fn new() -> Self {
+        Self {
+            worker: WorkerLink::new(COMBAT_QUEUE_CAPACITY),
+            inline_combats: HashMap::new(),
+            inline_statuses: HashMap::new(),
+            active_combats: Vec::new(),
+            active_statuses: Vec::new(),
+            pending_statuses: Vec::new(),
+            immunities: HashMap::new(),
+            profiles: HashMap::new(),
+            splash: HashMap::new(),
+            threat: ThreatTable::default(),
+            combat_timer: 0.0,
+            turn_mode: false,
+        }
+    }
+
+    /// Apply damage to a defender's HP and emit the resulting events
+    /// Fractional HP below 1.0 counts as dead (prevents "zombie" entities)
+    fn apply_hp_damage(world: &World, ctx: &SystemContext, defender_ulid: &[u8], damage: f32) -> Option<f32> {
+        let mut stats = world.entity_stats.get_mut(defender_ulid)?;
+        let current_hp = stats.value().get(StatType::HP);
+        let mut new_hp = (current_hp - damage).max(0.0);
+
+        // CRITICAL: If HP would be fractional and < 1.0, set to 0.0 (entity should die)
+        if new_hp > 0.0 && new_hp < 1.0 {
+            new_hp = 0.0;
+        }
+
+        stats.value_mut().set(StatType::HP, new_hp);
+
+        // CRITICAL: Sync to global cache so GDScript sees updated HP
+        if let Some(mut cache) = ENTITY_STATS.get_mut(defender_ulid) {
+            cache.set(StatType::HP, new_hp);
+        }
+
+        // Emit event with updated HP
+        let _ = ctx.events.send(GameEvent::EntityDamaged {
+            ulid: defender_ulid.to_vec(),
+            damage,
+            new_hp,
+        });
+
+        // CRITICAL: Check for death immediately after applying damage
+        if new_hp <= 0.0 {
+            let _ = ctx.events.send(GameEvent::EntityDied {
+                ulid: defender_ulid.to_vec(),
+            });
+        }
+
+        Some(new_hp)
+    }
+
+    /// A projectile type's burst: its SetProjectileSplash definition, else its default
+    fn splash_profile(&self, projectile: ProjectileType) -> SplashProfile {
+        self.splash.get(&projectile.to_u8()).copied().unwrap_or_else(|| default_splash(projectile))
+    }
+
+    /// Living entities a burst at `impact` catches besides its target, with their share of the power
+    /// Sorted by ulid, so their rolls replay in the same order
+    fn burst_victims(
+        world: &World,
+        splash: &SplashProfile,
+        impact: (i32, i32),
+        attacker_ulid: &[u8],
+        defender_ulid: &[u8],
+    ) -> Vec<(Vec<u8>, f32)> {
+        let team_of = |ulid: &[u8]| world.entity_player_ulids.get(ulid).map(|r| r.value().clone()).unwrap_or_default();
+        let mut candidates: Vec<SplashCandidate> = world.entities.iter()
+            .filter(|entry| hex_distance(entry.value().position, impact) <= splash.radius)
+            .filter(|entry| world.entity_stats.get(entry.key()).is_some_and(|stats| stats.get(StatType::HP) > 0.0))
+            .map(|entry| (entry.key().clone(), team_of(entry.key()), entry.value().position))
+            .collect();
+        candidates.sort_by(|a, b| a.0.cmp(&b.0));
+        splash_victims(splash, impact, (attacker_ulid, &team_of(attacker_ulid)), defender_ulid, &candidates)
+    }
+
+    /// Combat snapshot with each entity's type profile and threat table filled in
+    fn combat_snapshot(&self, world: &World) -> Vec<CombatEntitySnapshot> {
+        let mut entities = world.get_combat_snapshot();
+        for entity in &mut entities {
+            if let Some(data) = world.entities.get(&entity.ulid) {
+                entity.profile = self.profiles.get(&data.entity_type).copied().unwrap_or_default();
+            }
+            entity.threat = self.threat.of(&entity.ulid);
+        }
+        entities
+    }
+
+    /// Fade threat over a combat tick, dropping entities that are gone
+    fn decay_threat(&mut self, world: &World, delta: f32) {
+        self.threat.decay(delta, |ulid| world.entities.contains_key(ulid));
+    }
+
+    /// Replace the worker's combats and status effects with the latest known ones
+    fn send_restore(&self) {
+        self.worker.send(CombatWorkRequest {
+            entities_snapshot: Vec::new(),
+            delta: 0.0,
+            seed: 0,
+            apply_statuses: Vec::new(),
+            restore_combats: Some(self.active_combats.clone()),
+            restore_statuses: self.active_statuses.clone(),
+        });
+    }
+
+    /// Heal a target's HP and emit the resulting events
+    fn apply_hp_heal(world: &World, ctx: &SystemContext, ulid: &[u8], amount: f32) {
+        let Some(mut stats) = world.entity_stats.get_mut(ulid) else {
+            return;
+        };
+        let actual_heal = stats.heal(amount);
+        let new_hp = stats.get(StatType::HP);
+
+        // Sync to global cache
+        if let Some(mut cache) = ENTITY_STATS.get_mut(ulid) {
+            cache.heal(amount);
+        }
+
+        // Emit heal event
+        let _ = ctx.events.send(GameEvent::EntityHealed {
+            ulid: ulid.to_vec(),
+            heal_amount: actual_heal,
+            new_hp,
+        });
+
+        // Emit stat changed event
+        let _ = ctx.events.send(GameEvent::StatChanged {
+            ulid: ulid.to_vec(),
+            stat_type: StatType::HP as i64,
+            new_value: new_hp,
+        });
+    }
+
+    /// Queue an effect for the next combat tick, unless the target is immune or gone
+    fn queue_status(&mut self, world: &World, ulid: Vec<u8>, effect: StatusEffect) {
+        if !world.entities.contains_key(&ulid) || effect.remaining <= 0.0 {
+            return;
+        }
+        if self.immunities.get(&ulid).is_some_and(|kinds| kinds.contains(&effect.kind)) {
+            return;
+        }
+        self.pending_statuses.push((ulid, effect));
+    }
+
+    /// Apply one combat worker result (real-time collect or an inline turn round)
+    fn apply_result(&mut self, result: CombatWorkResult, ctx: &mut SystemContext) {
+        let world = ctx.world;
+
+        match result {
+            CombatWorkResult::CombatStarted { attacker_ulid, defender_ulid } => {
+                let _ = ctx.events.send(GameEvent::CombatStarted {
+                    attacker_ulid,
+                    defender_ulid,
+                });
+            }
+            CombatWorkResult::AttackExecuted { attacker_ulid: _ } => {
+                // ATTACKING state will be managed in GDScript via animation system
+                // GDScript entities listen for DamageDealt events and manage ATTACKING/HURT states with timers
+                // This keeps state management close to the animation logic
+            }
+            CombatWorkResult::DamageDealt { attacker_ulid, defender_ulid, damage, outcome } => {
+                // CRITICAL: Apply damage to entity_stats (Actor owns this state)
+                Self::apply_hp_damage(world, ctx, &defender_ulid, damage as f32);
+                self.threat.add(&defender_ulid, &attacker_ulid, damage as f32 * THREAT_PER_DAMAGE);
+
+                // HURT state will be managed in GDScript when EntityDamaged event is received
+                // GDScript entities will set HURT state, play hurt animation, then clear it after animation completes
+
+                // Also emit combat event for visual feedback
+                let _ = ctx.events.send(GameEvent::DamageDealt {
+                    attacker_ulid,
+                    defender_ulid,
+                    damage,
+                    outcome,
+                });
+            }
+            CombatWorkResult::EntityDied { ulid } => {
+                // Set HP to 0 to ensure consistency
+                if let Some(mut stats) = world.entity_stats.get_mut(&ulid) {
+                    stats.value_mut().set(StatType::HP, 0.0);
+
+                    // CRITICAL: Sync to global cache so GDScript sees updated HP
+                    if let Some(mut cache) = ENTITY_STATS.get_mut(&ulid) {
+                        cache.set(StatType::HP, 0.0);
+                    }
+                }
+
+                let _ = ctx.events.send(GameEvent::EntityDied {
+                    ulid,
+                });
+            }
+            CombatWorkResult::CombatEnded { attacker_ulid, defender_ulid } => {
+                let _ = ctx.events.send(GameEvent::CombatEnded {
+                    attacker_ulid,
+                    defender_ulid,
+                });
+            }
+            CombatWorkResult::SpawnProjectile {
+                attacker_ulid,
+                attacker_position,
+                target_ulid,
+                target_position,
+                projectile_type,
+                damage,
+                cover,
+            } => {
+                sim_print!(
+                    "[Rust Actor] Received SpawnProjectile work result, sending event: type={}, damage={}",
+                    projectile_type,
+                    damage
+                );
+                // Emit projectile spawn event for GDScript to handle visual
+                let _ = ctx.events.send(GameEvent::SpawnProjectile {
+                    attacker_ulid,
+                    attacker_position,
+                    target_ulid,
+                    target_position,
+                    projectile_type,
+                    damage,
+                    splash_radius: self.splash_profile(ProjectileType::from_u8(projectile_type).unwrap_or(ProjectileType::None)).radius,
+                    cover,
+                });
+            }
+            CombatWorkResult::ManaConsumed {
+                entity_ulid,
+                mana_cost: _,
+                new_mana,
+            } => {
+                // Update entity mana in Actor's entity_stats (Actor owns HP/Mana state)
+                if let Some(mut stats) = world.entity_stats.get_mut(&entity_ulid) {
+                    stats.value_mut().set(StatType::Mana, new_mana as f32);
+
+                    // Emit StatChanged event for GDScript UI to update mana bar
+                    let _ = ctx.events.send(GameEvent::StatChanged {
+                        ulid: entity_ulid.clone(),
+                        stat_type: StatType::Mana as i64,
+                        new_value: new_mana as f32,
+                    });
+                }
+            }
+            CombatWorkResult::StatusApplied { ulid, effect, move_speed_multiplier } => {
+                let _ = ctx.events.send(GameEvent::StatusApplied {
+                    ulid,
+                    source_ulid: effect.source_ulid,
+                    kind: effect.kind,
+                    stacks: effect.stacks,
+                    duration: effect.remaining,
+                    move_speed_multiplier,
+                });
+            }
+            CombatWorkResult::StatusTicked { ulid, damage, heal } => {
+                // Skip entities already dead (their effects go on the next combat tick)
+                let alive = world.entity_stats.get(&ulid).is_some_and(|stats| stats.is_alive());
+                if !alive {
+                    return;
+                }
+                if heal > 0.0 {
+                    Self::apply_hp_heal(world, ctx, &ulid, heal);
+                }
+                if damage > 0.0 {
+                    Self::apply_hp_damage(world, ctx, &ulid, damage);
+                }
+            }
+            CombatWorkResult::StatusExpired { ulid, kind, move_speed_multiplier } => {
+                let _ = ctx.events.send(GameEvent::StatusExpired {
+                    ulid,
+                    kind,
+                    move_speed_multiplier,
+                });
+            }
+            CombatWorkResult::ActiveCombats { combats, statuses } => {
+                self.active_combats = combats;
+                self.active_statuses = statuses;
+            }
+            CombatWorkResult::KiteAway {
+                entity_ulid,
+                enemy_position,
+                ideal_distance,
+            } => {
+                // KiteAway handles both kiting (positive ideal_distance) and chasing (negative ideal_distance)
+                let Some(entity_entry) = world.entities.get(&entity_ulid) else {
+                    return;
+                };
+                let entity_data = entity_entry.value();
+                let entity_pos = entity_data.position;
+
+                // Convert entity::TerrainType to terrain_cache::TerrainType
+                use crate::entity::TerrainType as EntityTerrainType;
+                use crate::terrain_cache::TerrainType as CacheTerrainType;
+                let cache_terrain = match entity_data.terrain_type {
+                    EntityTerrainType::Water => CacheTerrainType::Water,
+                    EntityTerrainType::Land => CacheTerrainType::Land,
+                };
+                drop(entity_entry);
+
+                let target_pos = if ideal_distance < 0 {
+                    // Negative ideal_distance = chase toward enemy (melee units)
+                    // Pathfind directly to enemy position
+                    enemy_position
+                } else {
+                    // Positive ideal_distance = kite away from enemy (ranged units)
+                    // Calculate escape direction (away from enemy)
+                    let dx = entity_pos.0 - enemy_position.0;
+                    let dy = entity_pos.1 - enemy_position.1;
+
+                    // Normalize and scale to ideal distance
+                    let distance = ((dx * dx + dy * dy) as f32).sqrt();
+                    if distance > 0.0 {
+                        let norm_x = (dx as f32 / distance * ideal_distance as f32) as i32;
+                        let norm_y = (dy as f32 / distance * ideal_distance as f32) as i32;
+                        (entity_pos.0 + norm_x, entity_pos.1 + norm_y)
+                    } else {
+                        entity_pos // Can't escape, stay put
+                    }
+                };
+
+                // Hand the move to the pathfinding system
+                ctx.queue_request(GameRequest::RequestPath {
+                    ulid: entity_ulid,
+                    terrain_type: cache_terrain,
+                    start: entity_pos,
+                    goal: target_pos,
+                    avoid_entities: false, // Prioritize combat movement over collision avoidance
+                });
+            }
+        }
+    }
+}
+
+impl GameSystem for CombatSystem {
+    fn name(&self) -> &'static str {
+        "combat"
+    }
+
+    fn init(&mut self, ctx: &mut SystemContext) {
+        if !ctx.deterministic {
+            self.worker.start("combat-worker", |rx, tx| spawn_combat_worker(rx, tx).map(|handle| vec![handle]), ctx);
+        }
+    }
+
+    fn handle_request(&mut self, request: GameRequest, ctx: &mut SystemContext) -> Option<GameRequest> {
+        let world = ctx.world;
+
+        match request {
+            GameRequest::TakeDamage { ulid, damage } => {
+                if let Some(mut stats) = world.entity_stats.get_mut(&ulid) {
+                    let actual_damage = stats.take_damage(damage);
+                    let mut new_hp = stats.get(StatType::HP);
+
+                    // CRITICAL: If HP would be fractional and < 1.0, set to 0.0 (entity should die)
+                    if new_hp > 0.0 && new_hp < 1.0 {
+                        new_hp = 0.0;
+                        stats.set(StatType::HP, new_hp);
+                    }
+
+                    // Sync to global cache
+                    if let Some(mut cache) = ENTITY_STATS.get_mut(&ulid) {
+                        cache.take_damage(damage);
+                        // Also apply the < 1.0 fix to cache
+                        let cache_hp = cache.get(StatType::HP);
+                        if cache_hp > 0.0 && cache_hp < 1.0 {
+                            cache.set(StatType::HP, 0.0);
+                        }
+                    }
+
+                    // Emit damage event
+                    let _ = ctx.events.send(GameEvent::EntityDamaged {
+                        ulid: ulid.clone(),
+                        damage: actual_damage,
+                        new_hp,
+                    });
+
+                    // Emit stat changed event
+                    let _ = ctx.events.send(GameEvent::StatChanged {
+                        ulid: ulid.clone(),
+                        stat_type: StatType::HP as i64,
+                        new_value: new_hp,
+                    });
+
+                    // Check for death
+                    if new_hp <= 0.0 {
+                        let _ = ctx.events.send(GameEvent::EntityDied {
+                            ulid,
+                        });
+                    }
+                }
+            }
+
+            GameRequest::Heal { ulid, amount } => {
+                Self::apply_hp_heal(world, ctx, &ulid, amount);
+            }
+
+            GameRequest::ApplyStatus { ulid, source_ulid, kind, duration, magnitude } => {
+                self.queue_status(world, ulid, StatusEffect {
+                    kind,
+                    stacks: 1,
+                    remaining: duration,
+                    magnitude,
+                    tick_timer: 0.0,
+                    source_ulid,
+                });
+            }
+
+            GameRequest::SetStatusImmunities { ulid, kinds } => {
+                if kinds.is_empty() {
+                    self.immunities.remove(&ulid);
+                } else {
+                    self.immunities.insert(ulid, kinds);
+                }
+            }
+
+            GameRequest::SetCombatProfile { entity_type, profile } => {
+                self.profiles.insert(entity_type, profile);
+            }
+
+            GameRequest::Taunt { ulid, radius, threat } => {
+                if let Some(position) = world.entities.get(&ulid).map(|entity| entity.position) {
+                    let team = world.entity_player_ulids.get(&ulid).map(|r| r.value().clone()).unwrap_or_default();
+                    let taunted: Vec<Vec<u8>> = world.entities.iter()
+                        .filter(|entry| hex_distance(entry.value().position, position) <= radius)
+                        .filter(|entry| {
+                            let other = world.entity_player_ulids.get(entry.key()).map(|r| r.value().clone()).unwrap_or_default();
+                            are_enemies(&team, &other)
+                        })
+                        .map(|entry| entry.key().clone())
+                        .collect();
+                    for enemy in taunted {
+                        self.threat.add(&enemy, &ulid, threat);
+                    }
+                }
+            }
+
+            GameRequest::SetProjectileSplash { projectile_type, splash } => {
+                self.splash.insert(projectile_type, splash);
+            }
+
+            GameRequest::ProjectileHit { attacker_ulid, defender_ulid, damage, projectile_type, impact_position, cover } => {
+                // Apply damage from projectile hit (called by GDScript after collision)
+                // `damage` is the attack power carried by the projectile; resolve it against each victim now
+                let projectile = ProjectileType::from_u8(projectile_type).unwrap_or(ProjectileType::None);
+                // The attacker may have died while the projectile was in flight (no crits from the grave)
+                let luck = world.entity_stats.get(&attacker_ulid)
+                    .map(|stats| stats.get(StatType::Luck))
+                    .unwrap_or(0.0);
+                // Ridges between the shooter and the target when it fired make it harder to hit
+                let cover = cover_evasion(cover);
+
+                // The target takes the full power (behind its cover), a burst catches those around the impact
+                let splash = self.splash_profile(projectile);
+                let mut victims = vec![(defender_ulid.clone(), 1.0, cover)];
+                if splash.radius > 0 {
+                    let burst = Self::burst_victims(world, &splash, impact_position, &attacker_ulid, &defender_ulid);
+                    victims.extend(burst.into_iter().map(|(ulid, share)| (ulid, share, 0.0)));
+                }
+
+                for (victim, share, cover) in victims {
+                    let Some((defense, evasion)) = world.entity_stats.get(&victim)
+                        .map(|stats| (stats.get(StatType::Defense), stats.get(StatType::Evasion)))
+                    else {
+                        continue;
+                    };
+                    let hit = resolve_hit(damage as f32 * share, luck, defense, evasion + cover, &mut *ctx.rng);
+
+                    // Emit damage dealt event
+                    let _ = ctx.events.send(GameEvent::DamageDealt {
+                        attacker_ulid: attacker_ulid.clone(),
+                        defender_ulid: victim.clone(),
+                        damage: hit.damage,
+                        outcome: hit.outcome,
+                    });
+
+                    // Emit entity damaged event (for health bars) and death
+                    if hit.damage > 0 {
+                        Self::apply_hp_damage(world, ctx, &victim, hit.damage as f32);
+                        self.threat.add(&victim, &attacker_ulid, hit.damage as f32 * THREAT_PER_DAMAGE);
+                    }
+
+                    // The projectile's effect, if it has one and the hit carried it
+                    if let Some(on_hit) = on_hit_status(projectile) {
+                        let roll: f32 = ctx.rng.random();
+                        if hit_applies_status(projectile, hit.outcome) && roll < on_hit.chance {
+                            self.queue_status(world, victim, StatusEffect {
+                                kind: on_hit.kind,
+                                stacks: 1,
+                                remaining: on_hit.duration,
+                                magnitude: on_hit.magnitude,
+                                tick_timer: 0.0,
+                                source_ulid: attacker_ulid.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+
+            other => return Some(other),
+        }
+
+        None
+    }
+
+    fn collect(&mut self, ctx: &mut SystemContext) {
+        let results: Vec<CombatWorkResult> = self.worker.results().collect();
+        for result in results {
+            self.apply_result(result, ctx);
+        }
+    }
+
+    fn tick(&mut self, delta: f64, ctx: &mut SystemContext) {
+        self.combat_timer += delta;
+        if self.combat_timer < COMBAT_TICK_INTERVAL {
+            return;
+        }
+
+        // Immunities and threat go with their entity
+        self.immunities.retain(|ulid, _| ctx.world.entities.contains_key(ulid));
+        self.decay_threat(ctx.world, self.combat_timer as f32);
+
+        // Prepare combat work (copy current entity data)
+        let work = CombatWorkRequest {
+            entities_snapshot: self.combat_snapshot(ctx.world),
+            delta: self.combat_timer as f32,
+            seed: ctx.rng.random(),
+            apply_statuses: std::mem::take(&mut self.pending_statuses),
+            restore_combats: None,
+            restore_statuses: Vec::new(),
+        };
+
+        if ctx.deterministic {
+            process_combat_tick(
+                &work.entities_snapshot,
+                &mut self.inline_combats,
+                &mut self.inline_statuses,
+                work.apply_statuses,
+                work.delta,
+                work.seed,
+                self.worker.inline_sender(),
+            );
+        } else if let Err(work) = self.worker.try_send(work) {
+            // Worker backed up: keep the elapsed time and effects, retry next tick with a fresh snapshot
+            self.pending_statuses = work.apply_statuses;
+            return;
+        }
+        self.combat_timer = 0.0;
+    }
+
+    fn snapshot(&self, _world: &World, out: &mut GameSnapshot) {
+        out.active_combats = self.active_combats.clone();
+        out.status_effects = self.active_statuses.clone();
+        out.status_immunities = self.immunities.iter()
+            .map(|(ulid, kinds)| (ulid.clone(), kinds.clone()))
+            .collect();
+        out.status_immunities.sort_by(|a, b| a.0.cmp(&b.0));
+        out.combat_profiles = self.profiles.iter()
+            .map(|(entity_type, profile)| (entity_type.clone(), *profile))
+            .collect();
+        out.combat_profiles.sort_by(|a, b| a.0.cmp(&b.0));
+        out.projectile_splash = self.splash.iter()
+            .map(|(projectile_type, splash)| (*projectile_type, *splash))
+            .collect();
+        out.projectile_splash.sort_by_key(|(projectile_type, _)| *projectile_type);
+        out.threat = self.threat.entries();
+    }
+
+    fn restore(&mut self, snapshot: &GameSnapshot, ctx: &mut SystemContext) {
+        self.worker.discard_results();
+
+        // Combat worker picks up restored combats before its next tick (channel order)
+        self.active_combats = snapshot.active_combats.clone();
+        self.active_statuses = snapshot.status_effects.clone();
+        self.pending_statuses.clear();
+        self.immunities = snapshot.status_immunities.iter().cloned().collect();
+        self.profiles = snapshot.combat_profiles.iter().cloned().collect();
+        self.splash = snapshot.projectile_splash.iter().copied().collect();
+        self.threat.restore(&snapshot.threat);
+        if ctx.deterministic || self.turn_mode {
+            restore_combats(&mut self.inline_combats, snapshot.active_combats.clone());
+            restore_statuses(&mut self.inline_statuses, snapshot.status_effects.clone());
+        }
+        if !ctx.deterministic {
+            self.send_restore();
+        }
+    }
+
+    fn reset_for_replay(&mut self) {
+        self.inline_combats.clear();
+        self.inline_statuses.clear();
+        self.pending_statuses.clear();
+        self.combat_timer = 0.0;
+    }
+
+    fn resume_workers(&mut self, ctx: &mut SystemContext) {
+        // Turn rounds keep running inline until turn mode ends
+        if self.turn_mode {
+            return;
+        }
+
+        // Pick up the last inline report, then hand the recorded combats to the worker
+        self.collect(ctx);
+        self.send_restore();
+        self.inline_combats.clear();
+        self.inline_statuses.clear();
+    }
+
+    fn supervise(&mut self, ctx: &mut SystemContext) {
+        if !self.worker.supervise(ctx) {
+            return;
+        }
+
+        // The dead worker took its combat state with it; seed the new one from the last report
+        self.send_restore();
+    }
+
+    fn shutdown(&mut self) {
+        self.worker.shutdown();
+    }
+
+    fn report_metrics(&self, metrics: &mut ActorMetrics) {
+        self.worker.report_queue_depths(self.name(), metrics);
+    }
+
+    fn set_turn_mode(&mut self, enabled: bool, ctx: &mut SystemContext) {
+        self.turn_mode = enabled;
+        self.combat_timer = 0.0;
+
+        // Deterministic mode already keeps combats inline
+        if ctx.deterministic {
+            return;
+        }
+        if enabled {
+            // Turn rounds run inline: take over the worker's combats from its last report
+            self.collect(ctx);
+            restore_combats(&mut self.inline_combats, self.active_combats.clone());
+            restore_statuses(&mut self.inline_statuses, self.active_statuses.clone());
+        } else {
+            // Hand the combats fought in turn mode back to the worker
+            self.send_restore();
+            self.inline_combats.clear();
+            self.inline_statuses.clear();
+        }
+    }
+
+    fn turn_phase(&mut self, phase: TurnPhase, summary: &mut TurnSummary, ctx: &mut SystemContext) {
+        if phase != TurnPhase::Combat {
+            return;
+        }
+
+        let alive_before: HashSet<Vec<u8>> = ctx.world.get_combat_snapshot()
+            .into_iter()
+            .filter(|entity| entity.hp > 0)
+            .map(|entity| entity.ulid)
+            .collect();
+
+        let mut last_hits: HashMap<Vec<u8>, Vec<u8>> = HashMap::new();  // defender -> attacker
+
+        // Fresh snapshot each round so damage from the previous round counts
+        for _ in 0..TURN_COMBAT_ROUNDS {
+            self.decay_threat(ctx.world, COMBAT_TICK_INTERVAL as f32);
+            let entities = self.combat_snapshot(ctx.world);
+            let seed = ctx.rng.random();
+            process_combat_tick(
+                &entities,
+                &mut self.inline_combats,
+                &mut self.inline_statuses,
+                std::mem::take(&mut self.pending_statuses),
+                COMBAT_TICK_INTERVAL as f32,
+                seed,
+                self.worker.inline_sender(),
+            );
+
+            let results: Vec<CombatWorkResult> = self.worker.results().collect();
+            for result in results {
+                if let CombatWorkResult::DamageDealt { attacker_ulid, defender_ulid, damage, .. } = &result {
+                    summary.attacks += 1;
+                    summary.damage_dealt += *damage as f64;
+                    last_hits.insert(defender_ulid.clone(), attacker_ulid.clone());
+                }
+                self.apply_result(result, ctx);
+            }
+        }
+
+        // Snapshot order is sorted by ULID, so deaths are too
+        summary.deaths = ctx.world.get_combat_snapshot()
+            .into_iter()
+            .filter(|entity| entity.hp <= 0 && alive_before.contains(&entity.ulid))
+            .map(|entity| entity.ulid)
+            .collect();
+        summary.killers = summary.deaths.iter()
+            .filter_map(|ulid| Some((ulid.clone(), last_hits.get(ulid)?.clone())))
+            .collect();
+    }
+}
@@ -0,0 +1,1141 @@
+// Economy system - authoritative resource pools, producers/consumers and the economy worker
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::entity::StatType;
+use crate::forecast;
+use crate::market::Market;
+use crate::taxation::City;
+use crate::metrics::ActorMetrics;
+use crate::resource_registry::resources;
+use crate::savegame::{CitySnapshot, FlowSnapshot, GameSnapshot, MarketSnapshot, RecipeSnapshot, ResourceSnapshot, SaveError, StorageSnapshot};
+use crate::types::{
+    ForecastHorizon, GameEvent, GameRequest, LedgerEntry, MarketPrice, Recipe, RecipeReport, RecipeStatus, RequestId, ResourceForecast,
+    ShortageConfig, ShortageEffect, TransactionReason, TurnSummary,
+};
+use crate::workers::{
+    handle_economy_request, net_rate, spawn_economy_worker, EconomyWorkRequest, EconomyWorkResult, RecipeProducer, ResourceFlow,
+    MIN_RECIPE_CYCLE_SECS,
+};
+use super::{GameSystem, SystemContext, TurnPhase, World, WorkerLink};
+
+/// Economy worker cadence (simulated seconds); each economy tick produces for all time since the last one
+const ECONOMY_TICK_INTERVAL: f64 = 1.0;
+/// Economy ticks that may wait for the worker
+const ECONOMY_QUEUE_CAPACITY: usize = 4;
+/// Production seconds per turn (same as one GameTimer turn in real time)
+const TURN_PRODUCTION_SECS: f64 = 60.0;
+/// Ledger entries kept for queries and rollback (oldest dropped first)
+/// Producer/consumer flow is reported as a rate, not ledgered
+const LEDGER_CAPACITY: usize = 4096;
+
+/// One player's resources: resource_type -> (current, cap, rate)
+type Pool = HashMap<i64, (f64, f64, f64)>;
+/// (resource_type, amount) per resource: transaction changes, or a shortfall
+type Amounts = Vec<(i64, f64)>;
+
+/// Starting resources for a new pool (every registered resource at its default amount and cap)
+fn default_pool() -> Pool {
+    resources().iter()
+        .map(|def| (def.id, (def.default_amount, def.default_cap, 0.0)))
+        .collect()
+}
+
+/// Recipes that make labor keep running through a labor shortage
+fn makes_labor(recipe: &Recipe, labor: i64) -> bool {
+    recipe.outputs.iter().any(|(resource_type, _)| *resource_type == labor)
+}
+
+/// Recipe with repeated resource types merged, non-positive amounts dropped and a usable cycle
+fn normalize_recipe(recipe: Recipe) -> Recipe {
+    let merge = |amounts: Vec<(i64, f64)>| -> Vec<(i64, f64)> {
+        let mut merged: BTreeMap<i64, f64> = BTreeMap::new();
+        for (resource_type, amount) in amounts {
+            *merged.entry(resource_type).or_default() += amount;
+        }
+        merged.into_iter().filter(|(_, amount)| *amount > 0.0).collect()
+    };
+    Recipe {
+        inputs: merge(recipe.inputs),
+        outputs: merge(recipe.outputs),
+        cycle_secs: if recipe.cycle_secs.is_finite() { recipe.cycle_secs.max(MIN_RECIPE_CYCLE_SECS) } else { MIN_RECIPE_CYCLE_SECS },
+    }
+}
+
+/// Player-controlled entities per owner (1 food each per turn)
+/// AI entities (empty player_ulid) do not consume food
+fn upkeep_counts(world: &World) -> BTreeMap<Vec<u8>, usize> {
+    let mut entity_counts: BTreeMap<Vec<u8>, usize> = BTreeMap::new();
+    for entry in world.entity_player_ulids.iter().filter(|entry| !entry.value().is_empty()) {
+        *entity_counts.entry(entry.value().clone()).or_default() += 1;
+    }
+    entity_counts
+}
+
+/// Publish a resource's new state to the shared view (synchronous FFI reads) and emit ResourceChanged
+fn announce(player_ulid: &[u8], resource_type: i64, (current, cap, rate): (f64, f64, f64), ctx: &SystemContext) {
+    ctx.world.resources.insert((player_ulid.to_vec(), resource_type), (current, cap, rate));
+    let _ = ctx.events.send(GameEvent::ResourceChanged {
+        player_ulid: player_ulid.to_vec(),
+        resource_type,
+        current,
+        cap,
+        rate,
+    });
+}
+
+/// Emit every resource of one pool, in resource type order
+fn emit_pool(player_ulid: &[u8], pool: &Pool, ctx: &SystemContext) {
+    let mut resource_types: Vec<i64> = pool.keys().copied().collect();
+    resource_types.sort_unstable();
+
+    for resource_type in resource_types {
+        announce(player_ulid, resource_type, pool[&resource_type], ctx);
+    }
+}
+
+/// Storage structure protecting part of a player's stock from decay
+struct Storage {
+    ulid: Vec<u8>,
+    player_ulid: Vec<u8>,
+    protection: Vec<(i64, f64)>,  // (resource_type, share of decay losses prevented)
+}
+
+pub struct EconomySystem {
+    pools: HashMap<Vec<u8>, Pool>,  // player_ulid -> pool (empty ULID = AI/neutral pool)
+    producers: Vec<ResourceFlow>,
+    consumers: Vec<ResourceFlow>,
+    recipes: Vec<RecipeProducer>,  // Registration order (also the order they draw inputs in)
+    storages: Vec<Storage>,
+    markets: BTreeMap<i64, Market>,  // market_id (host structure id) -> market
+    cities: BTreeMap<i64, City>,     // city_id (host structure id) -> tax base
+    worker: WorkerLink<EconomyWorkRequest, EconomyWorkResult>,
+    economy_timer: f64,
+    ledger: VecDeque<LedgerEntry>,  // Oldest first
+    next_transaction_id: u64,
+    shortage_config: ShortageConfig,
+    shortages: BTreeSet<(Vec<u8>, i64)>,  // (player_ulid, resource_type) reported by ResourceShortage and not yet ended
+}
+
+impl Default for EconomySystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EconomySystem {
+    pub fn new() -> Self {
+        // The AI/neutral pool always exists; player pools are created on first use
+        let mut pools = HashMap::new();
+        pools.insert(Vec::new(), default_pool());
+
+        Self {
+            pools,
+            producers: Vec::new(),
+            consumers: Vec::new(),
+            recipes: Vec::new(),
+            storages: Vec::new(),
+            markets: BTreeMap::new(),
+            cities: BTreeMap::new(),
+            worker: WorkerLink::new(ECONOMY_QUEUE_CAPACITY),
+            economy_timer: 0.0,
+            ledger: VecDeque::new(),
+            next_transaction_id: 1,
+            shortage_config: ShortageConfig::default(),
+            shortages: BTreeSet::new(),
+        }
+    }
+
+    /// Emit the full resource state of every pool (startup and after LoadGame)
+    fn emit_resources(&self, ctx: &SystemContext) {
+        let mut players: Vec<&Vec<u8>> = self.pools.keys().collect();
+        players.sort_unstable();
+
+        for player_ulid in players {
+            emit_pool(player_ulid, &self.pools[player_ulid], ctx);
+        }
+    }
+
+    /// A player's pool, created with starting resources (and announced) the first time it is used
+    /// Resources registered since the pool was created are added at their starting amount
+    fn pool_mut(&mut self, player_ulid: &[u8], ctx: &SystemContext) -> &mut Pool {
+        let pool = self.pools.entry(player_ulid.to_vec()).or_default();
+        for def in resources().iter() {
+            if let Entry::Vacant(entry) = pool.entry(def.id) {
+                announce(player_ulid, def.id, *entry.insert((def.default_amount, def.default_cap, 0.0)), ctx);
+            }
+        }
+        pool
+    }
+
+    /// Amount missing per resource for a cost from a player's pool (empty = affordable)
+    /// Unknown resource types (and pools) count as entirely missing
+    pub fn resource_shortfall(&self, player_ulid: &[u8], cost: &[(i64, f64)]) -> Vec<(i64, f64)> {
+        cost.iter()
+            .filter_map(|(resource_type, amount)| {
+                let current = self.resource(player_ulid, *resource_type).unwrap_or(0.0);
+                (current < *amount).then(|| (*resource_type, amount - current))
+            })
+            .collect()
+    }
+
+    /// Current amount of a resource in a player's pool (None = unknown pool or type)
+    pub fn resource(&self, player_ulid: &[u8], resource_type: i64) -> Option<f64> {
+        self.pools.get(player_ulid)?.get(&resource_type).map(|r| r.0)
+    }
+
+    /// Apply signed changes to a pool all-or-nothing, returning what was applied per resource
+    /// Err(shortfall) if any debit can't be covered or a resource type is unknown (nothing changes)
+    fn transact(&mut self, player_ulid: &[u8], changes: &[(i64, f64)], ctx: &SystemContext) -> Result<Amounts, Amounts> {
+        // Repeated resource types are merged so the check sees the real total
+        let mut net: BTreeMap<i64, f64> = BTreeMap::new();
+        for (resource_type, amount) in changes {
+            *net.entry(*resource_type).or_default() += amount;
+        }
+
+        let pool = self.pool_mut(player_ulid, ctx);
+        let shortfall: Vec<(i64, f64)> = net.iter()
+            .filter_map(|(resource_type, amount)| match pool.get(resource_type) {
+                Some(resource) => (resource.0 + amount < 0.0).then(|| (*resource_type, -amount - resource.0)),
+                None => Some((*resource_type, amount.abs())),
+            })
+            .collect();
+        if !shortfall.is_empty() {
+            return Err(shortfall);
+        }
+
+        let mut applied = Vec::with_capacity(net.len());
+        for (resource_type, amount) in net {
+            let Some(resource) = pool.get_mut(&resource_type) else {
+                continue;
+            };
+            let before = resource.0;
+            resource.0 = (resource.0 + amount).min(resource.1);  // Debits are covered, credits stop at the cap
+            applied.push((resource_type, resource.0 - before));
+
+            announce(player_ulid, resource_type, *resource, ctx);
+        }
+        Ok(applied)
+    }
+
+    /// Append an applied transaction to the ledger and announce it
+    fn record(&mut self, request_id: RequestId, player_ulid: &[u8], reason: TransactionReason, changes: Vec<(i64, f64)>, reverts: Option<u64>, ctx: &SystemContext) {
+        let entry = LedgerEntry {
+            transaction_id: self.next_transaction_id,
+            player_ulid: player_ulid.to_vec(),
+            reason,
+            changes,
+            reverts,
+            rolled_back: false,
+        };
+        self.next_transaction_id += 1;
+
+        if self.ledger.len() == LEDGER_CAPACITY {
+            self.ledger.pop_front();
+        }
+        self.ledger.push_back(entry.clone());
+        let _ = ctx.events.send(GameEvent::TransactionApplied { request_id, entry });
+    }
+
+    /// Transact and record, or emit TransactionRejected; true if applied
+    fn apply_transaction(&mut self, request_id: RequestId, player_ulid: &[u8], reason: TransactionReason, changes: &[(i64, f64)], ctx: &SystemContext) -> bool {
+        match self.transact(player_ulid, changes, ctx) {
+            Ok(applied) => {
+                self.record(request_id, player_ulid, reason, applied, None, ctx);
+                true
+            }
+            Err(shortfall) => {
+                let _ = ctx.events.send(GameEvent::TransactionRejected {
+                    request_id,
+                    player_ulid: player_ulid.to_vec(),
+                    reason,
+                    error: "insufficient resources".to_string(),
+                    shortfall,
+                });
+                false
+            }
+        }
+    }
+
+    /// Sell at a market for what it's worth there: one Trade transaction, then the market's stock moves
+    /// Rejected (TransactionRejected) for unknown markets, bad quotes or what the pool can't cover
+    fn trade(&mut self, request_id: RequestId, player_ulid: &[u8], market_id: i64, sell: (i64, f64), buy: i64, ctx: &SystemContext) {
+        let registry = resources();
+        let quote = match self.markets.get(&market_id) {
+            Some(market) => market.quote(sell, buy, |resource_type| registry.get(resource_type).map(|def| def.base_price)),
+            None => Err(format!("unknown market {}", market_id)),
+        };
+        let received = match quote {
+            Ok(received) => received,
+            Err(error) => {
+                let _ = ctx.events.send(GameEvent::TransactionRejected {
+                    request_id,
+                    player_ulid: player_ulid.to_vec(),
+                    reason: TransactionReason::Trade,
+                    error,
+                    shortfall: Vec::new(),
+                });
+                return;
+            }
+        };
+
+        if self.apply_transaction(request_id, player_ulid, TransactionReason::Trade, &[(sell.0, -sell.1), (buy, received)], ctx) {
+            if let Some(market) = self.markets.get_mut(&market_id) {
+                market.settle(sell, (buy, received));
+            }
+            let _ = ctx.events.send(GameEvent::TradeCompleted {
+                request_id,
+                player_ulid: player_ulid.to_vec(),
+                market_id,
+                sold: sell,
+                bought: (buy, received),
+            });
+        }
+    }
+
+    /// Apply the inverse of a ledger transaction and mark it rolled back
+    fn rollback(&mut self, request_id: RequestId, transaction_id: u64, ctx: &SystemContext) {
+        let original = self.ledger.iter().find(|entry| entry.transaction_id == transaction_id);
+        let error = match original {
+            None => Some(format!("unknown transaction {}", transaction_id)),
+            Some(entry) if entry.rolled_back => Some(format!("transaction {} is already rolled back", transaction_id)),
+            Some(entry) if entry.reverts.is_some() => Some(format!("transaction {} is a rollback", transaction_id)),
+            Some(_) => None,
+        };
+        let player_ulid = original.map(|entry| entry.player_ulid.clone()).unwrap_or_default();
+        if let Some(error) = error {
+            let _ = ctx.events.send(GameEvent::TransactionRejected {
+                request_id,
+                player_ulid,
+                reason: TransactionReason::Rollback,
+                error,
+                shortfall: Vec::new(),
+            });
+            return;
+        }
+
+        let inverse: Vec<(i64, f64)> = original.into_iter()
+            .flat_map(|entry| entry.changes.iter().map(|(resource_type, amount)| (*resource_type, -amount)))
+            .collect();
+        match self.transact(&player_ulid, &inverse, ctx) {
+            Ok(applied) => {
+                if let Some(entry) = self.ledger.iter_mut().find(|entry| entry.transaction_id == transaction_id) {
+                    entry.rolled_back = true;
+                }
+                self.record(request_id, &player_ulid, TransactionReason::Rollback, applied, Some(transaction_id), ctx);
+            }
+            Err(shortfall) => {
+                let _ = ctx.events.send(GameEvent::TransactionRejected {
+                    request_id,
+                    player_ulid,
+                    reason: TransactionReason::Rollback,
+                    error: "insufficient resources".to_string(),
+                    shortfall,
+                });
+            }
+        }
+    }
+
+    /// Change a cap, clamping (and ledgering) any amount above it
+    fn set_cap(&mut self, player_ulid: &[u8], resource_type: i64, cap: f64, ctx: &SystemContext) {
+        let Some(resource) = self.pool_mut(player_ulid, ctx).get_mut(&resource_type) else {
+            sim_error!("Actor: Resource type {} not found!", resource_type);
+            return;
+        };
+        let before = resource.0;
+        resource.1 = cap.max(0.0);
+        resource.0 = resource.0.min(resource.1);
+        let (clamped, resource) = (resource.0 - before, *resource);
+
+        announce(player_ulid, resource_type, resource, ctx);
+        if clamped != 0.0 {
+            self.record(0, player_ulid, TransactionReason::Adjustment, vec![(resource_type, clamped)], None, ctx);
+        }
+    }
+
+    /// Put a pool back to its starting amounts and caps (flows are left alone)
+    fn reset_pool(&mut self, player_ulid: &[u8], ctx: &SystemContext) {
+        let mut defaults: Vec<(i64, (f64, f64, f64))> = default_pool().into_iter().collect();
+        defaults.sort_unstable_by_key(|(resource_type, _)| *resource_type);
+
+        let pool = self.pool_mut(player_ulid, ctx);
+        let mut changes = Vec::new();
+        for (resource_type, (amount, cap, _)) in defaults {
+            let resource = pool.entry(resource_type).or_insert((0.0, cap, 0.0));
+            if resource.0 != amount {
+                changes.push((resource_type, amount - resource.0));
+            }
+            resource.0 = amount;
+            resource.1 = cap;
+            announce(player_ulid, resource_type, *resource, ctx);
+        }
+
+        if !changes.is_empty() {
+            self.record(0, player_ulid, TransactionReason::Adjustment, changes, None, ctx);
+        }
+    }
+
+    /// Consume 1 food per player-controlled entity from its owner's pool, returning the food eaten
+    /// Whatever the pool can't pay is a food shortage: the owner's units starve
+    fn consume_upkeep(&mut self, ctx: &mut SystemContext) -> f64 {
+        let food_type = resources().core().food;
+        let mut eaten = 0.0;
+        let mut starving = BTreeSet::new();
+        for (player_ulid, entity_count) in upkeep_counts(ctx.world) {
+            // Consume 1 food per player-controlled entity
+            let food_cost = entity_count as f64;
+            let Some(food) = self.pool_mut(&player_ulid, ctx).get_mut(&food_type) else {
+                continue;
+            };
+            let player_eaten = food.0.min(food_cost);
+            food.0 = (food.0 - food_cost).max(0.0);
+
+            // Emit resource changed event
+            announce(&player_ulid, food_type, *food, ctx);
+
+            // Upkeep eats what is there rather than failing, so it is recorded directly
+            if player_eaten > 0.0 {
+                self.record(0, &player_ulid, TransactionReason::Upkeep, vec![(food_type, -player_eaten)], None, ctx);
+            }
+            eaten += player_eaten;
+
+            if player_eaten < food_cost && self.starve(&player_ulid, food_cost - player_eaten, ctx) {
+                starving.insert(player_ulid);
+            }
+        }
+
+        // Fed again, or no units left to feed
+        let ended: Vec<Vec<u8>> = self.shortages.iter()
+            .filter(|(player_ulid, resource_type)| *resource_type == food_type && !starving.contains(player_ulid))
+            .map(|(player_ulid, _)| player_ulid.clone())
+            .collect();
+        for player_ulid in ended {
+            self.end_shortage(player_ulid, food_type, ctx);
+        }
+        eaten
+    }
+
+    /// Damage and demoralize a player's living units for unpaid upkeep; false if starvation is switched off
+    fn starve(&mut self, player_ulid: &[u8], deficit: f64, ctx: &mut SystemContext) -> bool {
+        let (hp_loss, morale_loss) = (self.shortage_config.starvation_hp, self.shortage_config.starvation_morale);
+        if hp_loss <= 0.0 && morale_loss <= 0.0 {
+            return false;
+        }
+
+        let mut units: Vec<(Vec<u8>, f32)> = ctx.world.entity_player_ulids.iter()
+            .filter(|entry| entry.value() == player_ulid)
+            .filter_map(|entry| {
+                let stats = ctx.world.entity_stats.get(entry.key())?;
+                (stats.get(StatType::HP) > 0.0).then(|| (entry.key().clone(), stats.get(StatType::Morale)))
+            })
+            .collect();
+        units.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        // Applied by the entity/combat systems later this tick
+        for (ulid, morale) in &units {
+            if morale_loss > 0.0 {
+                ctx.queue_request(GameRequest::SetStat {
+                    ulid: ulid.clone(),
+                    stat_type: StatType::Morale as i64,
+                    value: (morale - morale_loss).max(0.0),
+                });
+            }
+            if hp_loss > 0.0 {
+                ctx.queue_request(GameRequest::TakeDamage { ulid: ulid.clone(), damage: hp_loss });
+            }
+        }
+
+        let food_type = resources().core().food;
+        self.shortages.insert((player_ulid.to_vec(), food_type));
+        let _ = ctx.events.send(GameEvent::ResourceShortage {
+            player_ulid: player_ulid.to_vec(),
+            resource_type: food_type,
+            deficit,
+            effect: ShortageEffect::Starvation {
+                units: units.into_iter().map(|(ulid, _)| ulid).collect(),
+                hp_loss,
+                morale_loss,
+            },
+        });
+        true
+    }
+
+    /// Cities of players without faith lose reputation, once per upkeep
+    fn check_faith(&mut self, ctx: &SystemContext) {
+        let loss = self.shortage_config.faith_reputation_loss;
+        let faith_type = resources().core().faith;
+        let mut players: Vec<Vec<u8>> = self.pools.keys().filter(|player_ulid| !player_ulid.is_empty()).cloned().collect();
+        players.sort_unstable();
+
+        for player_ulid in players {
+            let faithless = self.resource(&player_ulid, faith_type).is_some_and(|faith| faith <= 0.0);
+            if faithless && loss > 0.0 {
+                self.shortages.insert((player_ulid.clone(), faith_type));
+                for city in self.cities.values_mut().filter(|city| city.player_ulid == player_ulid) {
+                    city.shift_reputation(-(loss as f64));
+                }
+                let _ = ctx.events.send(GameEvent::ResourceShortage {
+                    player_ulid,
+                    resource_type: faith_type,
+                    deficit: 0.0,
+                    effect: ShortageEffect::ReputationLoss { amount: loss },
+                });
+            } else {
+                self.end_shortage(player_ulid, faith_type, ctx);
+            }
+        }
+    }
+
+    /// Share of a resource's decay losses a player's storage prevents (storages stack multiplicatively)
+    fn decay_protection(&self, player_ulid: &[u8], resource_type: i64) -> f64 {
+        let exposed: f64 = self.storages.iter()
+            .filter(|storage| storage.player_ulid == player_ulid)
+            .flat_map(|storage| storage.protection.iter())
+            .filter(|(kind, _)| *kind == resource_type)
+            .map(|(_, protection)| 1.0 - protection.clamp(0.0, 1.0))
+            .product();
+        1.0 - exposed
+    }
+
+    /// Apply every resource's decay rule to every pool, once per upkeep (ledgered per pool)
+    /// Returns (player_ulid, resource_type, change) for the turn summary
+    fn decay(&mut self, ctx: &SystemContext) -> Vec<(Vec<u8>, i64, f64)> {
+        let registry = resources();
+        let mut players: Vec<Vec<u8>> = self.pools.keys().cloned().collect();
+        players.sort_unstable();
+
+        let mut decayed = Vec::new();
+        for player_ulid in players {
+            let mut changes = Vec::new();
+            for def in registry.iter() {
+                let protection = self.decay_protection(&player_ulid, def.id);
+                let Some(resource) = self.pools.get_mut(&player_ulid).and_then(|pool| pool.get_mut(&def.id)) else {
+                    continue;
+                };
+                let amount = def.decay.apply(resource.0, resource.1, protection);
+                if amount != resource.0 {
+                    changes.push((def.id, amount - resource.0));
+                    resource.0 = amount;
+                    announce(&player_ulid, def.id, *resource, ctx);
+                }
+            }
+
+            if !changes.is_empty() {
+                decayed.extend(changes.iter().map(|(resource_type, change)| (player_ulid.clone(), *resource_type, *change)));
+                self.record(0, &player_ulid, TransactionReason::Decay, changes, None, ctx);
+            }
+        }
+        decayed
+    }
+
+    /// Pause (or resume) production for pools that ran out of labor, before a production step
+    fn check_labor(&mut self, ctx: &SystemContext) {
+        let labor_type = resources().core().labor;
+        let mut players: Vec<Vec<u8>> = self.pools.keys().cloned().collect();
+        players.sort_unstable();
+
+        for player_ulid in players {
+            let idle = self.shortage_config.labor_pauses_production
+                && self.resource(&player_ulid, labor_type).is_some_and(|labor| labor <= 0.0);
+            if !idle {
+                self.end_shortage(player_ulid, labor_type, ctx);
+            } else if self.shortages.insert((player_ulid.clone(), labor_type)) {
+                let flows = self.producers.iter()
+                    .filter(|flow| flow.active && flow.player_ulid == player_ulid && flow.resource_type != labor_type)
+                    .map(|flow| flow.ulid.clone());
+                let recipes = self.recipes.iter()
+                    .filter(|recipe| recipe.active && recipe.player_ulid == player_ulid && !makes_labor(&recipe.recipe, labor_type))
+                    .map(|recipe| recipe.ulid.clone());
+                let producers = flows.chain(recipes).collect();
+                let _ = ctx.events.send(GameEvent::ResourceShortage {
+                    player_ulid,
+                    resource_type: labor_type,
+                    deficit: 0.0,
+                    effect: ShortageEffect::ProductionPaused { producers },
+                });
+            }
+        }
+    }
+
+    fn end_shortage(&mut self, player_ulid: Vec<u8>, resource_type: i64, ctx: &SystemContext) {
+        let key = (player_ulid, resource_type);
+        if self.shortages.remove(&key) {
+            let _ = ctx.events.send(GameEvent::ShortageEnded { player_ulid: key.0, resource_type });
+        }
+    }
+
+    /// Recipes as they run right now: switched off or held by a labor shortage (unless they make labor)
+    /// get that status and don't run
+    fn running_recipes(&self) -> Vec<RecipeProducer> {
+        let labor_type = resources().core().labor;
+        self.recipes.iter()
+            .cloned()
+            .map(|mut recipe| {
+                if !recipe.active {
+                    recipe.status = RecipeStatus::Inactive;
+                } else if !makes_labor(&recipe.recipe, labor_type) && self.shortages.contains(&(recipe.player_ulid.clone(), labor_type)) {
+                    recipe.active = false;
+                    recipe.status = RecipeStatus::Paused;
+                }
+                recipe
+            })
+            .collect()
+    }
+
+    /// Producers as they run right now: labor producers keep going during a labor shortage, the rest pause
+    /// Every city's taxes come in as a gold producer (spread over the turn, paid even without labor)
+    fn running_producers(&self) -> Vec<ResourceFlow> {
+        let core = resources().core();
+        let taxes = self.cities.values().map(|city| ResourceFlow {
+            ulid: city.city_id.to_be_bytes().to_vec(),
+            player_ulid: city.player_ulid.clone(),
+            resource_type: core.gold,
+            rate: city.income_per_turn() / TURN_PRODUCTION_SECS,
+            active: true,
+        });
+        self.producers.iter()
+            .cloned()
+            .map(|mut flow| {
+                if flow.resource_type != core.labor && self.shortages.contains(&(flow.player_ulid.clone(), core.labor)) {
+                    flow.active = false;
+                }
+                flow
+            })
+            .chain(taxes)
+            .collect()
+    }
+
+    /// Upkeep: every city's reputation moves with its tax rate (reported for the host's Structure)
+    fn tax_cities(&mut self, ctx: &SystemContext) {
+        for city in self.cities.values_mut() {
+            let reputation_delta = city.drift_reputation();
+            let _ = ctx.events.send(GameEvent::CityTaxed {
+                city_id: city.city_id,
+                player_ulid: city.player_ulid.clone(),
+                income_per_turn: city.income_per_turn(),
+                reputation_delta,
+            });
+        }
+    }
+
+    /// Project every resource of a pool over a horizon (pool state is not touched)
+    fn forecast(&self, player_ulid: &[u8], horizon: ForecastHorizon, world: &World) -> Vec<ResourceForecast> {
+        let Some(pool) = self.pools.get(player_ulid) else {
+            return Vec::new();
+        };
+        let food_upkeep = upkeep_counts(world).get(player_ulid).copied().unwrap_or(0) as f64;
+        let registry = resources();
+
+        let mut resource_types: Vec<i64> = pool.keys().copied().collect();
+        resource_types.sort_unstable();
+        resource_types.into_iter()
+            .map(|resource_type| {
+                let (current, cap, _) = pool[&resource_type];
+                let mut resource = ResourceForecast {
+                    resource_type,
+                    current,
+                    cap,
+                    // From the flows rather than the last tick's rate, so just-registered flows count
+                    rate: net_rate(&self.running_producers(), &self.consumers, &self.recipes, player_ulid, resource_type),
+                    upkeep_per_turn: if resource_type == registry.core().food { food_upkeep } else { 0.0 },
+                    decay: registry.get(resource_type).map(|def| def.decay).unwrap_or_default(),
+                    decay_protection: self.decay_protection(player_ulid, resource_type),
+                    ..Default::default()
+                };
+                forecast::project(&mut resource, horizon, TURN_PRODUCTION_SECS);
+                resource
+            })
+            .collect()
+    }
+
+    /// Snapshot producers/consumers/pools for one production step
+    fn production_work(&self, elapsed_secs: f64) -> EconomyWorkRequest {
+        EconomyWorkRequest {
+            producers_snapshot: self.running_producers(),
+            consumers_snapshot: self.consumers.clone(),
+            recipes_snapshot: self.running_recipes(),
+            current_resources: self.pools.iter()
+                .flat_map(|(player_ulid, pool)| pool.iter()
+                    .map(move |(k, (current, cap, _rate))| (player_ulid.clone(), *k, *current, *cap)))
+                .collect(),
+            elapsed_secs,
+        }
+    }
+
+    fn apply_result(&mut self, result: EconomyWorkResult, ctx: &SystemContext) {
+        // Production is a change against the snapshot the job was built from: add it to the live pools,
+        // so transactions, upkeep, decay and cap changes made while the job ran are kept
+        for (player_ulid, resource_type, change, rate) in result.resource_changes {
+            let Some(resource) = self.pools.get_mut(&player_ulid).and_then(|pool| pool.get_mut(&resource_type)) else {
+                continue;
+            };
+            let (current, cap, _) = *resource;
+            *resource = ((current + change).clamp(0.0, cap), cap, rate);
+            announce(&player_ulid, resource_type, *resource, ctx);
+        }
+
+        // Progress of recipes still registered (ones removed meanwhile are dropped)
+        for stepped in result.recipes {
+            let Some(recipe) = self.recipes.iter_mut().find(|recipe| recipe.ulid == stepped.ulid) else {
+                continue;
+            };
+            if recipe.status != stepped.status {
+                let _ = ctx.events.send(GameEvent::RecipeStatusChanged {
+                    ulid: stepped.ulid.clone(),
+                    player_ulid: stepped.player_ulid.clone(),
+                    status: stepped.status.clone(),
+                });
+            }
+            recipe.progress = stepped.progress;
+            recipe.inputs_paid = stepped.inputs_paid;
+            recipe.cycles_completed = stepped.cycles_completed;
+            recipe.status = stepped.status;
+        }
+    }
+}
+
+impl GameSystem for EconomySystem {
+    fn name(&self) -> &'static str {
+        "economy"
+    }
+
+    fn init(&mut self, ctx: &mut SystemContext) {
+        // Emit initial resource states
+        self.emit_resources(ctx);
+
+        if !ctx.deterministic {
+            self.worker.start("economy-worker", |rx, tx| spawn_economy_worker(rx, tx).map(|handle| vec![handle]), ctx);
+        }
+    }
+
+    fn handle_request(&mut self, request: GameRequest, ctx: &mut SystemContext) -> Option<GameRequest> {
+        match request {
+            GameRequest::RegisterProducer { ulid, player_ulid, resource_type, rate_per_sec, active } => {
+                self.pool_mut(&player_ulid, ctx);
+                self.producers.push(ResourceFlow { ulid, player_ulid, resource_type, rate: rate_per_sec, active });
+            }
+
+            GameRequest::RegisterConsumer { ulid, player_ulid, resource_type, rate_per_sec, active } => {
+                self.pool_mut(&player_ulid, ctx);
+                self.consumers.push(ResourceFlow { ulid, player_ulid, resource_type, rate: rate_per_sec, active });
+            }
+
+            GameRequest::RegisterRecipe { ulid, player_ulid, recipe, active } => {
+                self.pool_mut(&player_ulid, ctx);
+                self.recipes.push(RecipeProducer {
+                    ulid,
+                    player_ulid,
+                    recipe: normalize_recipe(recipe),
+                    active,
+                    progress: 0.0,
+                    inputs_paid: false,
+                    cycles_completed: 0,
+                    status: if active { RecipeStatus::Running } else { RecipeStatus::Inactive },
+                });
+            }
+
+            GameRequest::RemoveProducer { ulid } => {
+                self.producers.retain(|flow| flow.ulid != ulid);
+                self.recipes.retain(|recipe| recipe.ulid != ulid);
+            }
+
+            GameRequest::RemoveConsumer { ulid } => {
+                self.consumers.retain(|flow| flow.ulid != ulid);
+            }
+
+            GameRequest::AddResources { player_ulid, resource_type, amount } => {
+                // Add resources to the player's pool in Actor's authoritative state (capped at maximum)
+                if !self.apply_transaction(0, &player_ulid, TransactionReason::Adjustment, &[(resource_type, amount)], ctx) {
+                    sim_error!("Actor: Cannot add {} to resource type {}!", amount, resource_type);
+                }
+            }
+
+            GameRequest::SpendResources { player_ulid, cost } => {
+                // All resources are spent, or none
+                let debits: Vec<(i64, f64)> = cost.iter().map(|(resource_type, amount)| (*resource_type, -amount)).collect();
+                if self.apply_transaction(0, &player_ulid, TransactionReason::Adjustment, &debits, ctx) {
+                    sim_print!("Actor: Spent {:?}", cost);
+                } else {
+                    sim_warn!("Actor: Cannot afford resource cost!");
+                }
+            }
+
+            GameRequest::SetProducerActive { ulid, active } => {
+                self.producers.iter_mut().filter(|flow| flow.ulid == ulid).for_each(|flow| flow.active = active);
+                self.recipes.iter_mut().filter(|recipe| recipe.ulid == ulid).for_each(|recipe| recipe.active = active);
+            }
+
+            GameRequest::SetConsumerActive { ulid, active } => {
+                self.consumers.iter_mut().filter(|flow| flow.ulid == ulid).for_each(|flow| flow.active = active);
+            }
+
+            GameRequest::RegisterStorage { ulid, player_ulid, protection } => {
+                // Re-registering a storage replaces its protection
+                self.storages.retain(|storage| storage.ulid != ulid);
+                let protection = protection.into_iter()
+                    .filter(|(_, share)| share.is_finite())
+                    .map(|(resource_type, share)| (resource_type, share.clamp(0.0, 1.0)))
+                    .collect();
+                self.storages.push(Storage { ulid, player_ulid, protection });
+            }
+
+            GameRequest::RemoveStorage { ulid } => {
+                self.storages.retain(|storage| storage.ulid != ulid);
+            }
+
+            GameRequest::SetResourceCap { player_ulid, resource_type, cap } => {
+                self.set_cap(&player_ulid, resource_type, cap, ctx);
+            }
+
+            GameRequest::SetResourceAmount { player_ulid, resource_type, amount } => {
+                // Ledgered as the difference, so the history still adds up
+                self.pool_mut(&player_ulid, ctx);
+                let change = match self.pools[&player_ulid].get(&resource_type) {
+                    Some((current, cap, _)) => amount.clamp(0.0, *cap) - current,
+                    None => amount,  // Unknown type: rejected by the transaction
+                };
+                if change != 0.0 {
+                    self.apply_transaction(0, &player_ulid, TransactionReason::Adjustment, &[(resource_type, change)], ctx);
+                }
+            }
+
+            GameRequest::ResetResources { player_ulid } => {
+                self.reset_pool(&player_ulid, ctx);
+            }
+
+            GameRequest::EnsurePlayerPool { player_ulid } => {
+                self.pool_mut(&player_ulid, ctx);
+            }
+
+            GameRequest::SetShortageConfig { config } => {
+                // Shortages this switches off end at the next production step or upkeep
+                self.shortage_config = config;
+            }
+
+            GameRequest::ApplyTransaction { request_id, player_ulid, reason, changes } => {
+                self.apply_transaction(request_id, &player_ulid, reason, &changes, ctx);
+            }
+
+            GameRequest::RegisterMarket { market_id, trade_modifier, wealth } => {
+                self.markets.entry(market_id)
+                    .and_modify(|market| market.set_terms(trade_modifier, wealth))
+                    .or_insert_with(|| Market::new(market_id, trade_modifier, wealth));
+            }
+
+            GameRequest::RemoveMarket { market_id } => {
+                self.markets.remove(&market_id);
+            }
+
+            GameRequest::RegisterCity { city_id, player_ulid, population, wealth, reputation, tax_rate } => {
+                self.pool_mut(&player_ulid, ctx);
+                self.cities.entry(city_id)
+                    .and_modify(|city| city.set_terms(player_ulid.clone(), population, wealth, tax_rate))
+                    .or_insert_with(|| City::new(city_id, player_ulid, population, wealth, reputation, tax_rate));
+            }
+
+            GameRequest::RemoveCity { city_id } => {
+                self.cities.remove(&city_id);
+            }
+
+            GameRequest::Trade { request_id, player_ulid, market_id, sell, buy } => {
+                self.trade(request_id, &player_ulid, market_id, sell, buy, ctx);
+            }
+
+            GameRequest::QueryMarket { request_id, market_id } => {
+                let (trade_modifier, prices) = match self.markets.get(&market_id) {
+                    Some(market) => (
+                        market.trade_modifier,
+                        resources().iter()
+                            .map(|def| MarketPrice {
+                                resource_type: def.id,
+                                stock: market.stock_of(def.id),
+                                price: market.price(def.id, def.base_price),
+                            })
+                            .collect(),
+                    ),
+                    None => (0.0, Vec::new()),
+                };
+                let _ = ctx.events.send(GameEvent::MarketReply { request_id, market_id, trade_modifier, prices });
+            }
+
+            GameRequest::RollbackTransaction { request_id, transaction_id } => {
+                self.rollback(request_id, transaction_id, ctx);
+            }
+
+            GameRequest::QueryLedger { request_id, player_ulid, reason, limit } => {
+                let mut entries: Vec<LedgerEntry> = self.ledger.iter()
+                    .rev()
+                    .filter(|entry| player_ulid.as_ref().is_none_or(|player| &entry.player_ulid == player))
+                    .filter(|entry| reason.is_none_or(|reason| entry.reason == reason))
+                    .take(if limit == 0 { usize::MAX } else { limit as usize })
+                    .cloned()
+                    .collect();
+                entries.reverse();
+                let _ = ctx.events.send(GameEvent::LedgerReply { request_id, entries });
+            }
+
+            GameRequest::QueryRecipes { request_id, player_ulid } => {
+                let recipes = self.recipes.iter()
+                    .filter(|recipe| recipe.player_ulid == player_ulid)
+                    .map(|recipe| RecipeReport {
+                        ulid: recipe.ulid.clone(),
+                        player_ulid: recipe.player_ulid.clone(),
+                        recipe: recipe.recipe.clone(),
+                        status: recipe.status.clone(),
+                        progress: recipe.progress,
+                        cycles_completed: recipe.cycles_completed,
+                    })
+                    .collect();
+                let _ = ctx.events.send(GameEvent::RecipesReply { request_id, recipes });
+            }
+
+            GameRequest::QueryForecast { request_id, player_ulid, horizon } => {
+                self.pool_mut(&player_ulid, ctx);
+                let resources = self.forecast(&player_ulid, horizon, ctx.world);
+                let _ = ctx.events.send(GameEvent::ForecastReply {
+                    request_id,
+                    player_ulid,
+                    horizon_secs: forecast::horizon_secs(horizon, TURN_PRODUCTION_SECS),
+                    resources,
+                });
+            }
+
+            GameRequest::ProcessTurnConsumption => {
+                self.consume_upkeep(ctx);
+                self.check_faith(ctx);
+                self.decay(ctx);
+                self.markets.values_mut().for_each(Market::restock);
+                self.tax_cities(ctx);
+            }
+
+            GameRequest::QueryCanAfford { request_id, player_ulid, cost } => {
+                self.pool_mut(&player_ulid, ctx);
+                let shortfall = self.resource_shortfall(&player_ulid, &cost);
+                let _ = ctx.events.send(GameEvent::CanAffordReply {
+                    request_id,
+                    affordable: shortfall.is_empty(),
+                    shortfall,
+                });
+            }
+
+            other => return Some(other),
+        }
+
+        None
+    }
+
+    fn collect(&mut self, ctx: &mut SystemContext) {
+        let results: Vec<EconomyWorkResult> = self.worker.results().collect();
+        for result in results {
+            self.apply_result(result, ctx);
+        }
+    }
+
+    fn tick(&mut self, delta: f64, ctx: &mut SystemContext) {
+        self.economy_timer += delta;
+        if self.economy_timer < ECONOMY_TICK_INTERVAL {
+            return;
+        }
+
+        // Send current producer/consumer state to worker
+        self.check_labor(ctx);
+        let work = self.production_work(self.economy_timer);
+
+        // Worker backed up: keep the elapsed time and retry next tick (no production is lost)
+        if self.worker.dispatch(work, ctx.deterministic, handle_economy_request).is_ok() {
+            self.economy_timer = 0.0;
+        }
+    }
+
+    fn snapshot(&self, _world: &World, out: &mut GameSnapshot) {
+        let flows = |list: &Vec<ResourceFlow>| -> Vec<FlowSnapshot> {
+            list.iter()
+                .map(|flow| FlowSnapshot {
+                    ulid: flow.ulid.clone(),
+                    player_ulid: flow.player_ulid.clone(),
+                    resource_type: flow.resource_type,
+                    rate: flow.rate,
+                    active: flow.active,
+                })
+                .collect()
+        };
+
+        out.resources = self.pools.iter()
+            .flat_map(|(player_ulid, pool)| pool.iter()
+                .map(move |(resource_type, (current, cap, rate))| ResourceSnapshot {
+                    player_ulid: player_ulid.clone(),
+                    resource_type: *resource_type,
+                    current: *current,
+                    cap: *cap,
+                    rate: *rate,
+                }))
+            .collect();
+        out.resources.sort_by(|a, b| (&a.player_ulid, a.resource_type).cmp(&(&b.player_ulid, b.resource_type)));
+
+        out.producers = flows(&self.producers);
+        out.consumers = flows(&self.consumers);
+        out.recipes = self.recipes.iter()
+            .map(|recipe| RecipeSnapshot {
+                ulid: recipe.ulid.clone(),
+                player_ulid: recipe.player_ulid.clone(),
+                recipe: recipe.recipe.clone(),
+                active: recipe.active,
+                progress: recipe.progress,
+                inputs_paid: recipe.inputs_paid,
+                cycles_completed: recipe.cycles_completed,
+            })
+            .collect();
+        out.ledger = self.ledger.iter().cloned().collect();
+        out.next_transaction_id = self.next_transaction_id;
+        out.shortage_config = self.shortage_config.clone();
+        out.markets = self.markets.values()
+            .map(|market| MarketSnapshot {
+                market_id: market.market_id,
+                trade_modifier: market.trade_modifier,
+                wealth: market.wealth,
+                stock: market.stock.iter().map(|(resource_type, stock)| (*resource_type, *stock)).collect(),
+            })
+            .collect();
+        out.cities = self.cities.values()
+            .map(|city| CitySnapshot {
+                city_id: city.city_id,
+                player_ulid: city.player_ulid.clone(),
+                population: city.population,
+                wealth: city.wealth,
+                reputation: city.reputation,
+                tax_rate: city.tax_rate,
+            })
+            .collect();
+        out.storages = self.storages.iter()
+            .map(|storage| StorageSnapshot {
+                ulid: storage.ulid.clone(),
+                player_ulid: storage.player_ulid.clone(),
+                protection: storage.protection.clone(),
+            })
+            .collect();
+    }
+
+    fn validate(&self, snapshot: &GameSnapshot) -> Result<(), SaveError> {
+        let mut seen = HashSet::new();
+        for r in &snapshot.resources {
+            if !seen.insert((&r.player_ulid, r.resource_type)) {
+                return Err(SaveError::Invalid(format!("duplicate resource type {} in pool {:?}", r.resource_type, r.player_ulid)));
+            }
+        }
+        if let Some(entry) = snapshot.ledger.iter().find(|entry| entry.transaction_id >= snapshot.next_transaction_id) {
+            return Err(SaveError::Invalid(format!("ledger transaction {} is not below the next id {}", entry.transaction_id, snapshot.next_transaction_id)));
+        }
+        Ok(())
+    }
+
+    fn restore(&mut self, snapshot: &GameSnapshot, ctx: &mut SystemContext) {
+        self.worker.discard_results();
+        ctx.world.resources.clear();  // Republished by emit_resources below
+
+        self.pools.clear();
+        for r in &snapshot.resources {
+            self.pools.entry(r.player_ulid.clone()).or_default().insert(r.resource_type, (r.current, r.cap, r.rate));
+        }
+        let flows = |list: &[FlowSnapshot]| -> Vec<ResourceFlow> {
+            list.iter()
+                .map(|f| ResourceFlow {
+                    ulid: f.ulid.clone(),
+                    player_ulid: f.player_ulid.clone(),
+                    resource_type: f.resource_type,
+                    rate: f.rate,
+                    active: f.active,
+                })
+                .collect()
+        };
+        self.producers = flows(&snapshot.producers);
+        self.consumers = flows(&snapshot.consumers);
+        self.recipes = snapshot.recipes.iter()
+            .map(|r| RecipeProducer {
+                ulid: r.ulid.clone(),
+                player_ulid: r.player_ulid.clone(),
+                recipe: normalize_recipe(r.recipe.clone()),
+                active: r.active,
+                progress: r.progress,
+                inputs_paid: r.inputs_paid,
+                cycles_completed: r.cycles_completed,
+                status: if r.active { RecipeStatus::Running } else { RecipeStatus::Inactive },  // Settled by the next step
+            })
+            .collect();
+        self.ledger = snapshot.ledger.iter().cloned().collect();
+        self.next_transaction_id = snapshot.next_transaction_id;
+        self.shortage_config = snapshot.shortage_config.clone();
+        self.markets = snapshot.markets.iter()
+            .map(|m| {
+                let mut market = Market::new(m.market_id, m.trade_modifier, m.wealth);
+                market.stock = m.stock.iter().copied().collect();
+                (m.market_id, market)
+            })
+            .collect();
+        self.cities = snapshot.cities.iter()
+            .map(|c| (c.city_id, City::new(c.city_id, c.player_ulid.clone(), c.population, c.wealth, c.reputation, c.tax_rate)))
+            .collect();
+        self.storages = snapshot.storages.iter()
+            .map(|s| Storage { ulid: s.ulid.clone(), player_ulid: s.player_ulid.clone(), protection: s.protection.clone() })
+            .collect();
+        self.shortages.clear();  // Reported again by the next production step / upkeep
+
+        self.emit_resources(ctx);
+    }
+
+    fn reset_for_replay(&mut self) {
+        self.economy_timer = 0.0;
+    }
+
+    fn supervise(&mut self, ctx: &mut SystemContext) {
+        // Stateless worker: the next economy tick simply goes to the new thread
+        self.worker.supervise(ctx);
+    }
+
+    fn shutdown(&mut self) {
+        self.worker.shutdown();
+    }
+
+    fn report_metrics(&self, metrics: &mut ActorMetrics) {
+        self.worker.report_queue_depths(self.name(), metrics);
+    }
+
+    fn set_turn_mode(&mut self, _enabled: bool, _ctx: &mut SystemContext) {
+        // Partial real-time intervals don't carry into (or out of) turn mode
+        self.economy_timer = 0.0;
+    }
+
+    fn turn_phase(&mut self, phase: TurnPhase, summary: &mut TurnSummary, ctx: &mut SystemContext) {
+        match phase {
+            TurnPhase::Upkeep => {
+                summary.food_upkeep = self.consume_upkeep(ctx);
+                self.check_faith(ctx);
+                summary.decay = self.decay(ctx);
+                self.markets.values_mut().for_each(Market::restock);
+                self.tax_cities(ctx);
+            }
+
+            TurnPhase::Production => {
+                // Apply any real-time result still in flight first, so the turn starts from it
+                self.collect(ctx);
+
+                let before: HashMap<(Vec<u8>, i64), f64> = self.pools.iter()
+                    .flat_map(|(player_ulid, pool)| pool.iter().map(move |(k, r)| ((player_ulid.clone(), *k), r.0)))
+                    .collect();
+                self.check_labor(ctx);
+                let result = handle_economy_request(self.production_work(TURN_PRODUCTION_SECS));
+                self.apply_result(result, ctx);
+
+                summary.production = self.pools.iter()
+                    .flat_map(|(player_ulid, pool)| pool.iter().map(|(k, r)| {
+                        let change = r.0 - before.get(&(player_ulid.clone(), *k)).copied().unwrap_or(0.0);
+                        (player_ulid.clone(), *k, change)
+                    }))
+                    .collect();
+                summary.production.sort_by(|a, b| (&a.0, a.1).cmp(&(&b.0, b.1)));
+            }
+
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// Entity system - registration, position/state updates and stat bookkeeping
+// Owns no state of its own; everything lives in the shared World
+
+use crate::entity::{EntityData, StatType, ENTITY_STATS};
+use crate::savegame::GameSnapshot;
+use crate::types::{GameEvent, GameRequest};
+use super::{GameSystem, SystemContext, World};
+
+#[derive(Default)]
+pub struct EntitySystem;
+
+impl EntitySystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl GameSystem for EntitySystem {
+    fn name(&self) -> &'static str {
+        "entities"
+    }
+
+    fn handle_request(&mut self, request: GameRequest, ctx: &mut SystemContext) -> Option<GameRequest> {
+        let world = ctx.world;
+
+        match request {
+            GameRequest::UpdateEntityPosition { ulid, position } => {
+                // Direct update (fast, no worker needed)
+                if let Some(mut entity) = world.entities.get_mut(&ulid) {
+                    entity.position = position;
+                }
+            }
+
+            GameRequest::UpdateEntityState { ulid, state } => {
+                // Direct update
+                if let Some(mut entity) = world.entities.get_mut(&ulid) {
+                    entity.state = state;
+                }
+            }
+
+            GameRequest::RemoveEntity { ulid } => {
+                world.entities.remove(&ulid);
+                world.entity_stats.remove(&ulid);
+                ENTITY_STATS.remove(&ulid);  // Clean up cache too
+            }
+
+            GameRequest::RegisterEntityStats { ulid, player_ulid, entity_type, terrain_type, position, combat_type, projectile_type, combat_range, aggro_range } => {
+                use crate::entity::{EntityStats, TerrainType as EntityTerrainType, CombatType, ProjectileType};
+
+                // Store player_ulid for team detection
+                world.entity_player_ulids.insert(ulid.clone(), player_ulid);
+
+                // Determine terrain type from i32
+                let et = match terrain_type {
+                    0 => EntityTerrainType::Water,
+                    _ => EntityTerrainType::Land,
+                };
+
+                // Create default stats based on terrain type
+                let stats = match et {
+                    EntityTerrainType::Water => EntityStats::new_water_entity(),
+                    EntityTerrainType::Land => EntityStats::new_land_entity(),
+                };
+
+                // Actor OWNS stats - store in Actor's DashMap
+                world.entity_stats.insert(ulid.clone(), stats.clone());
+
+                // Also sync to global ENTITY_STATS for GDScript queries (read-only cache)
+                ENTITY_STATS.insert(ulid.clone(), stats.clone());
+
+                // Emit initial stat events for ALL stats so GDScript knows all starting values
+                // This is CRITICAL for UI panels to display correctly
+                // Send in a specific order: MaxHP/MaxEnergy first, then current values, then other stats
+                let all_stat_types = [
+                    StatType::MaxHP,
+                    StatType::MaxEnergy,
+                    StatType::HP,
+                    StatType::Energy,
+                    StatType::Attack,
+                    StatType::Defense,
+                    StatType::Speed,
+                    StatType::Range,
+                    StatType::Morale,
+                    StatType::Level,
+                    StatType::Experience,
+                ];
+
+                for stat_type in all_stat_types.iter() {
+                    let value = stats.get(*stat_type);
+                    let _ = ctx.events.send(GameEvent::StatChanged {
+                        ulid: ulid.clone(),
+                        stat_type: *stat_type as i64,
+                        new_value: value,
+                    });
+                }
+
+                // Also create entity data if it doesn't exist
+                if !world.entities.contains_key(&ulid) {
+                    // Parse combat type and projectile type
+                    let ct = CombatType::from_u8(combat_type).unwrap_or(CombatType::Melee);
+                    let pt = ProjectileType::from_u8(projectile_type).unwrap_or(ProjectileType::None);
+
+                    // Create entity data with combat info
+                    let mut entity_data = EntityData::new(ulid.clone(), position, et, entity_type);
+                    entity_data.combat_type = ct;
+                    entity_data.projectile_type = pt;
+                    entity_data.combat_range = combat_range;
+                    entity_data.aggro_range = aggro_range;
+
+                    world.entities.insert(entity_data.ulid.clone(), entity_data);
+                }
+            }
+
+            GameRequest::SetStat { ulid, stat_type, value } => {
+                if let Some(st) = StatType::from_i64(stat_type) {
+                    if let Some(mut stats) = world.entity_stats.get_mut(&ulid) {
+                        stats.set(st, value);
+
+                        // Sync to global cache
+                        if let Some(mut cache) = ENTITY_STATS.get_mut(&ulid) {
+                            cache.set(st, value);
+                        }
+
+                        // Emit stat changed event
+                        let _ = ctx.events.send(GameEvent::StatChanged {
+                            ulid,
+                            stat_type,
+                            new_value: value,
+                        });
+                    }
+                }
+            }
+
+            GameRequest::GetStat { request_id, ulid, stat_type } => {
+                let value = StatType::from_i64(stat_type).and_then(|stat| {
+                    world.entity_stats.get(&ulid).map(|stats| stats.value().get(stat))
+                });
+                let _ = ctx.events.send(GameEvent::StatReply {
+                    request_id,
+                    ulid,
+                    stat_type,
+                    value,
+                });
+            }
+
+            other => return Some(other),
+        }
+
+        None
+    }
+
+    fn snapshot(&self, world: &World, out: &mut GameSnapshot) {
+        out.entities = world.entities.iter().map(|e| e.value().clone()).collect();
+        out.entities.sort_by(|a, b| a.ulid.cmp(&b.ulid));
+
+        out.entity_stats = world.entity_stats.iter().map(|e| (e.key().clone(), e.value().clone())).collect();
+        out.entity_stats.sort_by(|a, b| a.0.cmp(&b.0));
+
+        out.entity_player_ulids = world.entity_player_ulids.iter().map(|e| (e.key().clone(), e.value().clone())).collect();
+        out.entity_player_ulids.sort_by(|a, b| a.0.cmp(&b.0));
+    }
+
+    fn restore(&mut self, snapshot: &GameSnapshot, ctx: &mut SystemContext) {
+        let world = ctx.world;
+
+        for entry in world.entity_stats.iter() {
+            ENTITY_STATS.remove(entry.key());
+        }
+        world.entity_stats.clear();
+        for (ulid, stats) in &snapshot.entity_stats {
+            ENTITY_STATS.insert(ulid.clone(), stats.clone());
+            world.entity_stats.insert(ulid.clone(), stats.clone());
+        }
+
+        world.entities.clear();
+        for entity in &snapshot.entities {
+            world.entities.insert(entity.ulid.clone(), entity.clone());
+        }
+
+        world.entity_player_ulids.clear();
+        for (ulid, player_ulid) in &snapshot.entity_player_ulids {
+            world.entity_player_ulids.insert(ulid.clone(), player_ulid.clone());
+        }
+    }
+}
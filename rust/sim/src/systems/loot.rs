@@ -0,0 +1,69 @@
+// Loot system - turn-mode drops for units killed during combat resolution
+// Real-time loot stays with the host (it rolls on EntityDied); in turn mode the Actor
+// rolls with its own RNG so a replayed turn drops the same rewards
+
+use crate::drop_table::generate_loot_with;
+use crate::types::{GameRequest, TransactionReason, TurnLoot, TurnSummary};
+use super::{GameSystem, SystemContext, TurnPhase};
+
+#[derive(Default)]
+pub struct LootSystem;
+
+impl LootSystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl GameSystem for LootSystem {
+    fn name(&self) -> &'static str {
+        "loot"
+    }
+
+    fn handle_request(&mut self, request: GameRequest, _ctx: &mut SystemContext) -> Option<GameRequest> {
+        Some(request)
+    }
+
+    fn turn_phase(&mut self, phase: TurnPhase, summary: &mut TurnSummary, ctx: &mut SystemContext) {
+        if phase != TurnPhase::Loot {
+            return;
+        }
+
+        for ulid in &summary.deaths {
+            // Unknown/removed entities roll the default table
+            let entity_type = ctx.world.entities.get(ulid)
+                .map(|entity| entity.entity_type.clone())
+                .unwrap_or_default();
+            let rewards = generate_loot_with(&entity_type, &mut *ctx.rng);
+
+            // Drops go to the killer's owner (AI/neutral pool for AI kills or unknown killers)
+            let player_ulid = summary.killers.iter()
+                .find(|(dead, _)| dead == ulid)
+                .and_then(|(_, killer)| ctx.world.entity_player_ulids.get(killer))
+                .map(|owner| owner.value().clone())
+                .unwrap_or_default();
+
+            // Resource drops go to the economy right after this phase, as one loot transaction
+            let changes: Vec<(i64, f64)> = rewards.iter()
+                .filter_map(|reward| Some((reward.reward_type.resource_type()?, reward.amount as f64)))
+                .collect();
+            if !changes.is_empty() {
+                ctx.queue_request(GameRequest::ApplyTransaction {
+                    request_id: 0,
+                    player_ulid: player_ulid.clone(),
+                    reason: TransactionReason::Loot,
+                    changes,
+                });
+            }
+
+            summary.loot.push(TurnLoot {
+                ulid: ulid.clone(),
+                entity_type,
+                player_ulid,
+                rewards: rewards.iter()
+                    .map(|reward| (reward.reward_type.to_string(), reward.amount))
+                    .collect(),
+            });
+        }
+    }
+}
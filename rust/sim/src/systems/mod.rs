@@ -0,0 +1,554 @@
+// Game systems - self-contained subsystems driven by the Actor
+// Each system owns its state and workers; the Actor only routes requests,
+// calls collect/tick in order, and stitches snapshots together for save/load.
+// Adding a subsystem = implement GameSystem + GameActor::register_system.
+
+pub mod entities;
+pub mod spawning;
+pub mod pathfinding;
+pub mod combat;
+pub mod economy;
+pub mod cards;
+pub mod loot;
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::io;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender, TryIter, TrySendError};
+use dashmap::DashMap;
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::entity::{EntityData, EntityStats};
+use crate::metrics::ActorMetrics;
+use crate::replay::EventSink;
+use crate::savegame::{GameSnapshot, SaveError};
+use crate::types::{CombatEntitySnapshot, CombatProfile, GameEvent, GameRequest, TurnSummary};
+
+pub use entities::EntitySystem;
+pub use spawning::SpawnSystem;
+pub use pathfinding::PathfindingSystem;
+pub use combat::CombatSystem;
+pub use economy::EconomySystem;
+pub use cards::CardSystem;
+pub use loot::LootSystem;
+
+/// Phases of a StepTurn, run in this order
+/// Every system sees each phase (in registration order) before the next phase starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnPhase {
+    Upkeep,        // Per-turn costs (food for player units)
+    Production,    // One turn of producer/consumer output
+    Movement,      // Resolve move orders issued since the last turn
+    Combat,        // Combat rounds between units in range
+    Loot,          // Drops for units killed this turn
+    ComboRewards,  // Combos completed by cards placed this turn
+}
+
+impl TurnPhase {
+    pub const ORDER: [TurnPhase; 6] = [
+        TurnPhase::Upkeep,
+        TurnPhase::Production,
+        TurnPhase::Movement,
+        TurnPhase::Combat,
+        TurnPhase::Loot,
+        TurnPhase::ComboRewards,
+    ];
+}
+
+/// A subsystem plugged into the Actor tick
+///
+/// Per Actor tick: requests are offered to systems in registration order until one
+/// consumes them, then every system supervises its workers, collects their results, and ticks.
+pub trait GameSystem: Send {
+    /// Short name for logs
+    fn name(&self) -> &'static str;
+
+    /// Called once when registered (start workers, emit initial state)
+    fn init(&mut self, _ctx: &mut SystemContext) {}
+
+    /// Handle a request, or return it unchanged so the next system can
+    fn handle_request(&mut self, request: GameRequest, ctx: &mut SystemContext) -> Option<GameRequest>;
+
+    /// Drain worker results, apply them and emit events
+    fn collect(&mut self, _ctx: &mut SystemContext) {}
+
+    /// Periodic work with the elapsed tick delta (seconds)
+    fn tick(&mut self, _delta: f64, _ctx: &mut SystemContext) {}
+
+    /// Write this system's part of a save
+    fn snapshot(&self, _world: &World, _out: &mut GameSnapshot) {}
+
+    /// Reject a save before anything is restored (keeps LoadGame all-or-nothing)
+    fn validate(&self, _snapshot: &GameSnapshot) -> Result<(), SaveError> {
+        Ok(())
+    }
+
+    /// Replace this system's state from a validated save
+    fn restore(&mut self, _snapshot: &GameSnapshot, _ctx: &mut SystemContext) {}
+
+    /// Actor switched to deterministic mode (replay recording): drop timing/worker-held state
+    fn reset_for_replay(&mut self) {}
+
+    /// Actor left deterministic mode (replay recording stopped): hand inline state back to the workers
+    fn resume_workers(&mut self, _ctx: &mut SystemContext) {}
+
+    /// Check worker threads once per tick, restarting dead ones and resending lost state
+    fn supervise(&mut self, _ctx: &mut SystemContext) {}
+
+    /// Stop and join worker threads (Actor shutdown)
+    fn shutdown(&mut self) {}
+
+    /// Add queue depths / latencies to the Actor metrics (end of every tick)
+    fn report_metrics(&self, _metrics: &mut ActorMetrics) {}
+
+    /// Actor entered/left turn mode; `tick` is not called while it is on
+    fn set_turn_mode(&mut self, _enabled: bool, _ctx: &mut SystemContext) {}
+
+    /// Run this system's part of a turn phase
+    /// Work runs inline so its results are applied before the next phase (and before TurnCompleted)
+    fn turn_phase(&mut self, _phase: TurnPhase, _summary: &mut TurnSummary, _ctx: &mut SystemContext) {}
+}
+
+/// (player_ulid, resource_type) -> (current, cap, rate), kept in step with ResourceChanged
+pub type SharedResources = Arc<DashMap<(Vec<u8>, i64), (f64, f64, f64)>>;
+
+/// Entity state shared by all systems
+/// DashMaps so systems can update entries through `&World`
+pub struct World {
+    pub entities: DashMap<Vec<u8>, EntityData>,
+    pub entity_stats: Arc<DashMap<Vec<u8>, EntityStats>>,  // Global ACTOR_ENTITY_STATS unless deterministic
+    pub entity_player_ulids: DashMap<Vec<u8>, Vec<u8>>,     // ULID -> player_ulid (for team detection)
+    pub resources: SharedResources,  // Global ACTOR_RESOURCES unless deterministic; only the economy writes it
+}
+
+impl World {
+    pub fn new(
+        entity_stats: Arc<DashMap<Vec<u8>, EntityStats>>,
+        resources: SharedResources,
+    ) -> Self {
+        Self {
+            entities: DashMap::new(),
+            entity_stats,
+            entity_player_ulids: DashMap::new(),
+            resources,
+        }
+    }
+
+    pub fn get_occupied_positions(&self) -> Vec<(i32, i32)> {
+        self.entities.iter()
+            .map(|entry| entry.value().position)
+            .collect()
+    }
+
+    pub fn get_entity_positions(&self) -> HashMap<Vec<u8>, (i32, i32)> {
+        self.entities.iter()
+            .map(|entry| (entry.key().clone(), entry.value().position))
+            .collect()
+    }
+
+    pub fn get_combat_snapshot(&self) -> Vec<CombatEntitySnapshot> {
+        let mut snapshot: Vec<CombatEntitySnapshot> = self.entities.iter()
+            .filter_map(|entry| {
+                let ulid = entry.key();
+                let entity = entry.value();
+
+                // Get stats from Actor's entity_stats
+                if let Some(stats) = self.entity_stats.get(ulid) {
+                    use crate::entity::StatType;
+                    use crate::entity::TerrainType as EntityTerrainType;
+                    use crate::terrain_cache::TerrainType as CacheTerrainType;
+
+                    // Convert entity::TerrainType to terrain_cache::TerrainType
+                    let cache_terrain = match entity.terrain_type {
+                        EntityTerrainType::Water => CacheTerrainType::Water,
+                        EntityTerrainType::Land => CacheTerrainType::Land,
+                    };
+
+                    // Get player_ulid for team detection (empty = AI team)
+                    let player_ulid = self.entity_player_ulids.get(ulid)
+                        .map(|r| r.value().clone())
+                        .unwrap_or_default();
+
+                    // Use ceil() for HP to ensure entities with fractional HP (0.1-0.9) are still alive
+                    // This prevents entities from appearing dead (hp=0) when they still have <1.0 HP
+                    let hp_f32 = stats.value().get(StatType::HP);
+                    let hp_ceiled = if hp_f32 > 0.0 { hp_f32.ceil() as i32 } else { 0 };
+
+                    // Same for mana - ceil to ensure fractional mana is still available
+                    let mana_f32 = stats.value().get(StatType::Mana);
+                    let mana_ceiled = if mana_f32 > 0.0 { mana_f32.ceil() as i32 } else { 0 };
+
+                    Some(CombatEntitySnapshot {
+                        ulid: ulid.clone(),
+                        player_ulid,
+                        position: entity.position,
+                        terrain_type: cache_terrain,
+                        hp: hp_ceiled,
+                        max_hp: stats.value().get(StatType::MaxHP) as i32,
+                        mana: mana_ceiled,
+                        max_mana: stats.value().get(StatType::MaxMana) as i32,
+                        attack: stats.value().get(StatType::Attack) as i32,
+                        defense: stats.value().get(StatType::Defense) as i32,
+                        // Stats registered without Morale fight at full strength
+                        morale: stats.value().stats.get(&StatType::Morale).copied().unwrap_or(100.0),
+                        luck: stats.value().get(StatType::Luck),
+                        evasion: stats.value().get(StatType::Evasion),
+                        range: stats.value().get(StatType::Range) as i32,
+                        combat_type: entity.combat_type.to_u8(),
+                        projectile_type: entity.projectile_type.to_u8(),
+                        combat_range: entity.combat_range,
+                        aggro_range: entity.aggro_range,
+                        // Filled in by the combat system (CombatSystem::combat_snapshot)
+                        profile: CombatProfile::default(),
+                        threat: Vec::new(),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // Stable order so combat resolution doesn't depend on DashMap iteration order
+        snapshot.sort_by(|a, b| a.ulid.cmp(&b.ulid));
+        snapshot
+    }
+}
+
+/// What a system gets to work with during a call
+pub struct SystemContext<'a> {
+    pub world: &'a World,
+    pub events: &'a EventSink,
+    pub rng: &'a mut StdRng,
+    /// Run worker jobs inline on the Actor thread (replay recording/verification)
+    pub deterministic: bool,
+    follow_ups: &'a mut Vec<GameRequest>,
+}
+
+impl<'a> SystemContext<'a> {
+    pub fn new(
+        world: &'a World,
+        events: &'a EventSink,
+        rng: &'a mut StdRng,
+        deterministic: bool,
+        follow_ups: &'a mut Vec<GameRequest>,
+    ) -> Self {
+        Self { world, events, rng, deterministic, follow_ups }
+    }
+
+    /// Ask another system for work (e.g. combat asking for a path)
+    /// Routed like a Godot request later in the same tick, but never recorded in replays
+    pub fn queue_request(&mut self, request: GameRequest) {
+        self.follow_ups.push(request);
+    }
+
+    /// Allocate a ULID for a new entity (seeded in deterministic mode)
+    pub fn next_ulid(&mut self) -> Vec<u8> {
+        if self.deterministic {
+            ulid::Ulid::from_parts(0, self.rng.random()).to_bytes().to_vec()
+        } else {
+            ulid::Ulid::new().to_bytes().to_vec()
+        }
+    }
+}
+
+/// Starts a system's worker thread(s) on the given channel ends
+pub type WorkerSpawner<Req, Res> = fn(Receiver<Req>, Sender<Res>) -> io::Result<Vec<JoinHandle<()>>>;
+
+/// Restarts before a worker is left dead (avoids a panic loop flooding events)
+const MAX_WORKER_RESTARTS: u32 = 5;
+/// Uptime after which a restarted worker counts as stable and gets its full restart budget back
+const WORKER_STABLE_PERIOD: Duration = Duration::from_secs(60);
+
+/// Channels between a system and its worker thread(s), plus their supervisor
+/// In deterministic mode jobs run inline and results go through the same result channel,
+/// so collect() never needs to know where a result came from
+///
+/// The job queue is bounded (dispatch hands the job back when full, so the system applies
+/// its own backpressure policy). The result queue stays unbounded: inline mode pushes results
+/// from the Actor thread itself, which would deadlock on a full bounded queue.
+pub struct WorkerLink<Req, Res> {
+    capacity: usize,
+    tx: Sender<Req>,
+    rx: Receiver<Res>,
+    inline_tx: Sender<Res>,
+    worker_ends: Option<(Receiver<Req>, Sender<Res>)>,
+
+    // === SUPERVISION (threaded mode only) ===
+    name: &'static str,
+    spawner: Option<WorkerSpawner<Req, Res>>,
+    handles: Vec<JoinHandle<()>>,
+    restarts: u32,                  // Restarts since the worker last stayed up for `stable_period`
+    last_restart: Option<Instant>,
+    stable_period: Duration,
+}
+
+impl<Req, Res> WorkerLink<Req, Res> {
+    /// `capacity` = jobs that may wait for a worker before dispatch starts refusing
+    pub fn new(capacity: usize) -> Self {
+        let (tx, worker_rx) = bounded(capacity);
+        let (worker_tx, rx) = unbounded();
+        Self {
+            capacity,
+            tx,
+            rx,
+            inline_tx: worker_tx.clone(),
+            worker_ends: Some((worker_rx, worker_tx)),
+            name: "worker",
+            spawner: None,
+            handles: Vec::new(),
+            restarts: 0,
+            last_restart: None,
+            stable_period: WORKER_STABLE_PERIOD,
+        }
+    }
+
+    /// Start the worker thread(s) and supervise them from now on
+    /// A failed start is reported like a crash and retried by `supervise`
+    pub fn start(&mut self, name: &'static str, spawner: WorkerSpawner<Req, Res>, ctx: &SystemContext) {
+        self.name = name;
+        self.spawner = Some(spawner);
+
+        let Some((worker_rx, worker_tx)) = self.worker_ends.take() else {
+            return;
+        };
+        match spawner(worker_rx, worker_tx) {
+            Ok(handles) => self.handles = handles,
+            Err(e) => self.report_failure(format!("failed to start: {}", e), true, ctx),
+        }
+    }
+
+    /// Detect dead worker threads and restart them on a fresh channel pair
+    /// Returns true when the worker was restarted (the system should resend any state it keeps)
+    pub fn supervise(&mut self, ctx: &SystemContext) -> bool {
+        let Some(spawner) = self.spawner else {
+            return false;
+        };
+        if self.restarts >= MAX_WORKER_RESTARTS {
+            return false;
+        }
+
+        // Started but no threads = the last start/restart failed
+        let dead = self.handles.is_empty() || self.handles.iter().any(|handle| handle.is_finished());
+        if !dead {
+            return false;
+        }
+
+        // Join the dead threads for their panic message; survivors of a pool are detached and
+        // exit on their own once the old request channel is dropped below
+        let (finished, running): (Vec<_>, Vec<_>) = std::mem::take(&mut self.handles)
+            .into_iter()
+            .partition(|handle| handle.is_finished());
+        drop(running);
+        let error = finished
+            .into_iter()
+            .find_map(|handle| handle.join().err().map(panic_message))
+            .unwrap_or_else(|| "worker thread exited".to_string());
+
+        // Only crashes in quick succession use up the budget; a worker that ran stable earns it back
+        if self.last_restart.is_some_and(|at| at.elapsed() >= self.stable_period) {
+            self.restarts = 0;
+        }
+        self.restarts += 1;
+        let restarting = self.restarts < MAX_WORKER_RESTARTS;
+        self.report_failure(error, restarting, ctx);
+        if !restarting {
+            return false;
+        }
+
+        // Fresh channel pair; results that already arrived are carried over
+        let (tx, worker_rx) = bounded(self.capacity);
+        let (worker_tx, rx) = unbounded();
+        self.tx = tx;
+        let old_rx = std::mem::replace(&mut self.rx, rx);
+        self.inline_tx = worker_tx.clone();
+        for result in old_rx.try_iter() {
+            let _ = self.inline_tx.send(result);
+        }
+
+        match spawner(worker_rx, worker_tx) {
+            Ok(handles) => {
+                sim_warn!("Actor: Restarted {} (restart {}/{})", self.name, self.restarts, MAX_WORKER_RESTARTS - 1);
+                self.handles = handles;
+                self.last_restart = Some(Instant::now());
+                true
+            }
+            Err(e) => {
+                self.report_failure(format!("failed to restart: {}", e), true, ctx);
+                false
+            }
+        }
+    }
+
+    /// Stop the worker thread(s) and wait for them to finish their current job
+    pub fn shutdown(&mut self) {
+        self.spawner = None;
+
+        // Dropping the only request sender ends each worker's recv() loop
+        let (tx, _) = bounded(0);
+        drop(std::mem::replace(&mut self.tx, tx));
+        self.worker_ends = None;
+
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+
+    fn report_failure(&self, error: String, restarted: bool, ctx: &SystemContext) {
+        sim_error!("Actor: {} died: {}", self.name, error);
+        let _ = ctx.events.send(GameEvent::WorkerFailed {
+            worker: self.name.to_string(),
+            error,
+            restarted,
+        });
+    }
+
+    /// Hand a job to the worker, or run it inline when deterministic
+    /// Err(job) when the job queue is full (or the worker is down until the supervisor restarts it)
+    pub fn dispatch(&self, job: Req, deterministic: bool, handler: impl FnOnce(Req) -> Res) -> Result<(), Req> {
+        if deterministic {
+            let _ = self.inline_tx.send(handler(job));
+            return Ok(());
+        }
+
+        self.try_send(job)
+    }
+
+    /// Queue a job for the worker without blocking
+    /// Err(job) when the job queue is full (or the worker is down until the supervisor restarts it)
+    pub fn try_send(&self, job: Req) -> Result<(), Req> {
+        self.tx.try_send(job).map_err(|e| match e {
+            TrySendError::Full(job) | TrySendError::Disconnected(job) => job,
+        })
+    }
+
+    /// Send straight to the worker (control messages that must not be dropped)
+    /// Blocks while the job queue is full
+    pub fn send(&self, job: Req) {
+        let _ = self.tx.send(job);
+    }
+
+    /// Result sender for inline work that produces several results
+    pub fn inline_sender(&self) -> &Sender<Res> {
+        &self.inline_tx
+    }
+
+    pub fn results(&self) -> TryIter<'_, Res> {
+        self.rx.try_iter()
+    }
+
+    /// Drop results of work dispatched before a LoadGame
+    pub fn discard_results(&self) {
+        self.rx.try_iter().for_each(drop);
+    }
+
+    /// Report both channel backlogs under the given name
+    pub fn report_queue_depths(&self, worker: &str, metrics: &mut ActorMetrics) {
+        metrics.set_worker_queue(worker, self.tx.len(), self.rx.len());
+    }
+}
+
+/// Text of a worker panic payload
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload.downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "worker panicked".to_string())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn echo_worker(rx: Receiver<i32>, tx: Sender<i32>) -> io::Result<Vec<JoinHandle<()>>> {
+        let handle = std::thread::Builder::new()
+            .name("echo-worker".to_string())
+            .spawn(move || {
+                while let Ok(job) = rx.recv() {
+                    assert!(job >= 0, "negative job");
+                    let _ = tx.send(job);
+                }
+            })?;
+        Ok(vec![handle])
+    }
+
+    fn wait_until(mut condition: impl FnMut() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !condition() {
+            assert!(Instant::now() < deadline, "timed out");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_supervisor_restarts_dead_worker() {
+        let world = World::new(Arc::new(DashMap::new()), Arc::new(DashMap::new()));
+        let (event_tx, event_rx) = unbounded();
+        let events = EventSink::new(event_tx);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut follow_ups = Vec::new();
+        let ctx = SystemContext::new(&world, &events, &mut rng, false, &mut follow_ups);
+
+        let mut link: WorkerLink<i32, i32> = WorkerLink::new(4);
+        link.start("echo-worker", echo_worker, &ctx);
+        assert!(!link.supervise(&ctx));
+
+        link.send(-1);
+        wait_until(|| link.handles.iter().all(|handle| handle.is_finished()));
+        assert!(link.supervise(&ctx));
+        assert!(event_rx.try_iter().any(|e| matches!(e,
+            GameEvent::WorkerFailed { ref error, restarted: true, .. } if error.contains("negative job"))));
+
+        // Fresh channels: new jobs reach the restarted worker
+        link.send(5);
+        let mut result = None;
+        wait_until(|| {
+            result = link.results().next();
+            result.is_some()
+        });
+        assert_eq!(result, Some(5));
+
+        link.shutdown();
+        assert!(link.handles.is_empty());
+    }
+
+    #[test]
+    fn test_restart_budget_only_counts_crashes_in_quick_succession() {
+        let world = World::new(Arc::new(DashMap::new()), Arc::new(DashMap::new()));
+        let (event_tx, _event_rx) = unbounded();
+        let events = EventSink::new(event_tx);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut follow_ups = Vec::new();
+        let ctx = SystemContext::new(&world, &events, &mut rng, false, &mut follow_ups);
+        let crash = |link: &mut WorkerLink<i32, i32>| {
+            link.send(-1);
+            wait_until(|| link.handles.iter().all(|handle| handle.is_finished()));
+            link.supervise(&ctx)
+        };
+
+        // Crashes spaced past the stable period never run out of restarts
+        let mut link: WorkerLink<i32, i32> = WorkerLink::new(4);
+        link.stable_period = Duration::from_millis(20);
+        link.start("echo-worker", echo_worker, &ctx);
+        for _ in 0..MAX_WORKER_RESTARTS * 2 {
+            std::thread::sleep(Duration::from_millis(30));
+            assert!(crash(&mut link));
+        }
+        assert_eq!(link.restarts, 1);
+        link.shutdown();
+
+        // A crash loop is given up on
+        let mut link: WorkerLink<i32, i32> = WorkerLink::new(4);
+        link.start("echo-worker", echo_worker, &ctx);
+        for _ in 1..MAX_WORKER_RESTARTS {
+            assert!(crash(&mut link));
+        }
+        assert!(!crash(&mut link));
+        assert!(!link.supervise(&ctx));
+    }
+}
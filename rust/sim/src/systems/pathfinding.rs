@@ -0,0 +1,389 @@
+// Pathfinding system - A* requests on the worker pool, random destinations, path cost queries
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Instant;
+use rand::Rng;
+
+use crate::metrics::{ActorMetrics, TimingStats};
+use crate::types::{GameEvent, GameRequest, RequestId, TurnSummary};
+use crate::workers::{handle_path_request, spawn_pathfinding_pool, PathWorkRequest, PathWorkResult};
+use super::{GameSystem, SystemContext, TurnPhase, WorkerLink};
+
+/// Worker threads in the pathfinding pool
+const PATHFINDING_POOL_SIZE: usize = 4;
+/// Path jobs that may wait for a pool thread
+const PATH_QUEUE_CAPACITY: usize = 256;
+/// Requests held back while the pool queue is full; beyond this new requests are rejected
+const MAX_PATH_BACKLOG: usize = 4096;
+
+/// What a path result answers (entity move, or path cost query)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PathKey {
+    Entity(Vec<u8>),
+    Query(RequestId),
+}
+
+pub struct PathfindingSystem {
+    worker: WorkerLink<PathWorkRequest, PathWorkResult>,
+    backlog: VecDeque<PathWorkRequest>,    // Accepted but waiting for room in the pool queue (oldest first)
+    latest_jobs: HashMap<Vec<u8>, u64>,    // Newest path job per entity; older jobs and results are superseded
+    next_job_id: u64,
+    superseded: u64,                       // Jobs/results dropped because a newer request replaced them
+    in_flight: HashMap<PathKey, Instant>,  // Accept time (latest request per entity)
+    latency: TimingStats,
+    turn_mode: bool,
+    move_orders: VecDeque<PathWorkRequest>,  // Turn mode: entity paths held for the next movement phase
+}
+
+impl Default for PathfindingSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PathfindingSystem {
+    pub fn new() -> Self {
+        Self {
+            worker: WorkerLink::new(PATH_QUEUE_CAPACITY),
+            backlog: VecDeque::new(),
+            latest_jobs: HashMap::new(),
+            next_job_id: 0,
+            superseded: 0,
+            in_flight: HashMap::new(),
+            latency: TimingStats::default(),
+            turn_mode: false,
+            move_orders: VecDeque::new(),
+        }
+    }
+
+    fn next_job_id(&mut self) -> u64 {
+        self.next_job_id += 1;
+        self.next_job_id
+    }
+
+    /// Entity path jobs are superseded once a newer request for the same entity was accepted
+    fn is_superseded(&self, work: &PathWorkRequest) -> bool {
+        work.query_id.is_none() && self.latest_jobs.get(&work.ulid) != Some(&work.job_id)
+    }
+
+    /// Accept a job into the backlog and dispatch what fits
+    /// Returns false (job dropped) when the backlog is full even after removing superseded jobs
+    fn enqueue(&mut self, work: PathWorkRequest, deterministic: bool) -> bool {
+        if self.backlog.len() >= MAX_PATH_BACKLOG {
+            let before = self.backlog.len();
+            let backlog = std::mem::take(&mut self.backlog);
+            self.backlog = backlog.into_iter().filter(|job| !self.is_superseded(job)).collect();
+            self.superseded += (before - self.backlog.len()) as u64;
+            if self.backlog.len() >= MAX_PATH_BACKLOG {
+                return false;
+            }
+        }
+
+        let key = match work.query_id {
+            Some(request_id) => PathKey::Query(request_id),
+            None => {
+                self.latest_jobs.insert(work.ulid.clone(), work.job_id);
+                PathKey::Entity(work.ulid.clone())
+            }
+        };
+        self.in_flight.insert(key, Instant::now());
+        self.backlog.push_back(work);
+        self.pump(deterministic);
+        true
+    }
+
+    /// Hold an entity path for the next movement phase, replacing that entity's older order
+    /// Returns false (job dropped) when too many orders are already waiting
+    fn hold_move_order(&mut self, work: PathWorkRequest) -> bool {
+        let before = self.move_orders.len();
+        self.move_orders.retain(|job| job.ulid != work.ulid);
+        self.superseded += (before - self.move_orders.len()) as u64;
+        if self.move_orders.len() >= MAX_PATH_BACKLOG {
+            return false;
+        }
+
+        self.latest_jobs.insert(work.ulid.clone(), work.job_id);
+        self.in_flight.insert(PathKey::Entity(work.ulid.clone()), Instant::now());
+        self.move_orders.push_back(work);
+        true
+    }
+
+    /// Move backlog jobs to the pool queue until it is full
+    fn pump(&mut self, deterministic: bool) {
+        while let Some(work) = self.backlog.pop_front() {
+            if self.is_superseded(&work) {
+                self.superseded += 1;
+                continue;
+            }
+            if let Err(work) = self.worker.dispatch(work, deterministic, handle_path_request) {
+                self.backlog.push_front(work);
+                break;
+            }
+        }
+    }
+
+    /// Result for an entity path: false if a newer request superseded it
+    fn accept_entity_result(&mut self, ulid: &[u8], job_id: u64) -> bool {
+        if self.latest_jobs.get(ulid) != Some(&job_id) {
+            self.superseded += 1;
+            return false;
+        }
+        self.latest_jobs.remove(ulid);
+        self.record_latency(PathKey::Entity(ulid.to_vec()));
+        true
+    }
+
+    fn record_latency(&mut self, key: PathKey) {
+        if let Some(dispatched_at) = self.in_flight.remove(&key) {
+            self.latency.record_since(dispatched_at);
+        }
+    }
+}
+
+impl GameSystem for PathfindingSystem {
+    fn name(&self) -> &'static str {
+        "pathfinding"
+    }
+
+    fn init(&mut self, ctx: &mut SystemContext) {
+        if !ctx.deterministic {
+            self.worker.start("pathfinding-pool", |rx, tx| spawn_pathfinding_pool(rx, tx, PATHFINDING_POOL_SIZE), ctx);
+        }
+    }
+
+    fn handle_request(&mut self, request: GameRequest, ctx: &mut SystemContext) -> Option<GameRequest> {
+        match request {
+            GameRequest::RequestPath { ulid, terrain_type, start, goal, avoid_entities } => {
+                let work = PathWorkRequest {
+                    ulid: ulid.clone(),
+                    job_id: self.next_job_id(),
+                    query_id: None,
+                    terrain_type,
+                    start,
+                    goal,
+                    avoid_entities,
+                    entity_positions: if avoid_entities {
+                        Some(ctx.world.get_entity_positions())
+                    } else {
+                        None
+                    },
+                };
+
+                let accepted = if self.turn_mode {
+                    self.hold_move_order(work)
+                } else {
+                    self.enqueue(work, ctx.deterministic)
+                };
+                if !accepted {
+                    sim_warn!("Actor: Path backlog full, rejecting path request");
+                    let _ = ctx.events.send(GameEvent::RequestRejected {
+                        kind: "RequestPath".to_string(),
+                        ulid,
+                        reason: "path queue full".to_string(),
+                    });
+                }
+            }
+
+            GameRequest::RequestRandomDest { ulid, terrain_type, start, min_distance, max_distance } => {
+                // Pick random destination in hex grid (not Cartesian!)
+                let rng = &mut *ctx.rng;
+
+                // Try to find a valid destination (max 10 attempts)
+                let mut found_dest = None;
+                for _attempt in 0..10 {
+                    // Pick random hex distance (in hex tiles, not pixels!)
+                    let distance = rng.random_range(min_distance..=max_distance);
+
+                    // Pick random direction in hex grid (6 cardinal directions + diagonals)
+                    // Use axial hex coordinates: q (x-axis), r (y-axis)
+                    let angle_index = rng.random_range(0..6);
+                    let hex_directions = [
+                        (1, 0), (1, -1), (0, -1),  // E, NE, NW
+                        (-1, 0), (-1, 1), (0, 1),  // W, SW, SE
+                    ];
+                    let (base_dq, base_dr) = hex_directions[angle_index];
+
+                    // Scale by distance and add some randomness for diagonal movement
+                    let rand_offset = rng.random_range(-distance/3..=distance/3);
+                    let dq = base_dq * distance + rand_offset;
+                    let dr = base_dr * distance - rand_offset;  // Subtract to maintain hex constraint
+
+                    let dest = (start.0 + dq, start.1 + dr);
+
+                    // Check if destination is walkable and not occupied
+                    use crate::terrain_cache;
+                    let dest_terrain = terrain_cache::get_terrain(dest.0, dest.1);
+
+                    if dest_terrain == terrain_type {
+                        // Check if not occupied by another entity
+                        let is_occupied = ctx.world.entities.iter().any(|entry| {
+                            entry.value().position == dest
+                        });
+
+                        if !is_occupied {
+                            found_dest = Some(dest);
+                            break;
+                        }
+                    }
+                }
+
+                if let Some(dest) = found_dest {
+                    let _ = ctx.events.send(GameEvent::RandomDestFound {
+                        ulid,
+                        destination: dest,
+                        found: true,
+                    });
+                } else {
+                    let _ = ctx.events.send(GameEvent::RandomDestFound {
+                        ulid,
+                        destination: start,
+                        found: false,
+                    });
+                }
+            }
+
+            GameRequest::QueryPathCost { request_id, terrain_type, start, goal } => {
+                let work = PathWorkRequest {
+                    ulid: Vec::new(),
+                    job_id: self.next_job_id(),
+                    query_id: Some(request_id),
+                    terrain_type,
+                    start,
+                    goal,
+                    avoid_entities: false,
+                    entity_positions: None,
+                };
+
+                if !self.enqueue(work, ctx.deterministic) {
+                    // Still exactly one reply per query
+                    let _ = ctx.events.send(GameEvent::RequestRejected {
+                        kind: "QueryPathCost".to_string(),
+                        ulid: Vec::new(),
+                        reason: "path queue full".to_string(),
+                    });
+                    let _ = ctx.events.send(GameEvent::PathCostReply {
+                        request_id,
+                        found: false,
+                        path: Vec::new(),
+                        cost: 0.0,
+                    });
+                }
+            }
+
+            other => return Some(other),
+        }
+
+        None
+    }
+
+    fn collect(&mut self, ctx: &mut SystemContext) {
+        let results: Vec<PathWorkResult> = self.worker.results().collect();
+        for result in results {
+            match result {
+                PathWorkResult::Success { ulid, job_id, path, cost } => {
+                    if !self.accept_entity_result(&ulid, job_id) {
+                        continue;
+                    }
+                    let _ = ctx.events.send(GameEvent::PathFound {
+                        ulid,
+                        path,
+                        cost,
+                    });
+                }
+                PathWorkResult::Failed { ulid, job_id } => {
+                    if !self.accept_entity_result(&ulid, job_id) {
+                        continue;
+                    }
+                    let _ = ctx.events.send(GameEvent::PathFailed {
+                        ulid,
+                    });
+                }
+                PathWorkResult::RandomDestSuccess { ulid, destination } => {
+                    let _ = ctx.events.send(GameEvent::RandomDestFound {
+                        ulid,
+                        destination,
+                        found: true,
+                    });
+                }
+                PathWorkResult::Query { request_id, path, cost } => {
+                    self.record_latency(PathKey::Query(request_id));
+                    let _ = ctx.events.send(GameEvent::PathCostReply {
+                        request_id,
+                        found: !path.is_empty(),
+                        path,
+                        cost,
+                    });
+                }
+                PathWorkResult::RandomDestFailed { ulid } => {
+                    let _ = ctx.events.send(GameEvent::RandomDestFound {
+                        ulid,
+                        destination: (0, 0),
+                        found: false,
+                    });
+                }
+            }
+        }
+
+        // Here rather than in tick(): queries must reach the pool while paused or in turn mode
+        self.pump(ctx.deterministic);
+    }
+
+    fn restore(&mut self, _snapshot: &crate::savegame::GameSnapshot, _ctx: &mut SystemContext) {
+        self.worker.discard_results();
+        self.backlog.clear();
+        self.move_orders.clear();
+        self.latest_jobs.clear();
+        self.in_flight.clear();
+    }
+
+    fn supervise(&mut self, ctx: &mut SystemContext) {
+        // Paths in flight on a dead pool are lost; entities re-request when they stay idle
+        // Backlog jobs were never dispatched and go to the new pool
+        if self.worker.supervise(ctx) {
+            let queued: HashSet<u64> = self.backlog.iter().map(|job| job.job_id).collect();
+            self.latest_jobs.retain(|_, job_id| queued.contains(job_id));
+            self.in_flight.clear();
+        }
+    }
+
+    fn shutdown(&mut self) {
+        self.worker.shutdown();
+    }
+
+    fn report_metrics(&self, metrics: &mut ActorMetrics) {
+        self.worker.report_queue_depths(self.name(), metrics);
+        metrics.path_latency = self.latency.clone();
+        metrics.path_backlog = self.backlog.len() + self.move_orders.len();
+        metrics.superseded_paths = self.superseded;
+    }
+
+    fn set_turn_mode(&mut self, enabled: bool, ctx: &mut SystemContext) {
+        self.turn_mode = enabled;
+        if enabled {
+            return;
+        }
+
+        // Back to real time: held orders go to the pool in the order they were given
+        for work in std::mem::take(&mut self.move_orders) {
+            self.backlog.push_back(work);
+        }
+        self.pump(ctx.deterministic);
+    }
+
+    fn turn_phase(&mut self, phase: TurnPhase, summary: &mut TurnSummary, ctx: &mut SystemContext) {
+        if phase != TurnPhase::Movement {
+            return;
+        }
+
+        // Resolve every held order inline, oldest first, so the paths are out before combat
+        for work in std::mem::take(&mut self.move_orders) {
+            if self.is_superseded(&work) {
+                self.superseded += 1;
+                continue;
+            }
+            let _ = self.worker.dispatch(work, true, handle_path_request);
+            summary.paths_resolved += 1;
+        }
+        self.collect(ctx);
+    }
+}
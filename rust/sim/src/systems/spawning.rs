@@ -0,0 +1,170 @@
+// Spawn system - finds free positions on the spawn worker and creates entities
+
+use std::collections::HashMap;
+
+use crate::entity::EntityData;
+use crate::metrics::ActorMetrics;
+use crate::savegame::GameSnapshot;
+use crate::types::{GameEvent, GameRequest};
+use crate::workers::{handle_spawn_request, spawn_spawn_worker, PendingSpawn, SpawnWorkRequest, SpawnWorkResult};
+use super::{GameSystem, SystemContext, World, WorkerLink};
+
+/// Spawn jobs that may wait for the spawn worker before SpawnEntity is rejected
+const SPAWN_QUEUE_CAPACITY: usize = 256;
+
+pub struct SpawnSystem {
+    pending_spawns: HashMap<Vec<u8>, PendingSpawn>,  // ULID -> in-flight spawn request
+    worker: WorkerLink<SpawnWorkRequest, SpawnWorkResult>,
+}
+
+impl Default for SpawnSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpawnSystem {
+    pub fn new() -> Self {
+        Self {
+            pending_spawns: HashMap::new(),
+            worker: WorkerLink::new(SPAWN_QUEUE_CAPACITY),
+        }
+    }
+
+    /// Reserve the preferred position and hand the spawn to the worker
+    fn request_spawn(&mut self, ulid: Vec<u8>, spawn: PendingSpawn, ctx: &SystemContext) {
+        // Include pending spawns in the occupied snapshot
+        let mut occupied_positions = ctx.world.get_occupied_positions();
+        occupied_positions.extend(self.pending_spawns.values().map(|spawn| spawn.preferred_location));
+
+        let work = SpawnWorkRequest {
+            ulid: ulid.clone(),
+            entity_type: spawn.entity_type.clone(),
+            terrain_type: spawn.terrain_type,
+            preferred_location: spawn.preferred_location,
+            search_radius: spawn.search_radius,
+            occupied_positions,
+        };
+
+        // Send to worker, reserving the position only once it is queued
+        if self.worker.dispatch(work, ctx.deterministic, handle_spawn_request).is_err() {
+            sim_warn!("Actor: Spawn queue full, rejecting {} spawn", spawn.entity_type);
+            let _ = ctx.events.send(GameEvent::RequestRejected {
+                kind: "SpawnEntity".to_string(),
+                ulid,
+                reason: "spawn queue full".to_string(),
+            });
+            let _ = ctx.events.send(GameEvent::SpawnFailed {
+                entity_type: spawn.entity_type,
+                error: "spawn queue full".to_string(),
+            });
+            return;
+        }
+        self.pending_spawns.insert(ulid, spawn);
+    }
+}
+
+impl GameSystem for SpawnSystem {
+    fn name(&self) -> &'static str {
+        "spawning"
+    }
+
+    fn init(&mut self, ctx: &mut SystemContext) {
+        if !ctx.deterministic {
+            self.worker.start("spawn-worker", |rx, tx| spawn_spawn_worker(rx, tx).map(|handle| vec![handle]), ctx);
+        }
+    }
+
+    fn handle_request(&mut self, request: GameRequest, ctx: &mut SystemContext) -> Option<GameRequest> {
+        match request {
+            GameRequest::SpawnEntity { entity_type, terrain_type, preferred_location, search_radius } => {
+                let ulid = ctx.next_ulid();
+                self.request_spawn(ulid, PendingSpawn {
+                    entity_type,
+                    terrain_type,
+                    preferred_location,
+                    search_radius,
+                }, ctx);
+                None
+            }
+            other => Some(other),
+        }
+    }
+
+    fn collect(&mut self, ctx: &mut SystemContext) {
+        for result in self.worker.results() {
+            match result {
+                SpawnWorkResult::Success { ulid, position, entity_type, terrain_type } => {
+                    // Clean up pending (results for unknown ULIDs predate a LoadGame)
+                    if self.pending_spawns.remove(&ulid).is_none() {
+                        continue;
+                    }
+
+                    // Update state
+                    // Convert terrain_cache::TerrainType to entity::TerrainType
+                    use crate::entity::TerrainType as EntityTerrainType;
+                    let entity_terrain = match terrain_type {
+                        crate::terrain_cache::TerrainType::Water => EntityTerrainType::Water,
+                        crate::terrain_cache::TerrainType::Land => EntityTerrainType::Land,
+                        crate::terrain_cache::TerrainType::Obstacle => EntityTerrainType::Land, // Default to Land
+                    };
+                    let entity_data = EntityData::new(ulid.clone(), position, entity_terrain, entity_type.clone());
+                    ctx.world.entities.insert(ulid.clone(), entity_data);
+
+                    // Emit event
+                    let _ = ctx.events.send(GameEvent::EntitySpawned {
+                        ulid,
+                        position,
+                        terrain_type: terrain_type as i32,
+                        entity_type,
+                    });
+                }
+                SpawnWorkResult::Failed { ulid, entity_type, error } => {
+                    if self.pending_spawns.remove(&ulid).is_none() {
+                        continue;
+                    }
+
+                    let _ = ctx.events.send(GameEvent::SpawnFailed {
+                        entity_type,
+                        error,
+                    });
+                }
+            }
+        }
+    }
+
+    fn snapshot(&self, _world: &World, out: &mut GameSnapshot) {
+        out.pending_spawns = self.pending_spawns.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        out.pending_spawns.sort_by(|a, b| a.0.cmp(&b.0));
+    }
+
+    fn restore(&mut self, snapshot: &GameSnapshot, ctx: &mut SystemContext) {
+        self.worker.discard_results();
+
+        // In-flight spawns were never resolved; dispatch them again under the same ULIDs
+        self.pending_spawns.clear();
+        for (ulid, spawn) in &snapshot.pending_spawns {
+            self.request_spawn(ulid.clone(), spawn.clone(), ctx);
+        }
+    }
+
+    fn supervise(&mut self, ctx: &mut SystemContext) {
+        if !self.worker.supervise(ctx) {
+            return;
+        }
+
+        // Re-dispatch everything still pending; duplicate results are dropped in collect()
+        let pending: Vec<(Vec<u8>, PendingSpawn)> = self.pending_spawns.drain().collect();
+        for (ulid, spawn) in pending {
+            self.request_spawn(ulid, spawn, ctx);
+        }
+    }
+
+    fn shutdown(&mut self) {
+        self.worker.shutdown();
+    }
+
+    fn report_metrics(&self, metrics: &mut ActorMetrics) {
+        self.worker.report_queue_depths(self.name(), metrics);
+    }
+}
@@ -0,0 +1,197 @@
+// Threat tables and target selection
+
+use std::collections::HashMap;
+
+use crate::types::{CombatEntitySnapshot, TargetingPolicy};
+use crate::range_calculator::hex_distance;
+
+/// Threat gained per point of damage taken
+pub const THREAT_PER_DAMAGE: f32 = 1.0;
+/// Fraction of threat that fades per second
+pub const THREAT_DECAY_PER_SEC: f32 = 0.05;
+/// Threat below this is forgotten
+pub const MIN_THREAT: f32 = 0.5;
+/// Threat per hex an enemy stands inside the edge of aggro range
+pub const PROXIMITY_THREAT: f32 = 2.0;
+/// A HighestThreat unit only switches to an enemy scoring this much more than its current target
+pub const RETARGET_THREAT_RATIO: f32 = 1.1;
+
+// CombatType flags that make a unit "ranged" for PreferRanged
+const RANGED_COMBAT_TYPES: u8 = (1 << 1) | (1 << 2) | (1 << 3);
+
+/// holder ulid -> (enemy ulid -> threat): how much each entity wants to hit back at each enemy
+/// Damage taken and taunts add threat, and it fades over time
+#[derive(Debug, Clone, Default)]
+pub struct ThreatTable {
+    threat: HashMap<Vec<u8>, HashMap<Vec<u8>, f32>>,
+}
+
+impl ThreatTable {
+    pub fn add(&mut self, holder: &[u8], enemy: &[u8], amount: f32) {
+        if amount <= 0.0 || holder == enemy {
+            return;
+        }
+        *self.threat.entry(holder.to_vec()).or_default().entry(enemy.to_vec()).or_default() += amount;
+    }
+
+    /// Fade all threat by `delta` seconds, forgetting faded entries and entities that are gone
+    pub fn decay(&mut self, delta: f32, exists: impl Fn(&[u8]) -> bool) {
+        let keep = (1.0 - THREAT_DECAY_PER_SEC * delta).max(0.0);
+        self.threat.retain(|holder, enemies| {
+            enemies.retain(|enemy, threat| {
+                *threat *= keep;
+                *threat >= MIN_THREAT && exists(enemy)
+            });
+            !enemies.is_empty() && exists(holder)
+        });
+    }
+
+    /// Threat `holder` holds toward each enemy, sorted by enemy ulid
+    pub fn of(&self, holder: &[u8]) -> Vec<(Vec<u8>, f32)> {
+        let mut threat: Vec<(Vec<u8>, f32)> = self.threat.get(holder)
+            .map(|enemies| enemies.iter().map(|(enemy, threat)| (enemy.clone(), *threat)).collect())
+            .unwrap_or_default();
+        threat.sort_by(|a, b| a.0.cmp(&b.0));
+        threat
+    }
+
+    /// (holder, enemy, threat) for every entry, sorted (SaveGame)
+    pub fn entries(&self) -> Vec<(Vec<u8>, Vec<u8>, f32)> {
+        let mut entries: Vec<(Vec<u8>, Vec<u8>, f32)> = self.threat.iter()
+            .flat_map(|(holder, enemies)| {
+                enemies.iter().map(move |(enemy, threat)| (holder.clone(), enemy.clone(), *threat))
+            })
+            .collect();
+        entries.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        entries
+    }
+
+    pub fn restore(&mut self, entries: &[(Vec<u8>, Vec<u8>, f32)]) {
+        self.threat.clear();
+        for (holder, enemy, threat) in entries {
+            self.add(holder, enemy, *threat);
+        }
+    }
+}
+
+/// Team detection by player_ulid: empty = AI team, so AI units are allies of each other and
+/// units of the same player are allies; anything else is an enemy
+pub fn are_enemies(a_player_ulid: &[u8], b_player_ulid: &[u8]) -> bool {
+    a_player_ulid != b_player_ulid
+}
+
+/// How much `attacker` wants to hit `defender`: its threat toward it plus proximity
+/// (a close enemy beats a far one holding the same threat)
+pub fn threat_score(attacker: &CombatEntitySnapshot, defender: &CombatEntitySnapshot) -> f32 {
+    let threat = attacker.threat.iter()
+        .find(|(enemy, _)| *enemy == defender.ulid)
+        .map_or(0.0, |(_, threat)| *threat);
+    let distance = hex_distance(attacker.position, defender.position);
+    threat + PROXIMITY_THREAT * (attacker.aggro_range - distance).max(0) as f32
+}
+
+/// Pick the attacker's target among living enemies in aggro range that it can see, by its
+/// TargetingPolicy (`in_sight` says whether the attacker has a line of fire to an enemy)
+/// Ties go to the nearest (HighestThreat: the first), then to the first in snapshot order
+pub fn select_target<'a>(
+    attacker: &CombatEntitySnapshot,
+    entities: &'a [CombatEntitySnapshot],
+    in_sight: impl Fn(&CombatEntitySnapshot) -> bool,
+) -> Option<&'a CombatEntitySnapshot> {
+    let candidates = entities.iter()
+        .filter(|defender| defender.ulid != attacker.ulid && defender.hp > 0)
+        .filter(|defender| are_enemies(&attacker.player_ulid, &defender.player_ulid))
+        .map(|defender| (defender, hex_distance(attacker.position, defender.position)))
+        .filter(|(_, distance)| *distance <= attacker.aggro_range)
+        .filter(|(defender, _)| in_sight(defender));
+
+    match attacker.profile.targeting {
+        TargetingPolicy::Nearest => candidates.min_by_key(|(_, distance)| *distance).map(|(defender, _)| defender),
+        TargetingPolicy::LowestHp => candidates
+            .min_by_key(|(defender, distance)| (defender.hp, *distance))
+            .map(|(defender, _)| defender),
+        TargetingPolicy::PreferRanged => candidates
+            .min_by_key(|(defender, distance)| (defender.combat_type & RANGED_COMBAT_TYPES == 0, *distance))
+            .map(|(defender, _)| defender),
+        TargetingPolicy::PreferStructures => candidates
+            .min_by_key(|(defender, distance)| (!defender.profile.is_structure, *distance))
+            .map(|(defender, _)| defender),
+        TargetingPolicy::HighestThreat => candidates
+            .fold(None, |best: Option<(&CombatEntitySnapshot, f32)>, (defender, _)| {
+                let score = threat_score(attacker, defender);
+                match best {
+                    Some((_, best_score)) if best_score >= score => best,
+                    _ => Some((defender, score)),
+                }
+            })
+            .map(|(defender, _)| defender),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain_cache::TerrainType;
+    use crate::types::CombatProfile;
+
+    fn unit(byte: u8, player: u8, position: (i32, i32), hp: i32, combat_type: u8) -> CombatEntitySnapshot {
+        CombatEntitySnapshot {
+            ulid: vec![byte; 16],
+            player_ulid: vec![player; 16],
+            position,
+            terrain_type: TerrainType::Land,
+            hp,
+            max_hp: 50,
+            mana: 0,
+            max_mana: 0,
+            attack: 5,
+            defense: 0,
+            morale: 100.0,
+            luck: 0.0,
+            evasion: 0.0,
+            range: 1,
+            combat_type,
+            projectile_type: 0,
+            combat_range: 1,
+            aggro_range: 8,
+            profile: CombatProfile::default(),
+            threat: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_policies_pick_different_targets() {
+        let mut entities = vec![
+            unit(1, 1, (0, 0), 50, 1),
+            unit(2, 2, (1, 0), 40, 1),   // Nearest
+            unit(3, 2, (3, 0), 10, 1),   // Weakest
+            unit(4, 2, (5, 0), 50, 4),   // Archer
+            unit(5, 2, (6, 0), 50, 1),   // Tower
+            unit(6, 1, (1, -1), 5, 1),   // Ally
+            unit(7, 2, (20, 0), 1, 4),   // Out of aggro range
+        ];
+        entities[4].profile.is_structure = true;
+
+        let target = |entities: &[CombatEntitySnapshot], targeting| {
+            let mut attacker = entities[0].clone();
+            attacker.profile.targeting = targeting;
+            select_target(&attacker, entities, |_| true).map(|t| t.ulid[0])
+        };
+        assert_eq!(target(&entities, TargetingPolicy::Nearest), Some(2));
+        assert_eq!(target(&entities, TargetingPolicy::LowestHp), Some(3));
+        assert_eq!(target(&entities, TargetingPolicy::PreferRanged), Some(4));
+        assert_eq!(target(&entities, TargetingPolicy::PreferStructures), Some(5));
+        assert_eq!(target(&entities, TargetingPolicy::HighestThreat), Some(2));
+
+        // A taunt outweighs proximity; threat fades and is forgotten
+        let mut table = ThreatTable::default();
+        table.add(&[1; 16], &[5; 16], 20.0);
+        entities[0].threat = table.of(&[1; 16]);
+        assert_eq!(target(&entities, TargetingPolicy::HighestThreat), Some(5));
+
+        table.decay(10.0, |_| true);
+        assert_eq!(table.of(&[1; 16]), vec![(vec![5; 16], 10.0)]);
+        table.decay(1.0, |ulid| ulid != [5; 16]);
+        assert!(table.entries().is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+// City taxation - gold income and reputation drift of one owned city
+
+/// Gold per inhabitant per turn at a 100% tax rate, average wealth (50) and neutral reputation (0)
+pub const TAX_PER_CAPITA: f64 = 0.05;
+/// Tax rate that leaves reputation where it is (also the default rate of a new city)
+pub const NEUTRAL_TAX_RATE: f64 = 0.2;
+/// Reputation gained (below neutral) or lost (above) per turn per 100% of tax rate off neutral
+pub const REPUTATION_PER_TAX: f64 = 10.0;
+
+/// One owned city's tax base and terms
+#[derive(Debug, Clone, PartialEq)]
+pub struct City {
+    pub city_id: i64,
+    pub player_ulid: Vec<u8>,  // Owner, whose pool the taxes feed
+    pub population: i64,
+    pub wealth: f64,           // 0-100
+    pub reputation: f64,       // -100 to 100
+    pub tax_rate: f64,         // 0.0-1.0
+}
+
+/// `value`, or `default` when it is NaN or infinite
+fn finite(value: f64, default: f64) -> f64 {
+    if value.is_finite() { value } else { default }
+}
+
+impl City {
+    pub fn new(city_id: i64, player_ulid: Vec<u8>, population: i64, wealth: f64, reputation: f64, tax_rate: f64) -> Self {
+        let mut city = Self {
+            city_id,
+            player_ulid: Vec::new(),
+            population: 0,
+            wealth: 50.0,
+            reputation: finite(reputation, 0.0).clamp(-100.0, 100.0),
+            tax_rate: NEUTRAL_TAX_RATE,
+        };
+        city.set_terms(player_ulid, population, wealth, tax_rate);
+        city
+    }
+
+    /// Update owner and tax base (reputation is kept: taxes and shortages move it from here on)
+    pub fn set_terms(&mut self, player_ulid: Vec<u8>, population: i64, wealth: f64, tax_rate: f64) {
+        self.player_ulid = player_ulid;
+        self.population = population.max(0);
+        self.wealth = finite(wealth, 50.0).clamp(0.0, 100.0);
+        self.tax_rate = finite(tax_rate, NEUTRAL_TAX_RATE).clamp(0.0, 1.0);
+    }
+
+    /// Gold collected per turn: population x rate, half as much when poor and 1.5x when rich,
+    /// half as much when hated and 1.5x when loved
+    pub fn income_per_turn(&self) -> f64 {
+        self.population as f64 * TAX_PER_CAPITA * self.tax_rate
+            * (0.5 + self.wealth / 100.0)
+            * (1.0 + self.reputation / 200.0)
+    }
+
+    /// Reputation change per turn at the current rate (positive below NEUTRAL_TAX_RATE)
+    /// A high rate is income now, a low rate is income later
+    pub fn reputation_per_turn(&self) -> f64 {
+        (NEUTRAL_TAX_RATE - self.tax_rate) * REPUTATION_PER_TAX
+    }
+
+    /// One turn of reputation drift; returns the change actually applied
+    pub fn drift_reputation(&mut self) -> f64 {
+        self.shift_reputation(self.reputation_per_turn())
+    }
+
+    /// Move reputation by `delta`; returns the change actually applied (reputation stays in -100..100)
+    pub fn shift_reputation(&mut self, delta: f64) -> f64 {
+        let before = self.reputation;
+        self.reputation = (before + delta).clamp(-100.0, 100.0);
+        self.reputation - before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tax_rate_trades_income_for_reputation() {
+        let mut city = City::new(1, vec![1; 16], 1000, 50.0, 0.0, NEUTRAL_TAX_RATE);
+        assert!((city.income_per_turn() - 10.0).abs() < 1e-9);
+        assert_eq!(city.drift_reputation(), 0.0);
+
+        // Doubling the rate doubles income now, but reputation (and so future income) falls
+        let mut greedy = City::new(2, vec![1; 16], 1000, 50.0, 0.0, 0.4);
+        assert!((greedy.income_per_turn() - 20.0).abs() < 1e-9);
+        assert!(greedy.drift_reputation() < 0.0);
+        assert!(greedy.income_per_turn() < 20.0);
+
+        // No taxes earns reputation; rich, loved cities pay more for the same rate
+        city.tax_rate = 0.0;
+        assert_eq!(city.income_per_turn(), 0.0);
+        assert!(city.drift_reputation() > 0.0);
+        let loved = City::new(3, vec![1; 16], 1000, 100.0, 100.0, NEUTRAL_TAX_RATE);
+        assert!((loved.income_per_turn() - 22.5).abs() < 1e-9);
+
+        // Out-of-range terms are clamped
+        let odd = City::new(4, vec![1; 16], -5, f64::NAN, 500.0, 2.0);
+        assert_eq!((odd.population, odd.wealth, odd.reputation, odd.tax_rate), (0, 50.0, 100.0, 1.0));
+    }
+}
@@ -0,0 +1,1344 @@
+// Event and request type definitions for the unified event system
+
+use serde::{Serialize, Deserialize};
+
+use crate::metrics::ActorMetrics;
+use crate::terrain_cache::TerrainType;
+
+/// Caller-chosen id carried by query requests and echoed in their reply event
+/// Lets concurrent callers match answers to their own questions
+pub type RequestId = u64;
+
+/// All game events emitted from Actor to Godot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GameEvent {
+    // === Spawn Events ===
+    EntitySpawned {
+        ulid: Vec<u8>,
+        position: (i32, i32),
+        terrain_type: i32,
+        entity_type: String,
+    },
+    SpawnFailed {
+        entity_type: String,
+        error: String,
+    },
+
+    // === Pathfinding Events ===
+    PathFound {
+        ulid: Vec<u8>,
+        path: Vec<(i32, i32)>,
+        cost: f32,
+    },
+    PathFailed {
+        ulid: Vec<u8>,
+    },
+    RandomDestFound {
+        ulid: Vec<u8>,
+        destination: (i32, i32),
+        found: bool,
+    },
+
+    // === Combat Events ===
+    CombatStarted {
+        attacker_ulid: Vec<u8>,
+        defender_ulid: Vec<u8>,
+    },
+    /// A melee hit or projectile impact was resolved (damage is 0 when dodged)
+    DamageDealt {
+        attacker_ulid: Vec<u8>,
+        defender_ulid: Vec<u8>,
+        damage: i32,
+        outcome: HitOutcome,
+    },
+    EntityDied {
+        ulid: Vec<u8>,
+    },
+    CombatEnded {
+        attacker_ulid: Vec<u8>,
+        defender_ulid: Vec<u8>,
+    },
+    /// Spawn a projectile for ranged/bow/magic combat
+    /// damage is the attack power it carries; the target's Defense/Evasion apply on ProjectileHit
+    /// splash_radius is how far it bursts on impact, in hexes (0 = hits only its target)
+    /// cover is how many tiles shielded the target when the shot was fired; ProjectileHit hands it back
+    SpawnProjectile {
+        attacker_ulid: Vec<u8>,
+        attacker_position: (i32, i32),
+        target_ulid: Vec<u8>,
+        target_position: (i32, i32),
+        projectile_type: u8,
+        damage: i32,
+        splash_radius: i32,
+        cover: u32,
+    },
+
+    // === Economy Events ===
+    ResourceChanged {
+        player_ulid: Vec<u8>,  // Pool owner (empty = AI/neutral pool)
+        resource_type: i64,
+        current: f64,
+        cap: f64,
+        rate: f64,
+    },
+    /// A transaction was applied and appended to the ledger
+    /// (request_id is 0 for transactions the Actor makes itself: loot, combo rewards, upkeep)
+    TransactionApplied {
+        request_id: RequestId,
+        entry: LedgerEntry,
+    },
+    /// A transaction or rollback changed nothing (shortfall lists what was missing, if anything)
+    TransactionRejected {
+        request_id: RequestId,
+        player_ulid: Vec<u8>,
+        reason: TransactionReason,
+        error: String,
+        shortfall: Vec<(i64, f64)>,
+    },
+    /// A pool ran short of a resource and it had consequences (see ShortageConfig)
+    /// Starvation and reputation loss repeat every upkeep while the shortage lasts
+    ResourceShortage {
+        player_ulid: Vec<u8>,
+        resource_type: i64,
+        deficit: f64,  // Amount that was due but not there (0 for an empty pool with nothing due)
+        effect: ShortageEffect,
+    },
+    /// A shortage reported by ResourceShortage is over
+    ShortageEnded {
+        player_ulid: Vec<u8>,
+        resource_type: i64,
+    },
+    /// A recipe producer started running, stalled, or was switched off/paused
+    RecipeStatusChanged {
+        ulid: Vec<u8>,
+        player_ulid: Vec<u8>,
+        status: RecipeStatus,
+    },
+    /// A Trade settled (its ledger entry comes with TransactionApplied)
+    TradeCompleted {
+        request_id: RequestId,
+        player_ulid: Vec<u8>,
+        market_id: i64,
+        sold: (i64, f64),    // (resource_type, amount) paid into the market
+        bought: (i64, f64),  // (resource_type, amount) received (credits above the cap are lost)
+    },
+    /// Every upkeep, per registered city: the gold its taxes bring in per turn and how its
+    /// reputation moved with the tax rate (for the host to apply to the Structure)
+    CityTaxed {
+        city_id: i64,
+        player_ulid: Vec<u8>,
+        income_per_turn: f64,
+        reputation_delta: f64,
+    },
+
+    // === Stats Events ===
+    StatChanged {
+        ulid: Vec<u8>,
+        stat_type: i64,
+        new_value: f32,
+    },
+    EntityDamaged {
+        ulid: Vec<u8>,
+        damage: f32,
+        new_hp: f32,
+    },
+    EntityHealed {
+        ulid: Vec<u8>,
+        heal_amount: f32,
+        new_hp: f32,
+    },
+
+    // === Status Effect Events ===
+    /// An effect took hold (or stacked/refreshed); move_speed_multiplier is the entity's movement
+    /// speed with all its effects (0.0 = stunned), for the host to apply
+    StatusApplied {
+        ulid: Vec<u8>,
+        source_ulid: Vec<u8>,
+        kind: StatusKind,
+        stacks: u32,
+        duration: f32,  // Seconds left
+        move_speed_multiplier: f32,
+    },
+    /// An effect ran out (move_speed_multiplier covers the effects still on the entity)
+    StatusExpired {
+        ulid: Vec<u8>,
+        kind: StatusKind,
+        move_speed_multiplier: f32,
+    },
+
+    // === Card Events ===
+    ComboDetected {
+        hand_rank: i32,          // PokerHand rank (0-9)
+        hand_name: String,       // "One Pair", "Full House", etc.
+        card_positions: Vec<(i32, i32)>,  // Positions of cards in the combo
+        resource_bonuses: Vec<(i32, f32)>, // (resource_type, amount) pairs
+    },
+
+    // === Replay Events ===
+    /// Replay recording written to disk
+    ReplaySaved {
+        path: String,
+        ticks: u64,
+    },
+    /// Replay recording could not be started or written
+    ReplayFailed {
+        error: String,
+    },
+
+    // === Query Replies (exactly one per query request, same request_id) ===
+    /// Reply to GetStat (None if the entity or stat is unknown)
+    StatReply {
+        request_id: RequestId,
+        ulid: Vec<u8>,
+        stat_type: i64,
+        value: Option<f32>,
+    },
+    /// Reply to QueryPathCost (empty path when no route exists)
+    PathCostReply {
+        request_id: RequestId,
+        found: bool,
+        path: Vec<(i32, i32)>,
+        cost: f32,
+    },
+    /// Reply to QueryCanAfford (shortfall lists what is missing per resource)
+    CanAffordReply {
+        request_id: RequestId,
+        affordable: bool,
+        shortfall: Vec<(i64, f64)>,
+    },
+    /// Reply to QueryCombo (hand_rank 0 = no combo)
+    ComboReply {
+        request_id: RequestId,
+        hand_rank: i32,
+        hand_name: String,
+        card_positions: Vec<(i32, i32)>,
+        resource_bonuses: Vec<(i32, f32)>,
+    },
+    /// Reply to QueryLedger (oldest first)
+    LedgerReply {
+        request_id: RequestId,
+        entries: Vec<LedgerEntry>,
+    },
+    /// Reply to QueryMarket (one entry per registered resource; empty for unknown markets)
+    MarketReply {
+        request_id: RequestId,
+        market_id: i64,
+        trade_modifier: f64,
+        prices: Vec<MarketPrice>,
+    },
+    /// Reply to QueryRecipes (registration order)
+    RecipesReply {
+        request_id: RequestId,
+        recipes: Vec<RecipeReport>,
+    },
+    /// Reply to QueryForecast (one entry per resource of the pool, in resource type order)
+    ForecastReply {
+        request_id: RequestId,
+        player_ulid: Vec<u8>,
+        horizon_secs: f64,  // Simulated seconds covered
+        resources: Vec<ResourceForecast>,
+    },
+
+    // === Save/Load Events ===
+    /// Actor state written to disk
+    GameSaved {
+        path: String,
+    },
+    /// Save could not be encoded or written (previous file left untouched)
+    SaveFailed {
+        path: String,
+        error: String,
+    },
+    /// Actor state replaced from a save file
+    GameLoaded {
+        path: String,
+        entity_count: usize,
+        card_count: usize,
+    },
+    /// Save could not be read, migrated or validated (current state left untouched)
+    LoadFailed {
+        path: String,
+        error: String,
+    },
+
+    // === Worker Supervision Events ===
+    /// A worker thread died (panic, or failed to start)
+    /// restarted = false once the supervisor gives up and the system stays degraded
+    WorkerFailed {
+        worker: String,
+        error: String,
+        restarted: bool,
+    },
+
+    // === Backpressure Events ===
+    /// A request was dropped because a queue was full (see QueuePolicy)
+    /// ulid is empty for requests not about an entity
+    RequestRejected {
+        kind: String,
+        ulid: Vec<u8>,
+        reason: String,
+    },
+
+    // === Turn Events ===
+    /// Turn mode switched on/off (SetTurnMode, or LoadGame of a save in the other mode)
+    TurnModeChanged {
+        enabled: bool,
+        turn: u64,  // Turns completed so far
+    },
+    /// Every phase of a StepTurn has run
+    TurnCompleted {
+        summary: Box<TurnSummary>,  // Boxed to keep GameEvent small
+    },
+
+    // === Simulation Clock Events ===
+    /// Clock state after any clock request
+    SimulationClockChanged {
+        paused: bool,
+        speed: f64,
+    },
+
+    // === Telemetry Events ===
+    /// Periodic copy of the Actor metrics (see SetMetricsInterval)
+    MetricsReport {
+        metrics: Box<ActorMetrics>,  // Boxed to keep GameEvent small
+    },
+
+    // === Network Events ===
+    /// Successfully connected to multiplayer server
+    NetworkConnected {
+        session_id: String,
+    },
+    /// Connection to server failed
+    NetworkConnectionFailed {
+        error: String,
+    },
+    /// Disconnected from server
+    NetworkDisconnected,
+    /// Received a message from the server
+    NetworkMessageReceived {
+        data: Vec<u8>,
+    },
+    /// Network error occurred
+    NetworkError {
+        message: String,
+    },
+
+    // === IRC Chat Events ===
+    /// Connected to IRC server
+    IrcConnected {
+        nickname: String,
+        server: String,
+    },
+    /// Disconnected from IRC
+    IrcDisconnected {
+        reason: Option<String>,
+    },
+    /// Joined IRC channel
+    IrcJoinedChannel {
+        channel: String,
+        nickname: String,
+    },
+    /// Left IRC channel
+    IrcLeftChannel {
+        channel: String,
+        nickname: String,
+        message: Option<String>,
+    },
+    /// Received channel message
+    IrcChannelMessage {
+        channel: String,
+        sender: String,
+        message: String,
+    },
+    /// Received private message
+    IrcPrivateMessage {
+        sender: String,
+        message: String,
+    },
+    /// User joined channel
+    IrcUserJoined {
+        channel: String,
+        nickname: String,
+    },
+    /// User left channel
+    IrcUserLeft {
+        channel: String,
+        nickname: String,
+        message: Option<String>,
+    },
+    /// User quit IRC
+    IrcUserQuit {
+        nickname: String,
+        message: Option<String>,
+    },
+    /// IRC error
+    IrcError {
+        message: String,
+    },
+}
+
+/// All requests from Godot to Actor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GameRequest {
+    // === Spawn Requests ===
+    SpawnEntity {
+        entity_type: String,
+        terrain_type: TerrainType,
+        preferred_location: (i32, i32),
+        search_radius: i32,
+    },
+
+    // === Pathfinding Requests ===
+    RequestPath {
+        ulid: Vec<u8>,
+        terrain_type: TerrainType,
+        start: (i32, i32),
+        goal: (i32, i32),
+        avoid_entities: bool,
+    },
+    RequestRandomDest {
+        ulid: Vec<u8>,
+        terrain_type: TerrainType,
+        start: (i32, i32),
+        min_distance: i32,
+        max_distance: i32,
+    },
+
+    // === Entity Update Requests ===
+    UpdateEntityPosition {
+        ulid: Vec<u8>,
+        position: (i32, i32),
+    },
+    UpdateEntityState {
+        ulid: Vec<u8>,
+        state: i64,
+    },
+    RemoveEntity {
+        ulid: Vec<u8>,
+    },
+
+    // === Resource Requests ===
+    // player_ulid picks the pool (empty = AI/neutral pool); player pools are created on first use
+    SpendResources {
+        player_ulid: Vec<u8>,
+        cost: Vec<(i64, f64)>, // (resource_type, amount)
+    },
+    AddResources {
+        player_ulid: Vec<u8>,
+        resource_type: i64,
+        amount: f64,
+    },
+    /// Process turn-based resource consumption (called by GameTimer on turn end)
+    /// Consumes 1 food per player-controlled entity from its owner's pool
+    ProcessTurnConsumption,
+    RegisterProducer {
+        ulid: Vec<u8>,
+        player_ulid: Vec<u8>,  // Pool the production goes to
+        resource_type: i64,
+        rate_per_sec: f64,
+        active: bool,
+    },
+    RegisterConsumer {
+        ulid: Vec<u8>,
+        player_ulid: Vec<u8>,  // Pool the consumption comes from
+        resource_type: i64,
+        rate_per_sec: f64,
+        active: bool,
+    },
+    /// Producer that turns inputs into outputs once per cycle (RemoveProducer/SetProducerActive apply too)
+    /// Inputs are taken from the pool when a cycle starts; without them the producer stalls
+    RegisterRecipe {
+        ulid: Vec<u8>,
+        player_ulid: Vec<u8>,  // Pool inputs come from and outputs go to
+        recipe: Recipe,
+        active: bool,
+    },
+    RemoveProducer {
+        ulid: Vec<u8>,
+    },
+    RemoveConsumer {
+        ulid: Vec<u8>,
+    },
+    SetProducerActive {
+        ulid: Vec<u8>,
+        active: bool,
+    },
+    SetConsumerActive {
+        ulid: Vec<u8>,
+        active: bool,
+    },
+    /// Storage structure (granary, vault, ...) protecting a player's stock from decay
+    /// protection: (resource_type, share of the decay loss prevented, 0.0-1.0); several storages stack
+    RegisterStorage {
+        ulid: Vec<u8>,
+        player_ulid: Vec<u8>,
+        protection: Vec<(i64, f64)>,
+    },
+    RemoveStorage {
+        ulid: Vec<u8>,
+    },
+    /// Trading post (TRADING_POST/MARKET structure) players can trade at; re-registering updates its
+    /// terms and keeps its stock. trade_modifier is Structure::get_trade_modifier, wealth 0-100
+    RegisterMarket {
+        market_id: i64,
+        trade_modifier: f64,
+        wealth: f64,
+    },
+    RemoveMarket {
+        market_id: i64,
+    },
+    /// Owned, inhabited city paying taxes in gold to its owner; re-registering updates it but keeps
+    /// its reputation (taxes and shortages move that, see CityTaxed)
+    /// wealth 0-100, reputation -100 to 100 (starting value), tax_rate 0.0-1.0 (see taxation)
+    RegisterCity {
+        city_id: i64,
+        player_ulid: Vec<u8>,
+        population: i64,
+        wealth: f64,
+        reputation: f64,
+        tax_rate: f64,
+    },
+    RemoveCity {
+        city_id: i64,
+    },
+    /// Change a resource's cap (current is clamped to it)
+    SetResourceCap {
+        player_ulid: Vec<u8>,
+        resource_type: i64,
+        cap: f64,
+    },
+    /// Set a resource to an amount, clamped to [0, cap] (ledgered as an adjustment)
+    SetResourceAmount {
+        player_ulid: Vec<u8>,
+        resource_type: i64,
+        amount: f64,
+    },
+    /// Put a pool back to its starting amounts and caps (ledgered as an adjustment)
+    /// Producers and consumers are kept
+    ResetResources {
+        player_ulid: Vec<u8>,
+    },
+    /// Create (and announce) a player's pool now instead of on first use; no-op if it exists
+    EnsurePlayerPool {
+        player_ulid: Vec<u8>,
+    },
+    /// Change what food, labor and faith shortages do (kept in saves)
+    SetShortageConfig {
+        config: ShortageConfig,
+    },
+    /// Credit (positive) and debit (negative) several resources of one pool at once
+    /// All-or-nothing: any debit the pool can't cover rejects the whole transaction
+    /// (answered by TransactionApplied/TransactionRejected)
+    ApplyTransaction {
+        request_id: RequestId,
+        player_ulid: Vec<u8>,
+        reason: TransactionReason,
+        changes: Vec<(i64, f64)>,  // (resource_type, signed amount)
+    },
+    /// Sell an amount of one resource at a market for as much of another as it's worth there
+    /// (answered by TransactionApplied + TradeCompleted, or TransactionRejected)
+    Trade {
+        request_id: RequestId,
+        player_ulid: Vec<u8>,
+        market_id: i64,
+        sell: (i64, f64),  // (resource_type, amount)
+        buy: i64,          // resource_type
+    },
+    /// Undo a ledger transaction by applying its inverse (recorded as a Rollback transaction)
+    /// Rejected if the transaction is unknown, already rolled back, or the pool can't cover the inverse
+    RollbackTransaction {
+        request_id: RequestId,
+        transaction_id: u64,
+    },
+
+    // === Stats Requests ===
+    RegisterEntityStats {
+        ulid: Vec<u8>,
+        player_ulid: Vec<u8>,  // Team affiliation (empty = AI team)
+        entity_type: String,
+        terrain_type: i32,
+        position: (i32, i32),
+        combat_type: u8,       // CombatType bitwise flags
+        projectile_type: u8,   // ProjectileType enum value
+        combat_range: i32,     // Attack range in hexes
+        aggro_range: i32,      // Detection/aggro range in hexes
+    },
+    /// Query a single stat (answered by StatReply)
+    GetStat {
+        request_id: RequestId,
+        ulid: Vec<u8>,
+        stat_type: i64,
+    },
+    SetStat {
+        ulid: Vec<u8>,
+        stat_type: i64,
+        value: f32,
+    },
+    TakeDamage {
+        ulid: Vec<u8>,
+        damage: f32,
+    },
+    Heal {
+        ulid: Vec<u8>,
+        amount: f32,
+    },
+    /// Put a timed effect on an entity (abilities, cards, support units); ignored if it is immune
+    /// magnitude: HP per tick per stack for Poison/Burn/Regen, fraction of speed taken off for Slow
+    ApplyStatus {
+        ulid: Vec<u8>,
+        source_ulid: Vec<u8>,
+        kind: StatusKind,
+        duration: f32,
+        magnitude: f32,
+    },
+    /// Effects an entity shrugs off from now on (replaces its previous immunities)
+    SetStatusImmunities {
+        ulid: Vec<u8>,
+        kinds: Vec<StatusKind>,
+    },
+    /// How units of an entity type pick targets, and whether they count as structures for
+    /// PreferStructures; replaces the type's previous profile (unconfigured types: Nearest, not a structure)
+    SetCombatProfile {
+        entity_type: String,
+        profile: CombatProfile,
+    },
+    /// How a projectile type bursts on impact; replaces the type's default (see splash::default_splash)
+    SetProjectileSplash {
+        projectile_type: u8,
+        splash: SplashProfile,
+    },
+    /// Draw enemies' attention: every enemy within `radius` hexes of the taunter gains `threat` toward it
+    Taunt {
+        ulid: Vec<u8>,
+        radius: i32,
+        threat: f32,
+    },
+
+    // === Combat Requests ===
+    /// Called by GDScript when a projectile hits its target
+    /// This is when damage is actually applied for ranged/bow/magic combat: `damage` and `cover` are
+    /// the power and cover from SpawnProjectile, resolved against the target's stats (see
+    /// damage::resolve_hit, range_calculator::cover_evasion), and the
+    /// projectile's status effect may take hold (see status::on_hit_status). A projectile with a
+    /// splash radius also hits those around `impact_position`, each against its own stats (see splash)
+    ProjectileHit {
+        attacker_ulid: Vec<u8>,
+        defender_ulid: Vec<u8>,
+        damage: i32,
+        projectile_type: u8,
+        impact_position: (i32, i32),  // Hex it landed on (the target's, when it connects)
+        cover: u32,                   // Tiles covering the target when the shot was fired
+    },
+
+    // === Card Requests ===
+    PlaceCard {
+        x: i32,
+        y: i32,
+        ulid: Vec<u8>,
+        player_ulid: Vec<u8>,  // Placing player (turn-mode combo rewards go to their pool)
+        suit: u8,
+        value: u8,
+        card_id: i32,
+        is_custom: bool,
+    },
+    RemoveCardAt {
+        x: i32,
+        y: i32,
+    },
+    RemoveCardByUlid {
+        ulid: Vec<u8>,
+    },
+    /// Request combo detection at a specific position
+    /// Actor will check cards in radius and emit combo event if found
+    DetectCombo {
+        center_x: i32,
+        center_y: i32,
+        radius: i32,
+    },
+
+    // === Query Requests (no side effects, answered by a *Reply event) ===
+    /// Path cost between two hexes without moving anything (answered by PathCostReply)
+    QueryPathCost {
+        request_id: RequestId,
+        terrain_type: TerrainType,
+        start: (i32, i32),
+        goal: (i32, i32),
+    },
+    /// Check a cost against current resources without spending (answered by CanAffordReply)
+    QueryCanAfford {
+        request_id: RequestId,
+        player_ulid: Vec<u8>,
+        cost: Vec<(i64, f64)>, // (resource_type, amount)
+    },
+    /// Ledger history, optionally filtered by pool and reason (answered by LedgerReply)
+    QueryLedger {
+        request_id: RequestId,
+        player_ulid: Option<Vec<u8>>,
+        reason: Option<TransactionReason>,
+        limit: u32,  // Newest entries to return (0 = all kept)
+    },
+    /// A market's stock and prices (answered by MarketReply, changes nothing)
+    QueryMarket {
+        request_id: RequestId,
+        market_id: i64,
+    },
+    /// Recipe producers of a pool with their status and cycle progress (answered by RecipesReply)
+    QueryRecipes {
+        request_id: RequestId,
+        player_ulid: Vec<u8>,
+    },
+    /// Project a pool ahead from its producers, consumers, caps and per-entity upkeep
+    /// (answered by ForecastReply, changes nothing)
+    QueryForecast {
+        request_id: RequestId,
+        player_ulid: Vec<u8>,
+        horizon: ForecastHorizon,
+    },
+    /// Preview the combo at a position, optionally with cards not yet placed
+    /// (answered by ComboReply, grants no resources)
+    QueryCombo {
+        request_id: RequestId,
+        center_x: i32,
+        center_y: i32,
+        radius: i32,
+        extra_cards: Vec<(i32, i32, u8, u8)>, // (x, y, suit, value) hypothetical placements
+    },
+
+    // === Replay Requests ===
+    /// Start recording requests/events for a deterministic replay
+    /// Reseeds the Actor and runs workers inline so results land on fixed ticks
+    /// Replays assume recording starts from a fresh session (no entities yet)
+    StartReplayRecording {
+        seed: u64,
+    },
+    /// Stop recording and write the replay file (emits ReplaySaved/ReplayFailed)
+    StopReplayRecording {
+        path: String,
+    },
+
+    // === Save/Load Requests ===
+    /// Snapshot all Actor-owned state to a versioned save file (emits GameSaved/SaveFailed)
+    SaveGame {
+        path: String,
+    },
+    /// Replace all Actor-owned state from a save file (emits GameLoaded/LoadFailed)
+    /// Older save versions are migrated; the restore is all-or-nothing
+    LoadGame {
+        path: String,
+    },
+
+    // === Turn Requests ===
+    /// Switch between real-time ticks and turn mode (emits TurnModeChanged)
+    /// In turn mode periodic work (economy, combat) waits for StepTurn; requests are still handled every tick
+    SetTurnMode {
+        enabled: bool,
+    },
+    /// Advance one turn: upkeep, production, movement, combat, loot, combo rewards (emits TurnCompleted)
+    /// Rejected unless turn mode is on
+    StepTurn,
+
+    // === Simulation Clock Requests (all emit SimulationClockChanged) ===
+    /// Speed multiplier for simulated time, clamped to 0.5..=8.0
+    SetSimulationSpeed {
+        speed: f64,
+    },
+    /// Freeze simulated time; requests and worker results are still handled while paused
+    SetSimulationPaused {
+        paused: bool,
+    },
+    /// Run `ticks` single ticks of simulated time while paused (ignored while running)
+    StepSimulation {
+        ticks: u32,
+    },
+
+    // === Telemetry Requests ===
+    /// Emit MetricsReport every interval_secs of tick delta (0 = off, the default)
+    /// Never emitted while deterministic (wall-clock timings would break replays)
+    SetMetricsInterval {
+        interval_secs: f64,
+    },
+
+    // === Lifecycle Requests ===
+    /// Stop and join all worker threads, then end the Actor loop (extension deinit)
+    Shutdown,
+
+    // === Network Requests ===
+    /// Connect to a multiplayer server
+    NetworkConnect {
+        url: String,
+    },
+    /// Send a network message to the server
+    NetworkSend {
+        message: Vec<u8>,
+    },
+    /// Disconnect from the multiplayer server
+    NetworkDisconnect,
+
+    // === IRC Chat Requests ===
+    /// Connect to IRC server (Ergo WebIRC)
+    IrcConnect {
+        player_name: String,
+    },
+    /// Send a message to IRC channel
+    IrcSendMessage {
+        message: String,
+    },
+    /// Join an IRC channel
+    IrcJoinChannel {
+        channel: String,
+    },
+    /// Leave an IRC channel
+    IrcLeaveChannel {
+        channel: String,
+        message: Option<String>,
+    },
+    /// Disconnect from IRC
+    IrcDisconnect {
+        message: Option<String>,
+    },
+}
+
+/// What happens to a request that cannot be queued or is repeated before it is processed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueuePolicy {
+    /// Only the newest request per ULID matters; older ones (and ones hitting a full queue) are dropped silently
+    Coalesce,
+    /// A newer request for the same ULID replaces the older one; a full queue emits RequestRejected
+    Supersede,
+    /// Every request counts; a full queue emits RequestRejected
+    Reject,
+}
+
+impl GameRequest {
+    /// Variant name (for RequestRejected and logs)
+    pub fn kind(&self) -> String {
+        let debug = format!("{:?}", self);
+        debug.split([' ', '{', '(']).next().unwrap_or_default().to_string()
+    }
+
+    /// Entity the request is about, if any
+    pub fn ulid(&self) -> Option<&[u8]> {
+        match self {
+            Self::RequestPath { ulid, .. }
+            | Self::RequestRandomDest { ulid, .. }
+            | Self::UpdateEntityPosition { ulid, .. }
+            | Self::UpdateEntityState { ulid, .. }
+            | Self::RemoveEntity { ulid }
+            | Self::RegisterEntityStats { ulid, .. }
+            | Self::GetStat { ulid, .. }
+            | Self::SetStat { ulid, .. }
+            | Self::TakeDamage { ulid, .. }
+            | Self::Heal { ulid, .. }
+            | Self::ApplyStatus { ulid, .. }
+            | Self::SetStatusImmunities { ulid, .. }
+            | Self::Taunt { ulid, .. } => Some(ulid),
+            Self::ProjectileHit { defender_ulid, .. } => Some(defender_ulid),
+            _ => None,
+        }
+    }
+
+    pub fn queue_policy(&self) -> QueuePolicy {
+        match self {
+            Self::UpdateEntityPosition { .. } => QueuePolicy::Coalesce,
+            Self::RequestPath { .. } => QueuePolicy::Supersede,
+            _ => QueuePolicy::Reject,
+        }
+    }
+
+    /// RequestRejected event for this request
+    pub fn rejected(&self, reason: &str) -> GameEvent {
+        GameEvent::RequestRejected {
+            kind: self.kind(),
+            ulid: self.ulid().map(<[u8]>::to_vec).unwrap_or_default(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// LEDGER TYPES
+// ============================================================================
+
+/// Why resources changed (ledger tag)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransactionReason {
+    Adjustment,    // AddResources/SpendResources and host grants without a more specific reason
+    ComboReward,
+    Loot,
+    Upkeep,
+    Construction,
+    Rollback,      // Undoes an earlier transaction (see LedgerEntry::reverts)
+    Decay,         // Spoilage/drift applied at upkeep
+    Trade,         // Exchange at a market
+}
+
+impl TransactionReason {
+    /// Bridge/GDScript value (declaration order)
+    pub fn from_i64(value: i64) -> Option<Self> {
+        match value {
+            0 => Some(Self::Adjustment),
+            1 => Some(Self::ComboReward),
+            2 => Some(Self::Loot),
+            3 => Some(Self::Upkeep),
+            4 => Some(Self::Construction),
+            5 => Some(Self::Rollback),
+            6 => Some(Self::Decay),
+            7 => Some(Self::Trade),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Adjustment => "adjustment",
+            Self::ComboReward => "combo_reward",
+            Self::Loot => "loot",
+            Self::Upkeep => "upkeep",
+            Self::Construction => "construction",
+            Self::Rollback => "rollback",
+            Self::Decay => "decay",
+            Self::Trade => "trade",
+        }
+    }
+}
+
+/// One applied transaction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub transaction_id: u64,
+    pub player_ulid: Vec<u8>,  // Pool changed (empty = AI/neutral pool)
+    pub reason: TransactionReason,
+    pub changes: Vec<(i64, f64)>,  // (resource_type, amount actually applied), credits may be cut at the cap
+    pub reverts: Option<u64>,      // Rollback entries: the transaction they undo
+    pub rolled_back: bool,
+}
+
+// ============================================================================
+// RECIPE TYPES
+// ============================================================================
+
+/// What a recipe producer takes and makes per cycle
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Recipe {
+    pub inputs: Vec<(i64, f64)>,   // (resource_type, amount) taken when a cycle starts
+    pub outputs: Vec<(i64, f64)>,  // (resource_type, amount) added when it completes (capped)
+    pub cycle_secs: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecipeStatus {
+    Running,
+    /// Waiting for inputs (missing: what the pool lacks to start the next cycle)
+    Stalled {
+        missing: Vec<(i64, f64)>,
+    },
+    /// Switched off (RegisterRecipe/SetProducerActive)
+    Inactive,
+    /// Held by a labor shortage (see ShortageConfig::labor_pauses_production)
+    Paused,
+}
+
+/// One recipe producer as reported by QueryRecipes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecipeReport {
+    pub ulid: Vec<u8>,
+    pub player_ulid: Vec<u8>,
+    pub recipe: Recipe,
+    pub status: RecipeStatus,
+    pub progress: f64,  // Seconds into the current cycle
+    pub cycles_completed: u64,
+}
+
+// ============================================================================
+// SHORTAGE TYPES
+// ============================================================================
+
+/// Consequences of running out of food, labor or faith (0 / false turns an effect off)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShortageConfig {
+    pub starvation_hp: f32,             // Damage each of a player's units takes per upkeep it can't pay for (TakeDamage)
+    pub starvation_morale: f32,         // Morale each unit loses per unpaid upkeep
+    pub labor_pauses_production: bool,  // No labor: the pool's other producers stop until labor is back
+    pub faith_reputation_loss: f32,     // No faith: reputation the player's cities lose per upkeep
+}
+
+impl Default for ShortageConfig {
+    fn default() -> Self {
+        Self {
+            starvation_hp: 5.0,
+            starvation_morale: 10.0,
+            labor_pauses_production: true,
+            faith_reputation_loss: 2.0,
+        }
+    }
+}
+
+/// What a shortage did (ResourceShortage)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ShortageEffect {
+    /// Food: the player's units took damage and lost Morale (damage goes through TakeDamage, so it can kill)
+    Starvation {
+        units: Vec<Vec<u8>>,
+        hp_loss: f32,
+        morale_loss: f32,
+    },
+    /// Labor: these producers are paused until ShortageEnded
+    ProductionPaused {
+        producers: Vec<Vec<u8>>,
+    },
+    /// Faith: the player's cities lost this much reputation (the host mirrors it onto its structures)
+    ReputationLoss {
+        amount: f32,
+    },
+}
+
+// ============================================================================
+// DECAY TYPES
+// ============================================================================
+
+/// How a stored resource decays, once per turn after upkeep (defined per resource in the registry)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DecayRule {
+    /// Keeps forever (up to its cap)
+    #[default]
+    None,
+    /// Loses `rate` of whatever is stored above `threshold`
+    Spoil { rate: f64, threshold: f64 },
+    /// Moves `rate` of the way towards `baseline`, from above or below
+    Drift { rate: f64, baseline: f64 },
+}
+
+impl DecayRule {
+    /// Amount after one turn of decay, clamped to [0, cap]
+    /// Storage `protection` (0.0-1.0) prevents that share of a loss; gains (drift up to the baseline) are unaffected
+    pub fn apply(&self, amount: f64, cap: f64, protection: f64) -> f64 {
+        let kept = 1.0 - protection.clamp(0.0, 1.0);
+        let loss = match *self {
+            DecayRule::None => 0.0,
+            DecayRule::Spoil { rate, threshold } => (amount - threshold).max(0.0) * rate * kept,
+            DecayRule::Drift { rate, baseline } if amount > baseline => (amount - baseline) * rate * kept,
+            DecayRule::Drift { rate, baseline } => (amount - baseline) * rate,
+        };
+        (amount - loss).clamp(0.0, cap)
+    }
+}
+
+// ============================================================================
+// MARKET TYPES
+// ============================================================================
+
+/// One resource at a market
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketPrice {
+    pub resource_type: i64,
+    pub stock: f64,
+    pub price: f64,  // Unit value before the fee and trade_modifier (gold = 1.0 at normal stock)
+}
+
+// ============================================================================
+// FORECAST TYPES
+// ============================================================================
+
+/// How far QueryForecast looks ahead
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ForecastHorizon {
+    /// Real time: upkeep is due at the end of every full turn (GameTimer's ProcessTurnConsumption)
+    Seconds(f64),
+    /// Turn mode: each StepTurn charges upkeep, then produces one turn's worth
+    Turns(u32),
+}
+
+/// Projection of one resource over a forecast horizon
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceForecast {
+    pub resource_type: i64,
+    pub current: f64,
+    pub cap: f64,
+    pub rate: f64,             // Net producer/consumer flow per second
+    pub upkeep_per_turn: f64,  // Charged by player-controlled entities (food only)
+    pub decay: DecayRule,      // Applied right after each upkeep
+    pub decay_protection: f64, // Share of decay losses the player's storage prevents
+    pub projected: f64,        // Amount at the end of the horizon
+    pub empty_at: Option<f64>, // Seconds from now until it first reaches zero (None = not within the horizon)
+    pub full_at: Option<f64>,  // Seconds from now until it first reaches its cap (None = not within the horizon)
+}
+
+// ============================================================================
+// TURN TYPES
+// ============================================================================
+
+/// Outcome of one StepTurn, filled in phase by phase
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TurnSummary {
+    pub turn: u64,                    // 1-based number of the turn just completed
+    pub food_upkeep: f64,             // Food eaten by player-controlled entities
+    pub production: Vec<(Vec<u8>, i64, f64)>,  // (player_ulid, resource_type, net change) from producers/consumers
+    pub decay: Vec<(Vec<u8>, i64, f64)>,       // (player_ulid, resource_type, change) from spoilage/drift at upkeep
+    pub paths_resolved: u32,          // Move orders pathed this turn
+    pub attacks: u32,
+    pub damage_dealt: f64,
+    pub deaths: Vec<Vec<u8>>,         // ULIDs killed during combat resolution
+    pub killers: Vec<(Vec<u8>, Vec<u8>)>,  // (dead ULID, attacker that landed the last hit)
+    pub loot: Vec<TurnLoot>,
+    pub combos: Vec<TurnCombo>,
+}
+
+/// Drops rolled for one entity killed this turn
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TurnLoot {
+    pub ulid: Vec<u8>,
+    pub entity_type: String,
+    pub player_ulid: Vec<u8>,  // Pool credited (the killer's owner, empty = AI/neutral pool)
+    pub rewards: Vec<(String, i32)>,  // (reward type name, amount), e.g. ("gold", 25)
+}
+
+/// Combo completed by cards placed this turn
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TurnCombo {
+    pub player_ulid: Vec<u8>,  // Pool credited (whoever placed the cards)
+    pub hand_rank: i32,
+    pub hand_name: String,
+    pub card_positions: Vec<(i32, i32)>,
+    pub resource_bonuses: Vec<(i32, f32)>,  // (resource_type, amount), already credited
+}
+
+// ============================================================================
+// WORKER COMMUNICATION TYPES
+// ============================================================================
+
+/// How a hit landed (DamageDealt)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HitOutcome {
+    Normal,
+    Critical,  // Luck: extra damage
+    Glancing,  // Evasion: nearly dodged, reduced damage
+    Dodged,    // Evasion: no damage
+}
+
+impl HitOutcome {
+    pub fn name(self) -> &'static str {
+        match self {
+            HitOutcome::Normal => "normal",
+            HitOutcome::Critical => "critical",
+            HitOutcome::Glancing => "glancing",
+            HitOutcome::Dodged => "dodged",
+        }
+    }
+}
+
+/// Timed status effect kinds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum StatusKind {
+    Poison = 0,  // Damage per tick, stacks
+    Burn = 1,    // Damage per tick, refreshes
+    Stun = 2,    // No attacks or movement
+    Slow = 3,    // Slower attacks and movement
+    Regen = 4,   // Heal per tick, stacks
+}
+
+impl StatusKind {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(StatusKind::Poison),
+            1 => Some(StatusKind::Burn),
+            2 => Some(StatusKind::Stun),
+            3 => Some(StatusKind::Slow),
+            4 => Some(StatusKind::Regen),
+            _ => None,
+        }
+    }
+
+    pub fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            StatusKind::Poison => "poison",
+            StatusKind::Burn => "burn",
+            StatusKind::Stun => "stun",
+            StatusKind::Slow => "slow",
+            StatusKind::Regen => "regen",
+        }
+    }
+
+    /// Most copies of this effect that stack on one entity (1 = reapplying only refreshes it)
+    pub fn max_stacks(self) -> u32 {
+        match self {
+            StatusKind::Poison => 5,
+            StatusKind::Regen => 3,
+            StatusKind::Burn | StatusKind::Stun | StatusKind::Slow => 1,
+        }
+    }
+}
+
+/// A status effect on an entity (combat worker state, reported for SaveGame)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub stacks: u32,
+    pub remaining: f32,        // Seconds left
+    pub magnitude: f32,        // HP per tick per stack (Poison/Burn/Regen), fraction of speed taken off (Slow)
+    pub tick_timer: f32,       // Seconds toward the next periodic tick
+    pub source_ulid: Vec<u8>,  // Who applied it (empty = no one in particular)
+}
+
+/// How a unit picks its next target among enemies in aggro range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum TargetingPolicy {
+    #[default]
+    Nearest = 0,
+    LowestHp = 1,
+    HighestThreat = 2,    // Most threat (damage taken, taunts) plus proximity; switches when out-threatened
+    PreferRanged = 3,     // Nearest bow/magic/ranged unit, else nearest
+    PreferStructures = 4, // Nearest structure, else nearest
+}
+
+impl TargetingPolicy {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(TargetingPolicy::Nearest),
+            1 => Some(TargetingPolicy::LowestHp),
+            2 => Some(TargetingPolicy::HighestThreat),
+            3 => Some(TargetingPolicy::PreferRanged),
+            4 => Some(TargetingPolicy::PreferStructures),
+            _ => None,
+        }
+    }
+}
+
+/// Per entity type combat behavior (SetCombatProfile)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CombatProfile {
+    pub targeting: TargetingPolicy,
+    pub is_structure: bool,
+}
+
+/// Per projectile type burst on impact (SetProjectileSplash)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct SplashProfile {
+    pub radius: i32,          // Hexes around the impact it reaches (0 = single target)
+    pub falloff: f32,         // Fraction of the power lost per hex from the impact
+    pub friendly_fire: bool,  // Also catches the shooter's allies (never the shooter)
+}
+
+/// Entity snapshot for combat worker (immutable snapshot of entity state)
+#[derive(Debug, Clone)]
+pub struct CombatEntitySnapshot {
+    pub ulid: Vec<u8>,
+    pub player_ulid: Vec<u8>,  // Team affiliation (empty = AI team)
+    pub position: (i32, i32),
+    pub terrain_type: TerrainType,
+    pub hp: i32,
+    pub max_hp: i32,
+    pub mana: i32,             // Current mana (for magic attacks)
+    pub max_mana: i32,         // Maximum mana
+    pub attack: i32,
+    pub defense: i32,
+    pub morale: f32,           // Scales attack power (0-100)
+    pub luck: f32,             // Crit chance, in percent
+    pub evasion: f32,          // Dodge (and glancing) chance, in percent
+    pub range: i32,
+    pub combat_type: u8,       // CombatType bitwise flags
+    pub projectile_type: u8,   // ProjectileType enum value
+    pub combat_range: i32,     // Attack range in hexes
+    pub aggro_range: i32,      // Detection/aggro range in hexes
+    pub profile: CombatProfile,       // From the entity type (SetCombatProfile)
+    pub threat: Vec<(Vec<u8>, f32)>,  // Threat this entity holds toward each attacker
+}
+
+/// Persistent state of one attacker's combat (save/load)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveCombat {
+    pub attacker_ulid: Vec<u8>,
+    pub defender_ulid: Vec<u8>,
+    pub time_since_last_attack: f32,
+    pub attack_interval: f32,
+}
+
+/// Work request sent from Actor to Combat Worker
+#[derive(Debug, Clone)]
+pub struct CombatWorkRequest {
+    pub entities_snapshot: Vec<CombatEntitySnapshot>,
+    pub delta: f32,  // Simulated seconds since the previous combat tick
+    pub seed: u64,   // Seeds this tick's damage rolls (drawn from the Actor's RNG)
+    /// Effects applied since the previous tick, (ulid, effect); merged in before the tick
+    pub apply_statuses: Vec<(Vec<u8>, StatusEffect)>,
+    /// When set, replace the worker's active combats instead of ticking (LoadGame)
+    pub restore_combats: Option<Vec<ActiveCombat>>,
+    /// Status effects replacing the worker's alongside restore_combats
+    pub restore_statuses: Vec<(Vec<u8>, StatusEffect)>,
+}
+
+/// Work result sent from Combat Worker back to Actor
+#[derive(Debug, Clone)]
+pub enum CombatWorkResult {
+    CombatStarted {
+        attacker_ulid: Vec<u8>,
+        defender_ulid: Vec<u8>,
+    },
+    /// Attack animation should play (sets ATTACKING state)
+    AttackExecuted {
+        attacker_ulid: Vec<u8>,
+    },
+    DamageDealt {
+        attacker_ulid: Vec<u8>,
+        defender_ulid: Vec<u8>,
+        damage: i32,
+        outcome: HitOutcome,
+    },
+    EntityDied {
+        ulid: Vec<u8>,
+    },
+    CombatEnded {
+        attacker_ulid: Vec<u8>,
+        defender_ulid: Vec<u8>,
+    },
+    /// Spawn a projectile for ranged/bow/magic combat
+    SpawnProjectile {
+        attacker_ulid: Vec<u8>,
+        attacker_position: (i32, i32),
+        target_ulid: Vec<u8>,
+        target_position: (i32, i32),
+        projectile_type: u8,  // ProjectileType enum value
+        damage: i32,          // Attack power (Morale and high ground applied); Defense/Evasion/Luck resolve on ProjectileHit
+        cover: u32,           // Tiles covering the target from the shooter when fired
+    },
+    /// Mana consumed from attacker (for magic attacks)
+    ManaConsumed {
+        entity_ulid: Vec<u8>,
+        mana_cost: i32,
+        new_mana: i32,
+    },
+    /// Ranged unit should kite away from enemy (move to ideal distance)
+    KiteAway {
+        entity_ulid: Vec<u8>,
+        enemy_position: (i32, i32),
+        ideal_distance: i32,
+    },
+    /// An effect was merged into an entity's effects (stacks and duration as they now stand)
+    StatusApplied {
+        ulid: Vec<u8>,
+        effect: StatusEffect,
+        move_speed_multiplier: f32,
+    },
+    /// Periodic status damage/heal on an entity this tick
+    StatusTicked {
+        ulid: Vec<u8>,
+        damage: f32,
+        heal: f32,
+    },
+    StatusExpired {
+        ulid: Vec<u8>,
+        kind: StatusKind,
+        move_speed_multiplier: f32,
+    },
+    /// Worker's active combats and status effects after a tick (Actor keeps the latest for SaveGame)
+    ActiveCombats {
+        combats: Vec<ActiveCombat>,
+        statuses: Vec<(Vec<u8>, StatusEffect)>,
+    },
+}
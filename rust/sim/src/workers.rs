@@ -0,0 +1,843 @@
+// Worker thread implementations
+// Workers receive snapshots of data, compute results, return via channels
+
+use crossbeam_channel::{Receiver, Sender};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::thread::{self, JoinHandle};
+use std::collections::{HashMap, HashSet};
+use serde::{Serialize, Deserialize};
+
+use crate::damage::{attack_power, resolve_hit};
+use crate::range_calculator::{hex_distance, high_ground_multiplier, sightline, world_elevation, Sightline};
+use crate::targeting::{select_target, threat_score, RETARGET_THREAT_RATIO};
+use crate::status::{advance_statuses, apply_status, attack_speed_multiplier, is_stunned, move_speed_multiplier};
+use crate::terrain_cache::TerrainType;
+use crate::types::{Recipe, RecipeStatus, RequestId, StatusEffect, TargetingPolicy};
+
+// Re-export combat types from types.rs for convenience (other modules import from workers)
+pub use crate::types::{ActiveCombat, CombatEntitySnapshot, CombatWorkRequest, CombatWorkResult};
+
+// ============================================================================
+// SPAWN WORKER
+// ============================================================================
+
+#[derive(Debug, Clone)]
+pub struct SpawnWorkRequest {
+    pub ulid: Vec<u8>, // Assigned by the Actor (seeded when recording/replaying)
+    pub entity_type: String,
+    pub terrain_type: TerrainType,
+    pub preferred_location: (i32, i32),
+    pub search_radius: i32,
+    pub occupied_positions: Vec<(i32, i32)>, // Snapshot, not DashMap
+}
+
+/// Spawn the Actor has dispatched but not yet received a result for
+/// Kept (keyed by ULID) so saves can re-dispatch in-flight spawns on load
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingSpawn {
+    pub entity_type: String,
+    pub terrain_type: TerrainType,
+    pub preferred_location: (i32, i32),
+    pub search_radius: i32,
+}
+
+#[derive(Debug, Clone)]
+pub enum SpawnWorkResult {
+    Success {
+        ulid: Vec<u8>,
+        position: (i32, i32),
+        entity_type: String,
+        terrain_type: TerrainType,
+    },
+    Failed {
+        ulid: Vec<u8>,
+        entity_type: String,
+        error: String,
+    },
+}
+
+/// Workers run until every request sender is dropped (shutdown or supervisor restart)
+pub fn spawn_spawn_worker(
+    rx: Receiver<SpawnWorkRequest>,
+    tx: Sender<SpawnWorkResult>,
+) -> std::io::Result<JoinHandle<()>> {
+    thread::Builder::new()
+        .name("spawn-worker".to_string())
+        .spawn(move || {
+            while let Ok(request) = rx.recv() {
+                let _ = tx.send(handle_spawn_request(request));
+            }
+        })
+}
+
+/// Resolve a single spawn request (worker thread, or inline on the Actor thread)
+pub fn handle_spawn_request(request: SpawnWorkRequest) -> SpawnWorkResult {
+    // Simple spawn logic: try preferred location first, then spiral search
+    let position_result = find_spawn_position_simple(
+        request.preferred_location,
+        request.search_radius,
+        request.terrain_type,
+        &request.occupied_positions,
+    );
+
+    match position_result {
+        Some(position) => SpawnWorkResult::Success {
+            ulid: request.ulid,
+            position,
+            entity_type: request.entity_type,
+            terrain_type: request.terrain_type,
+        },
+        None => SpawnWorkResult::Failed {
+            ulid: request.ulid,
+            entity_type: request.entity_type,
+            error: "No valid spawn position found".to_string(),
+        },
+    }
+}
+
+/// Simple spawn position finder using snapshots
+fn find_spawn_position_simple(
+    preferred: (i32, i32),
+    radius: i32,
+    terrain_type: TerrainType,
+    occupied: &[(i32, i32)],
+) -> Option<(i32, i32)> {
+    use crate::terrain_cache;
+
+    // Check preferred location first
+    let terrain = terrain_cache::get_terrain(preferred.0, preferred.1);
+    if terrain == terrain_type && !occupied.contains(&preferred) {
+        return Some(preferred);
+    }
+
+    // Spiral search outward
+    for r in 1..=radius {
+        for dx in -r..=r {
+            for dy in -r..=r {
+                let pos = (preferred.0 + dx, preferred.1 + dy);
+                let terrain = terrain_cache::get_terrain(pos.0, pos.1);
+
+                if terrain == terrain_type && !occupied.contains(&pos) {
+                    return Some(pos);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// ============================================================================
+// PATHFINDING WORKER POOL
+// ============================================================================
+
+#[derive(Debug, Clone)]
+pub struct PathWorkRequest {
+    pub ulid: Vec<u8>,
+    pub job_id: u64,  // Echoed in the result so superseded paths can be dropped
+    pub query_id: Option<RequestId>, // Set for QueryPathCost (answered with PathWorkResult::Query)
+    pub terrain_type: TerrainType,
+    pub start: (i32, i32),
+    pub goal: (i32, i32),
+    pub avoid_entities: bool,
+    pub entity_positions: Option<HashMap<Vec<u8>, (i32, i32)>>, // Snapshot if avoiding
+}
+
+#[derive(Debug, Clone)]
+pub enum PathWorkResult {
+    Success {
+        ulid: Vec<u8>,
+        job_id: u64,
+        path: Vec<(i32, i32)>,
+        cost: f32,
+    },
+    Failed {
+        ulid: Vec<u8>,
+        job_id: u64,
+    },
+    RandomDestSuccess {
+        ulid: Vec<u8>,
+        destination: (i32, i32),
+    },
+    RandomDestFailed {
+        ulid: Vec<u8>,
+    },
+    Query {
+        request_id: RequestId,
+        path: Vec<(i32, i32)>,
+        cost: f32,
+    },
+}
+
+pub fn spawn_pathfinding_pool(
+    rx: Receiver<PathWorkRequest>,
+    tx: Sender<PathWorkResult>,
+    pool_size: usize,
+) -> std::io::Result<Vec<JoinHandle<()>>> {
+    (0..pool_size)
+        .map(|i| {
+            let rx_clone = rx.clone();
+            let tx_clone = tx.clone();
+
+            thread::Builder::new()
+                .name(format!("pathfinding-worker-{}", i))
+                .spawn(move || {
+                    while let Ok(request) = rx_clone.recv() {
+                        let _ = tx_clone.send(handle_path_request(request));
+                    }
+                })
+        })
+        .collect()
+}
+
+/// Resolve a single pathfinding request (worker thread, or inline on the Actor thread)
+pub fn handle_path_request(request: PathWorkRequest) -> PathWorkResult {
+    // Call actual A* pathfinding
+    use crate::pathfinding;
+
+    let pathfinding_request = pathfinding::PathfindingRequest {
+        entity_ulid: request.ulid.clone(),
+        start: request.start,
+        goal: request.goal,
+        terrain_type: request.terrain_type,
+        avoid_entities: request.avoid_entities,
+    };
+
+    let pathfinding_result = pathfinding::find_path_unified(&pathfinding_request);
+
+    if let Some(request_id) = request.query_id {
+        let found = pathfinding_result.success && !pathfinding_result.path.is_empty();
+        return PathWorkResult::Query {
+            request_id,
+            path: if found { pathfinding_result.path } else { Vec::new() },
+            cost: if found { pathfinding_result.cost } else { 0.0 },
+        };
+    }
+
+    if pathfinding_result.success && !pathfinding_result.path.is_empty() {
+        PathWorkResult::Success {
+            ulid: request.ulid,
+            job_id: request.job_id,
+            path: pathfinding_result.path,
+            cost: pathfinding_result.cost,
+        }
+    } else {
+        PathWorkResult::Failed {
+            ulid: request.ulid,
+            job_id: request.job_id,
+        }
+    }
+}
+
+// ============================================================================
+// COMBAT WORKER
+// ============================================================================
+
+// NOTE: Combat types moved to types.rs to avoid duplication
+
+pub fn spawn_combat_worker(
+    rx: Receiver<CombatWorkRequest>,
+    tx: Sender<CombatWorkResult>,
+) -> std::io::Result<JoinHandle<()>> {
+    thread::Builder::new()
+        .name("combat-worker".to_string())
+        .spawn(move || {
+            // Active combat instances (attacker_ulid -> combat state)
+            let mut active_combats: HashMap<Vec<u8>, CombatInstance> = HashMap::new();
+            // Status effects (entity ulid -> effects)
+            let mut statuses: HashMap<Vec<u8>, Vec<StatusEffect>> = HashMap::new();
+
+            while let Ok(request) = rx.recv() {
+                if let Some(combats) = request.restore_combats {
+                    restore_combats(&mut active_combats, combats);
+                    restore_statuses(&mut statuses, request.restore_statuses);
+                    continue;
+                }
+
+                // Process combat for all entities in snapshot
+                process_combat_tick(
+                    &request.entities_snapshot,
+                    &mut active_combats,
+                    &mut statuses,
+                    request.apply_statuses,
+                    request.delta,
+                    request.seed,
+                    &tx,
+                );
+            }
+        })
+}
+
+/// Combat instance tracking for a single attacker
+#[derive(Debug, Clone)]
+pub struct CombatInstance {
+    defender_ulid: Vec<u8>,
+    time_since_last_attack: f32,
+    attack_interval: f32,
+}
+
+impl CombatInstance {
+    fn new(defender_ulid: Vec<u8>, attack_interval: f32) -> Self {
+        Self {
+            defender_ulid,
+            time_since_last_attack: attack_interval, // Allow immediate first attack
+            attack_interval,
+        }
+    }
+
+    fn can_attack(&self) -> bool {
+        self.time_since_last_attack >= self.attack_interval
+    }
+
+    fn reset_attack_timer(&mut self) {
+        self.time_since_last_attack = 0.0;
+    }
+
+    fn tick(&mut self, delta: f32) {
+        self.time_since_last_attack += delta;
+    }
+}
+
+/// Replace active combats with restored ones (LoadGame)
+pub fn restore_combats(
+    active_combats: &mut HashMap<Vec<u8>, CombatInstance>,
+    combats: Vec<ActiveCombat>,
+) {
+    active_combats.clear();
+    for combat in combats {
+        active_combats.insert(combat.attacker_ulid, CombatInstance {
+            defender_ulid: combat.defender_ulid,
+            time_since_last_attack: combat.time_since_last_attack,
+            attack_interval: combat.attack_interval,
+        });
+    }
+}
+
+/// Replace status effects with restored ones (LoadGame)
+pub fn restore_statuses(
+    statuses: &mut HashMap<Vec<u8>, Vec<StatusEffect>>,
+    restored: Vec<(Vec<u8>, StatusEffect)>,
+) {
+    statuses.clear();
+    for (ulid, effect) in restored {
+        statuses.entry(ulid).or_default().push(effect);
+    }
+}
+
+/// Process one combat tick for all entities
+/// `delta` = simulated seconds since the previous combat tick; `seed` seeds its damage rolls
+/// `new_statuses` are merged in after existing effects advance, so they start with a full duration
+pub fn process_combat_tick(
+    entities: &[CombatEntitySnapshot],
+    active_combats: &mut HashMap<Vec<u8>, CombatInstance>,
+    statuses: &mut HashMap<Vec<u8>, Vec<StatusEffect>>,
+    new_statuses: Vec<(Vec<u8>, StatusEffect)>,
+    delta: f32,
+    seed: u64,
+    tx: &Sender<CombatWorkResult>,
+) {
+    // Attackers roll in snapshot (ULID) order, so a seed always plays out the same
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // Build quick lookup maps
+    let entity_map: HashMap<&Vec<u8>, &CombatEntitySnapshot> =
+        entities.iter().map(|e| (&e.ulid, e)).collect();
+
+    // Shooters need a line of fire over the terrain to pick or keep a target
+    let elevation = world_elevation();
+    let in_sight = |attacker: &CombatEntitySnapshot, defender: &CombatEntitySnapshot| {
+        !fires_projectiles(attacker) || !sightline(attacker.position, defender.position, &elevation).blocked
+    };
+    let mut lost_sight: HashSet<Vec<u8>> = HashSet::new();
+
+    // Advance status effects in snapshot order; dead or despawned entities lose theirs
+    statuses.retain(|ulid, _| entity_map.get(ulid).is_some_and(|entity| entity.hp > 0));
+    for entity in entities {
+        let Some(effects) = statuses.get_mut(&entity.ulid) else {
+            continue;
+        };
+        let tick = advance_statuses(effects, delta);
+        if tick.damage > 0.0 || tick.heal > 0.0 {
+            let _ = tx.send(CombatWorkResult::StatusTicked {
+                ulid: entity.ulid.clone(),
+                damage: tick.damage,
+                heal: tick.heal,
+            });
+        }
+        for kind in tick.expired {
+            let _ = tx.send(CombatWorkResult::StatusExpired {
+                ulid: entity.ulid.clone(),
+                kind,
+                move_speed_multiplier: move_speed_multiplier(effects),
+            });
+        }
+    }
+    for (ulid, effect) in new_statuses {
+        if entity_map.get(&ulid).is_none_or(|entity| entity.hp <= 0) {
+            continue;
+        }
+        let effects = statuses.entry(ulid.clone()).or_default();
+        let effect = apply_status(effects, effect);
+        let _ = tx.send(CombatWorkResult::StatusApplied {
+            ulid,
+            effect,
+            move_speed_multiplier: move_speed_multiplier(effects),
+        });
+    }
+    statuses.retain(|_, effects| !effects.is_empty());
+
+    // Tick all active combats (a Slow stretches the attack interval)
+    for (attacker_ulid, combat) in active_combats.iter_mut() {
+        let speed = statuses.get(attacker_ulid).map_or(1.0, |effects| attack_speed_multiplier(effects));
+        combat.tick(delta * speed);
+    }
+
+    // Process each entity
+    for attacker in entities {
+        // Skip dead entities
+        if attacker.hp <= 0 {
+            continue;
+        }
+
+        // Stunned entities neither attack, chase nor pick targets
+        if statuses.get(&attacker.ulid).is_some_and(|effects| is_stunned(effects)) {
+            continue;
+        }
+
+        // Threat-driven units turn on whoever clearly out-threatens their current target
+        if let Some(combat) = active_combats.get_mut(&attacker.ulid) {
+            if attacker.profile.targeting == TargetingPolicy::HighestThreat {
+                retarget_by_threat(attacker, combat, entities, &entity_map, |defender| in_sight(attacker, defender), tx);
+            }
+        }
+
+        // Check if already in combat
+        if let Some(combat) = active_combats.get_mut(&attacker.ulid) {
+            // Combat exists - check if defender still valid
+            if let Some(defender) = entity_map.get(&combat.defender_ulid) {
+                let defender_alive = defender.hp > 0;
+
+                if !defender_alive {
+                    // Defender died - end combat
+                    let _ = tx.send(CombatWorkResult::CombatEnded {
+                        attacker_ulid: attacker.ulid.clone(),
+                        defender_ulid: combat.defender_ulid.clone(),
+                    });
+                    // Remove from active combats (will happen after loop)
+                } else {
+                    // Check if still in range (entities might have moved)
+                    let distance = hex_distance(attacker.position, defender.position);
+                    let in_range = distance <= attacker.combat_range;
+
+                    // Shots follow the terrain: a mountain in the way blocks them, high ground helps
+                    let sight = (in_range && fires_projectiles(attacker))
+                        .then(|| sightline(attacker.position, defender.position, &elevation));
+                    let blocked = sight.is_some_and(|sight| sight.blocked);
+
+                    // Check combat type for chase vs disengage behavior
+                    const MELEE: u8 = 1 << 0;
+                    let is_melee = attacker.combat_type & MELEE != 0;
+
+                    if blocked {
+                        // Lost the line of fire - drop the target (another may be in sight next tick)
+                        let _ = tx.send(CombatWorkResult::CombatEnded {
+                            attacker_ulid: attacker.ulid.clone(),
+                            defender_ulid: combat.defender_ulid.clone(),
+                        });
+                        lost_sight.insert(attacker.ulid.clone());
+                    } else if in_range && combat.can_attack() {
+                        // In range and can attack - execute attack
+                        execute_attack(attacker, defender, sight, &mut rng, tx);
+                        combat.reset_attack_timer();
+                    } else if !in_range {
+                        // Out of range behavior depends on combat type
+                        if is_melee {
+                            // Melee units chase their target (pathfind toward enemy)
+                            let _ = tx.send(CombatWorkResult::KiteAway {
+                                entity_ulid: attacker.ulid.clone(),
+                                enemy_position: defender.position,
+                                ideal_distance: -1, // Negative = chase toward (not away)
+                            });
+                        } else {
+                            // Ranged units disengage when out of range
+                            let _ = tx.send(CombatWorkResult::CombatEnded {
+                                attacker_ulid: attacker.ulid.clone(),
+                                defender_ulid: combat.defender_ulid.clone(),
+                            });
+                        }
+                    }
+                }
+            } else {
+                // Defender no longer exists - end combat
+                let _ = tx.send(CombatWorkResult::CombatEnded {
+                    attacker_ulid: attacker.ulid.clone(),
+                    defender_ulid: combat.defender_ulid.clone(),
+                });
+            }
+        } else {
+            // Not in combat - search for targets (by the attacker's targeting policy)
+            if let Some(target) = select_target(attacker, entities, |defender| in_sight(attacker, defender)) {
+                // Found enemy in range - start combat
+                let combat = CombatInstance::new(target.ulid.clone(), 1.5); // 1.5s attack interval
+                active_combats.insert(attacker.ulid.clone(), combat);
+
+                // Queue combat started event
+                let _ = tx.send(CombatWorkResult::CombatStarted {
+                    attacker_ulid: attacker.ulid.clone(),
+                    defender_ulid: target.ulid.clone(),
+                });
+            }
+        }
+    }
+
+    // Clean up combats where defender is dead, out of range or out of sight
+    // This removes the combat instance after CombatEnded events have been sent
+    active_combats.retain(|attacker_ulid, combat| {
+        if lost_sight.contains(attacker_ulid) {
+            return false;
+        }
+
+        // Find attacker and defender
+        let attacker_opt = entity_map.get(attacker_ulid);
+        let defender_opt = entity_map.get(&combat.defender_ulid);
+
+        // Keep combat only if both exist, defender is alive, and in range
+        if let (Some(attacker), Some(defender)) = (attacker_opt, defender_opt) {
+            if defender.hp <= 0 {
+                return false; // Defender dead
+            }
+            let distance = hex_distance(attacker.position, defender.position);
+            distance <= attacker.combat_range // Keep only if in range
+        } else {
+            false // Either attacker or defender missing
+        }
+    });
+
+    // Report surviving combats so the Actor can include them in saves
+    let mut combats: Vec<ActiveCombat> = active_combats
+        .iter()
+        .map(|(attacker_ulid, combat)| ActiveCombat {
+            attacker_ulid: attacker_ulid.clone(),
+            defender_ulid: combat.defender_ulid.clone(),
+            time_since_last_attack: combat.time_since_last_attack,
+            attack_interval: combat.attack_interval,
+        })
+        .collect();
+    combats.sort_by(|a, b| a.attacker_ulid.cmp(&b.attacker_ulid));
+    let mut active_statuses: Vec<(Vec<u8>, StatusEffect)> = statuses
+        .iter()
+        .flat_map(|(ulid, effects)| effects.iter().map(move |effect| (ulid.clone(), effect.clone())))
+        .collect();
+    active_statuses.sort_by(|a, b| a.0.cmp(&b.0));
+    let _ = tx.send(CombatWorkResult::ActiveCombats { combats, statuses: active_statuses });
+}
+
+/// Switch a HighestThreat attacker to the best-scoring enemy if it beats the current target by
+/// RETARGET_THREAT_RATIO (keeps the attack timer, so switching never grants a free attack)
+fn retarget_by_threat(
+    attacker: &CombatEntitySnapshot,
+    combat: &mut CombatInstance,
+    entities: &[CombatEntitySnapshot],
+    entity_map: &HashMap<&Vec<u8>, &CombatEntitySnapshot>,
+    in_sight: impl Fn(&CombatEntitySnapshot) -> bool,
+    tx: &Sender<CombatWorkResult>,
+) {
+    let Some(best) = select_target(attacker, entities, in_sight) else {
+        return;
+    };
+    if best.ulid == combat.defender_ulid {
+        return;
+    }
+    let current_score = entity_map.get(&combat.defender_ulid)
+        .filter(|current| current.hp > 0)
+        .map_or(0.0, |current| threat_score(attacker, current));
+    if threat_score(attacker, best) <= current_score * RETARGET_THREAT_RATIO {
+        return;
+    }
+
+    let _ = tx.send(CombatWorkResult::CombatEnded {
+        attacker_ulid: attacker.ulid.clone(),
+        defender_ulid: combat.defender_ulid.clone(),
+    });
+    let _ = tx.send(CombatWorkResult::CombatStarted {
+        attacker_ulid: attacker.ulid.clone(),
+        defender_ulid: best.ulid.clone(),
+    });
+    combat.defender_ulid = best.ulid.clone();
+}
+
+/// Whether an entity attacks with projectiles (BOW or MAGIC), which need a line of fire
+fn fires_projectiles(entity: &CombatEntitySnapshot) -> bool {
+    const BOW: u8 = 1 << 2;
+    const MAGIC: u8 = 1 << 3;
+    entity.combat_type & (BOW | MAGIC) != 0
+}
+
+/// Execute an attack between two entities
+/// `sight` is the shooter's line of fire as traced this tick (None for melee): its high ground
+/// powers the shot now, its cover travels with the projectile to ProjectileHit
+fn execute_attack(
+    attacker: &CombatEntitySnapshot,
+    defender: &CombatEntitySnapshot,
+    sight: Option<Sightline>,
+    rng: &mut StdRng,
+    tx: &Sender<CombatWorkResult>,
+) {
+    // CombatType flags (using bit shifts for readability)
+    #[allow(dead_code)]
+    const MELEE: u8 = 1 << 0;   // 1 (default when BOW/MAGIC are unset)
+    const BOW: u8 = 1 << 2;     // 4
+    const MAGIC: u8 = 1 << 3;   // 8
+
+    // Mana cost for magic attacks
+    const MAGIC_MANA_COST: i32 = 15;
+
+    // Check combat type
+    let is_bow = attacker.combat_type & BOW != 0;
+    let is_magic = attacker.combat_type & MAGIC != 0;
+
+    // Check mana availability for magic attacks
+    if is_magic && attacker.mana < MAGIC_MANA_COST {
+        // Not enough mana - attack fails silently
+        // Could emit a "NotEnoughMana" event in the future
+        return;
+    }
+
+    // What the attacker puts in (Attack scaled by Morale and high ground); the defender's stats apply on impact
+    let elevation_advantage = sight.map_or(0.0, |sight| sight.elevation_advantage);
+    let power = attack_power(attacker.attack as f32, attacker.morale) * high_ground_multiplier(elevation_advantage);
+
+    // Consume mana for magic attacks
+    if is_magic {
+        let new_mana = attacker.mana - MAGIC_MANA_COST;
+        let _ = tx.send(CombatWorkResult::ManaConsumed {
+            entity_ulid: attacker.ulid.clone(),
+            mana_cost: MAGIC_MANA_COST,
+            new_mana,
+        });
+    }
+
+    // Notify that attacker is executing attack animation
+    let _ = tx.send(CombatWorkResult::AttackExecuted {
+        attacker_ulid: attacker.ulid.clone(),
+    });
+
+    // For ranged combat (BOW or MAGIC), spawn projectile instead of instant damage
+    if is_bow || is_magic {
+        sim_print!(
+            "[Rust Combat] Spawning projectile: attacker={:02x?}, target={:02x?}, type={}, power={}",
+            &attacker.ulid[..8],
+            &defender.ulid[..8],
+            attacker.projectile_type,
+            power
+        );
+        let _ = tx.send(CombatWorkResult::SpawnProjectile {
+            attacker_ulid: attacker.ulid.clone(),
+            attacker_position: attacker.position,
+            target_ulid: defender.ulid.clone(),
+            target_position: defender.position,
+            projectile_type: attacker.projectile_type,
+            damage: power.round() as i32,
+            cover: sight.map_or(0, |sight| sight.cover),
+        });
+
+        // Kiting behavior: If enemy is too close, move away to ideal distance
+        // Ideal distance for ranged units: 60% of max range (allows flexibility)
+        let distance = hex_distance(attacker.position, defender.position);
+        let ideal_distance = (attacker.combat_range as f32 * 0.6).ceil() as i32;
+        let min_safe_distance = (attacker.combat_range as f32 * 0.4).ceil() as i32;
+
+        if distance < min_safe_distance {
+            // Too close - kite away
+            let _ = tx.send(CombatWorkResult::KiteAway {
+                entity_ulid: attacker.ulid.clone(),
+                enemy_position: defender.position,
+                ideal_distance,
+            });
+        }
+    } else {
+        // Melee combat - instant damage
+        let hit = resolve_hit(power, attacker.luck, defender.defense as f32, defender.evasion, rng);
+        let _ = tx.send(CombatWorkResult::DamageDealt {
+            attacker_ulid: attacker.ulid.clone(),
+            defender_ulid: defender.ulid.clone(),
+            damage: hit.damage,
+            outcome: hit.outcome,
+        });
+
+        // Check if defender will die
+        let new_hp = defender.hp - hit.damage;
+        if new_hp <= 0 {
+            let _ = tx.send(CombatWorkResult::EntityDied {
+                ulid: defender.ulid.clone(),
+            });
+        }
+    }
+}
+
+// ============================================================================
+// ECONOMY WORKER
+// ============================================================================
+
+/// Registered producer or consumer
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceFlow {
+    pub ulid: Vec<u8>,         // Building/entity that produces or consumes
+    pub player_ulid: Vec<u8>,  // Pool it feeds or drains (empty = AI/neutral pool)
+    pub resource_type: i64,
+    pub rate: f64,             // Per second
+    pub active: bool,
+}
+
+/// Shortest recipe cycle (shorter ones are stretched to it)
+pub const MIN_RECIPE_CYCLE_SECS: f64 = 0.1;
+
+/// Registered recipe producer and its progress through the current cycle
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeProducer {
+    pub ulid: Vec<u8>,
+    pub player_ulid: Vec<u8>,
+    pub recipe: Recipe,
+    pub active: bool,
+    pub progress: f64,      // Seconds into the current cycle
+    pub inputs_paid: bool,  // Inputs of the current cycle already taken from the pool
+    pub cycles_completed: u64,
+    pub status: RecipeStatus,
+}
+
+impl RecipeProducer {
+    /// Average flow per second into a resource while running (outputs minus inputs)
+    pub fn rate(&self, resource_type: i64) -> f64 {
+        let per_cycle = |amounts: &[(i64, f64)]| -> f64 {
+            amounts.iter().filter(|(kind, _)| *kind == resource_type).map(|(_, amount)| amount).sum()
+        };
+        (per_cycle(&self.recipe.outputs) - per_cycle(&self.recipe.inputs)) / self.recipe.cycle_secs.max(MIN_RECIPE_CYCLE_SECS)
+    }
+
+    /// Run for `secs` against a pool (resource_type -> (current, cap)), completing as many cycles as fit
+    /// Inactive producers are left alone (their status is set by the economy system)
+    pub fn run(&mut self, secs: f64, pool: &mut HashMap<i64, (f64, f64)>) {
+        if !self.active {
+            return;
+        }
+
+        let cycle = self.recipe.cycle_secs.max(MIN_RECIPE_CYCLE_SECS);
+        let mut remaining = secs;
+        loop {
+            if !self.inputs_paid {
+                let missing: Vec<(i64, f64)> = self.recipe.inputs.iter()
+                    .filter_map(|(resource_type, amount)| {
+                        let have = pool.get(resource_type).map_or(0.0, |resource| resource.0);
+                        (have < *amount).then(|| (*resource_type, amount - have))
+                    })
+                    .collect();
+                if !missing.is_empty() {
+                    self.status = RecipeStatus::Stalled { missing };
+                    return;
+                }
+                for (resource_type, amount) in &self.recipe.inputs {
+                    if let Some(resource) = pool.get_mut(resource_type) {
+                        resource.0 -= amount;
+                    }
+                }
+                self.inputs_paid = true;
+            }
+            self.status = RecipeStatus::Running;
+
+            if self.progress + remaining < cycle {
+                self.progress += remaining;
+                return;
+            }
+            remaining -= cycle - self.progress;
+            self.progress = 0.0;
+            self.inputs_paid = false;
+            self.cycles_completed += 1;
+            for (resource_type, amount) in &self.recipe.outputs {
+                if let Some(resource) = pool.get_mut(resource_type) {
+                    resource.0 = (resource.0 + amount).min(resource.1);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EconomyWorkRequest {
+    pub producers_snapshot: Vec<ResourceFlow>,
+    pub consumers_snapshot: Vec<ResourceFlow>,
+    pub recipes_snapshot: Vec<RecipeProducer>,  // Run in order, after producer/consumer flow
+    pub current_resources: Vec<(Vec<u8>, i64, f64, f64)>, // (player_ulid, type, current, cap)
+    pub elapsed_secs: f64,  // Simulated seconds of production to apply (since the last economy tick, or a whole turn)
+}
+
+#[derive(Debug, Clone)]
+pub struct EconomyWorkResult {
+    pub resource_changes: Vec<(Vec<u8>, i64, f64, f64)>, // (player_ulid, type, change, rate); change is against current_resources
+    pub recipes: Vec<RecipeProducer>,  // recipes_snapshot after the step
+}
+
+pub fn spawn_economy_worker(
+    rx: Receiver<EconomyWorkRequest>,
+    tx: Sender<EconomyWorkResult>,
+) -> std::io::Result<JoinHandle<()>> {
+    thread::Builder::new()
+        .name("economy-worker".to_string())
+        .spawn(move || {
+            while let Ok(request) = rx.recv() {
+                let _ = tx.send(handle_economy_request(request));
+            }
+        })
+}
+
+/// Net flow per second into one resource of a pool (active producers minus active consumers,
+/// plus the average of running recipes). Only flows owned by the pool's player count towards it
+pub fn net_rate(producers: &[ResourceFlow], consumers: &[ResourceFlow], recipes: &[RecipeProducer], player_ulid: &[u8], resource_type: i64) -> f64 {
+    let net_flow = |flows: &[ResourceFlow]| -> f64 {
+        flows.iter()
+            .filter(|flow| flow.active && flow.resource_type == resource_type && flow.player_ulid == player_ulid)
+            .map(|flow| flow.rate)
+            .sum()
+    };
+    let recipe_flow: f64 = recipes.iter()
+        .filter(|recipe| recipe.status == RecipeStatus::Running && recipe.player_ulid == player_ulid)
+        .map(|recipe| recipe.rate(resource_type))
+        .sum();
+    net_flow(producers) - net_flow(consumers) + recipe_flow
+}
+
+/// Calculate resource changes for one economy tick (worker thread, or inline on the Actor thread)
+pub fn handle_economy_request(request: EconomyWorkRequest) -> EconomyWorkResult {
+    // Producer/consumer flow first, per pool
+    let mut pools: HashMap<Vec<u8>, HashMap<i64, (f64, f64)>> = HashMap::new();
+    for (player_ulid, resource_type, current, cap) in &request.current_resources {
+        let net_rate = net_rate(&request.producers_snapshot, &request.consumers_snapshot, &[], player_ulid, *resource_type);
+
+        // Apply change over the elapsed time
+        let new_current = (current + net_rate * request.elapsed_secs).clamp(0.0, *cap);
+        pools.entry(player_ulid.clone()).or_default().insert(*resource_type, (new_current, *cap));
+    }
+
+    // Then recipes, which may stall on what the flow left
+    let mut recipes = request.recipes_snapshot;
+    for recipe in &mut recipes {
+        if let Some(pool) = pools.get_mut(&recipe.player_ulid) {
+            recipe.run(request.elapsed_secs, pool);
+        }
+    }
+
+    // Report what production changed rather than where it left the snapshot, so the Actor can add it
+    // to whatever its pools hold by the time the result arrives
+    let changes = request.current_resources.into_iter()
+        .map(|(player_ulid, resource_type, current, cap)| {
+            let change = pools[&player_ulid][&resource_type].0.clamp(0.0, cap) - current;
+            let rate = net_rate(&request.producers_snapshot, &request.consumers_snapshot, &recipes, &player_ulid, resource_type);
+            (player_ulid, resource_type, change, rate)
+        })
+        .collect();
+
+    EconomyWorkResult {
+        resource_changes: changes,
+        recipes,
+    }
+}
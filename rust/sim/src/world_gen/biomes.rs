@@ -1,6 +1,5 @@
 use super::noise::NoiseGenerator;
 use crate::config::map as map_config;
-use serde::{Deserialize, Deserializer, Serialize};
 
 /// Terrain types matching the existing GDScript system
 // #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)] !TODO: Serde
@@ -96,7 +95,7 @@ impl BiomeGenerator {
     /// Layout: STACKED_OFFSET VERTICAL
     /// Uses constants from config::map for maintainability
     #[inline]
-    fn tile_to_hex_world_pos(tile_x: i32, tile_y: i32) -> (f32, f32) {
+    pub(crate) fn tile_to_hex_world_pos(tile_x: i32, tile_y: i32) -> (f32, f32) {
         let x = map_config::HEX_OFFSET_X + (tile_x as f32) * map_config::HEX_HORIZONTAL_SPACING;
         let mut y = map_config::HEX_OFFSET_Y + (tile_y as f32) * map_config::HEX_VERTICAL_SPACING;
 
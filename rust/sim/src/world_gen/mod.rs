@@ -0,0 +1,14 @@
+pub mod biomes;
+pub mod noise;
+
+pub use biomes::{BiomeGenerator, TerrainType};
+pub use noise::NoiseGenerator;
+
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Thread-safe cache of noise generators by seed
+/// Shared by the Godot chunk generator and the headless terrain cache
+pub static NOISE_CACHE: once_cell::sync::Lazy<RwLock<HashMap<i32, Arc<NoiseGenerator>>>> =
+    once_cell::sync::Lazy::new(|| RwLock::new(HashMap::new()));
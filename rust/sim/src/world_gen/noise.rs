@@ -133,7 +133,7 @@ mod tests {
         for x in 0..100 {
             for y in 0..100 {
                 let elevation = gen.get_elevation(x as f32, y as f32);
-                assert!(elevation >= -1.0 && elevation <= 1.0);
+                assert!((-1.0..=1.0).contains(&elevation));
             }
         }
     }
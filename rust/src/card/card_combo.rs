@@ -2,991 +2,7 @@ use godot::prelude::*;
 use super::card::CardData;
 use std::collections::HashMap;
 
-/// Resource type enum (matches GDScript ResourceLedger.R enum)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ResourceType {
-    Gold = 0,    // Diamonds
-    Food = 1,    // Hearts
-    Labor = 2,   // Spades
-    Faith = 3,   // Clubs
-}
-
-impl ResourceType {
-    /// Get resource type from suit (0=Clubs, 1=Diamonds, 2=Hearts, 3=Spades)
-    pub fn from_suit(suit: u8) -> Self {
-        match suit {
-            0 => ResourceType::Faith,   // Clubs -> Faith
-            1 => ResourceType::Gold,    // Diamonds -> Gold
-            2 => ResourceType::Food,    // Hearts -> Food
-            3 => ResourceType::Labor,   // Spades -> Labor
-            _ => ResourceType::Gold,    // Default to Gold
-        }
-    }
-
-    pub fn to_string(&self) -> String {
-        match self {
-            ResourceType::Gold => "Gold".to_string(),
-            ResourceType::Food => "Food".to_string(),
-            ResourceType::Labor => "Labor".to_string(),
-            ResourceType::Faith => "Faith".to_string(),
-        }
-    }
-}
-
-/// Resource bonus from a combo
-#[derive(Debug, Clone)]
-pub struct ResourceBonus {
-    pub resource_type: ResourceType,
-    pub resource_name: String,
-    pub amount: f32,
-}
-
-/// Poker hand ranks (from weakest to strongest)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum PokerHand {
-    HighCard = 0,
-    OnePair = 1,
-    TwoPair = 2,
-    ThreeOfAKind = 3,
-    Straight = 4,
-    Flush = 5,
-    FullHouse = 6,
-    FourOfAKind = 7,
-    StraightFlush = 8,
-    RoyalFlush = 9,
-}
-
-impl PokerHand {
-    pub fn to_string(&self) -> String {
-        match self {
-            PokerHand::HighCard => "High Card".to_string(),
-            PokerHand::OnePair => "One Pair".to_string(),
-            PokerHand::TwoPair => "Two Pair".to_string(),
-            PokerHand::ThreeOfAKind => "Three of a Kind".to_string(),
-            PokerHand::Straight => "Straight".to_string(),
-            PokerHand::Flush => "Flush".to_string(),
-            PokerHand::FullHouse => "Full House".to_string(),
-            PokerHand::FourOfAKind => "Four of a Kind".to_string(),
-            PokerHand::StraightFlush => "Straight Flush".to_string(),
-            PokerHand::RoyalFlush => "Royal Flush".to_string(),
-        }
-    }
-
-    /// Get bonus multiplier for this hand
-    pub fn bonus_multiplier(&self) -> f32 {
-        match self {
-            PokerHand::HighCard => 1.0,
-            PokerHand::OnePair => 1.5,
-            PokerHand::TwoPair => 2.0,
-            PokerHand::ThreeOfAKind => 3.0,
-            PokerHand::Straight => 4.0,
-            PokerHand::Flush => 5.0,
-            PokerHand::FullHouse => 7.0,
-            PokerHand::FourOfAKind => 10.0,
-            PokerHand::StraightFlush => 20.0,
-            PokerHand::RoyalFlush => 50.0,
-        }
-    }
-}
-
-/// Card with position on hex grid
-#[derive(Debug, Clone)]
-pub struct PositionedCard {
-    pub card: CardData,
-    pub x: i32,
-    pub y: i32,
-    pub index: usize, // Original index in input array
-}
-
-/// Result of combo detection
-#[derive(Debug, Clone)]
-pub struct ComboResult {
-    pub hand: PokerHand,
-    pub hand_name: String,
-    pub bonus_multiplier: f32,
-    pub cards_used: Vec<usize>, // Indices of cards that form the combo
-    pub positions: Vec<(i32, i32)>, // Hex positions of the combo
-    pub resource_bonuses: Vec<ResourceBonus>, // Resource bonuses from this combo
-}
-
-impl ComboResult {
-    pub fn new(hand: PokerHand) -> Self {
-        Self {
-            hand_name: hand.to_string(),
-            bonus_multiplier: hand.bonus_multiplier(),
-            hand,
-            cards_used: Vec::new(),
-            positions: Vec::new(),
-            resource_bonuses: Vec::new(),
-        }
-    }
-
-    pub fn with_cards(hand: PokerHand, cards: Vec<usize>, positions: Vec<(i32, i32)>) -> Self {
-        Self {
-            hand_name: hand.to_string(),
-            bonus_multiplier: hand.bonus_multiplier(),
-            hand,
-            cards_used: cards,
-            resource_bonuses: Vec::new(),
-            positions,
-        }
-    }
-
-    pub fn with_resources(hand: PokerHand, cards: Vec<usize>, positions: Vec<(i32, i32)>, line_cards: &[&CardData]) -> Self {
-        let mut result = Self {
-            hand_name: hand.to_string(),
-            bonus_multiplier: hand.bonus_multiplier(),
-            hand,
-            cards_used: cards,
-            positions,
-            resource_bonuses: Vec::new(),
-        };
-        result.calculate_resource_bonuses(line_cards);
-        result
-    }
-
-    fn calculate_resource_bonuses(&mut self, cards: &[&CardData]) {
-        // Sum card values by suit (value * 10 * multiplier per card)
-        let mut suit_totals: HashMap<u8, f32> = HashMap::new();
-        for card in cards.iter().filter(|c| !c.is_custom) {
-            // Calculate resource for this card: value * 10 * hand_multiplier
-            let card_value = card.value as f32;
-            let card_resource = card_value * 10.0 * self.bonus_multiplier;
-            *suit_totals.entry(card.suit).or_insert(0.0) += card_resource;
-        }
-
-        // Create resource bonuses from suit totals
-        for (suit, total) in suit_totals.iter() {
-            let resource_type = ResourceType::from_suit(*suit);
-            self.resource_bonuses.push(ResourceBonus {
-                resource_type,
-                resource_name: resource_type.to_string(),
-                amount: *total,
-            });
-        }
-    }
-}
-
-/// Hex grid directions (6 directions for flat-top hex)
-const HEX_DIRECTIONS: [(i32, i32); 6] = [
-    (1, 0),   // East
-    (1, -1),  // Northeast
-    (0, -1),  // Northwest
-    (-1, 0),  // West
-    (-1, 1),  // Southwest
-    (0, 1),   // Southeast
-];
-
-/// Detect poker hands in hex grid with distance-bounded spatial evaluation
-pub struct ComboDetector;
-
-impl ComboDetector {
-    /// Maximum hex distance for cards to be considered in the same group
-    /// This is the "pair window" - cards beyond this distance won't form combos
-    const MAX_HEX_DISTANCE: i32 = 5;
-
-    /// Detect the best poker hand from positioned cards on hex grid
-    /// Uses distance-bounded spatial hand evaluation
-    /// Returns ComboResult - if hand is HighCard, it means no valid combo was found
-    pub fn detect_combo(positioned_cards: &[PositionedCard]) -> ComboResult {
-        if positioned_cards.is_empty() {
-            return ComboResult::new(PokerHand::HighCard);
-        }
-
-        // Find all groups where cards are within MAX_HEX_DISTANCE of each other
-        let groups = Self::find_distance_bounded_groups(positioned_cards);
-
-        // Check each group for the best hand
-        let mut best_result = ComboResult::new(PokerHand::HighCard);
-
-        for group in groups {
-            if let Some(result) = Self::check_group_for_combos(&group, positioned_cards) {
-                if result.hand > best_result.hand {
-                    best_result = result;
-                }
-            }
-        }
-
-        // Don't populate HighCard with cards - HighCard means "no combo found"
-        // Caller should check if result.hand == HighCard and skip emitting signal
-        best_result
-    }
-
-    /// Calculate hex distance between two positions (axial coordinates)
-    /// Uses the standard hex distance formula for axial coordinates
-    fn hex_distance(a: (i32, i32), b: (i32, i32)) -> i32 {
-        let dx = (a.0 - b.0).abs();
-        let dy = (a.1 - b.1).abs();
-        let dz = (dx + dy).abs();
-        (dx + dy + dz) / 2
-    }
-
-    /// Find groups of cards within MAX_HEX_DISTANCE of each other
-    /// Uses flood-fill but expands to any card within distance, not just adjacent
-    fn find_distance_bounded_groups(cards: &[PositionedCard]) -> Vec<Vec<usize>> {
-        let mut groups = Vec::new();
-        let mut visited = vec![false; cards.len()];
-
-        for start_idx in 0..cards.len() {
-            if visited[start_idx] {
-                continue;
-            }
-
-            // Start a new group with flood-fill
-            let mut group = Vec::new();
-            let mut to_visit = vec![start_idx];
-
-            while let Some(idx) = to_visit.pop() {
-                if visited[idx] {
-                    continue;
-                }
-
-                visited[idx] = true;
-                group.push(idx);
-
-                // Find all cards within MAX_HEX_DISTANCE
-                let card_pos = (cards[idx].x, cards[idx].y);
-                for (other_idx, other_card) in cards.iter().enumerate() {
-                    if visited[other_idx] {
-                        continue;
-                    }
-
-                    let other_pos = (other_card.x, other_card.y);
-                    let distance = Self::hex_distance(card_pos, other_pos);
-
-                    if distance <= Self::MAX_HEX_DISTANCE {
-                        to_visit.push(other_idx);
-                    }
-                }
-            }
-
-            if group.len() >= 2 {
-                groups.push(group);
-            }
-        }
-
-        groups
-    }
-
-    /// Trace a line in a specific direction from a starting card
-    fn trace_line(start_idx: usize, direction: (i32, i32), cards: &[PositionedCard]) -> Option<Vec<usize>> {
-        let start = &cards[start_idx];
-        let mut line = vec![start_idx];
-        let mut current_pos = (start.x, start.y);
-
-        // Trace forward
-        loop {
-            let next_pos = (current_pos.0 + direction.0, current_pos.1 + direction.1);
-
-            if let Some(next_idx) = Self::find_card_at_position(&next_pos, cards) {
-                line.push(next_idx);
-                current_pos = next_pos;
-            } else {
-                break;
-            }
-        }
-
-        // Trace backward
-        current_pos = (start.x, start.y);
-        let reverse_dir = (-direction.0, -direction.1);
-        loop {
-            let prev_pos = (current_pos.0 + reverse_dir.0, current_pos.1 + reverse_dir.1);
-
-            if let Some(prev_idx) = Self::find_card_at_position(&prev_pos, cards) {
-                line.insert(0, prev_idx);
-                current_pos = prev_pos;
-            } else {
-                break;
-            }
-        }
-
-        if line.len() >= 2 {
-            Some(line)
-        } else {
-            None
-        }
-    }
-
-    /// Find card index at specific position
-    fn find_card_at_position(pos: &(i32, i32), cards: &[PositionedCard]) -> Option<usize> {
-        cards.iter()
-            .position(|c| c.x == pos.0 && c.y == pos.1)
-    }
-
-    /// Check if line already exists in the list
-    fn line_exists(line: &[usize], lines: &[Vec<usize>]) -> bool {
-        for existing in lines {
-            if Self::same_line(line, existing) {
-                return true;
-            }
-        }
-        false
-    }
-
-    /// Check if two lines contain the same cards (regardless of order)
-    fn same_line(a: &[usize], b: &[usize]) -> bool {
-        if a.len() != b.len() {
-            return false;
-        }
-        let mut a_sorted = a.to_vec();
-        let mut b_sorted = b.to_vec();
-        a_sorted.sort();
-        b_sorted.sort();
-        a_sorted == b_sorted
-    }
-
-    /// Select the best 5 cards from a group for a specific poker hand
-    /// This is a simplified implementation - just takes first 5 standard cards
-    /// Select the best 5 cards for a specific poker hand
-    /// Returns the indices of cards that form the BEST version of that hand
-    fn select_best_5_for_hand(
-        indices: &[usize],
-        cards: &[&CardData],
-        all_cards: &[PositionedCard],
-        hand: PokerHand
-    ) -> Vec<usize> {
-        // Return early if we have exactly 5 or fewer cards
-        if indices.len() <= 5 {
-            return indices.to_vec();
-        }
-
-        // Use hand-specific selection logic
-        match hand {
-            PokerHand::FullHouse => Self::select_best_full_house(indices, cards, all_cards),
-            PokerHand::FourOfAKind => Self::select_best_four_of_a_kind(indices, cards, all_cards),
-            PokerHand::ThreeOfAKind => Self::select_best_three_of_a_kind(indices, cards, all_cards),
-            PokerHand::TwoPair => Self::select_best_two_pair(indices, cards, all_cards),
-            PokerHand::OnePair => Self::select_best_one_pair(indices, cards, all_cards),
-            PokerHand::Flush => Self::select_best_flush(indices, cards, all_cards),
-            PokerHand::Straight => Self::select_best_straight(indices, cards, all_cards),
-            PokerHand::StraightFlush => Self::select_best_straight_flush(indices, cards, all_cards),
-            PokerHand::RoyalFlush => Self::select_best_royal_flush(indices, cards, all_cards),
-            _ => Self::select_best_generic(indices, all_cards),
-        }
-    }
-
-    /// Generic selection - just pick highest value cards with deterministic tiebreaking
-    fn select_best_generic(indices: &[usize], all_cards: &[PositionedCard]) -> Vec<usize> {
-        let mut sortable: Vec<(usize, u8, i32, i32)> = indices
-            .iter()
-            .filter_map(|&idx| {
-                all_cards.iter()
-                    .find(|c| c.index == idx)
-                    .map(|c| (idx, c.card.value, c.x, c.y))
-            })
-            .collect();
-
-        sortable.sort_by(|a, b| {
-            b.1.cmp(&a.1)
-                .then_with(|| a.2.cmp(&b.2))
-                .then_with(|| a.3.cmp(&b.3))
-        });
-
-        sortable.iter().take(5).map(|&(idx, _, _, _)| idx).collect()
-    }
-
-    /// Select best Full House (best three-of-a-kind + best pair)
-    fn select_best_full_house(indices: &[usize], _cards: &[&CardData], all_cards: &[PositionedCard]) -> Vec<usize> {
-        // Group cards by value
-        let mut by_value: HashMap<u8, Vec<usize>> = HashMap::new();
-        for &idx in indices {
-            if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                by_value.entry(card.card.value).or_insert_with(Vec::new).push(idx);
-            }
-        }
-
-        // Find best three-of-a-kind (highest value)
-        let mut trips: Vec<(u8, Vec<usize>)> = by_value.iter()
-            .filter(|(_, indices)| indices.len() >= 3)
-            .map(|(&val, indices)| (val, indices.clone()))
-            .collect();
-        trips.sort_by(|a, b| b.0.cmp(&a.0));
-
-        // Find best pair (highest value, excluding the trips)
-        let mut pairs: Vec<(u8, Vec<usize>)> = by_value.iter()
-            .filter(|(_, indices)| indices.len() >= 2)
-            .map(|(&val, indices)| (val, indices.clone()))
-            .collect();
-        pairs.sort_by(|a, b| b.0.cmp(&a.0));
-
-        if let Some((trips_val, trips_indices)) = trips.first() {
-            if let Some((pair_val, pair_indices)) = pairs.iter().find(|(val, _)| val != trips_val) {
-                let mut result = Vec::new();
-                result.extend(Self::pick_n_deterministic(&trips_indices, 3, all_cards));
-                result.extend(Self::pick_n_deterministic(&pair_indices, 2, all_cards));
-                return result;
-            }
-        }
-
-        // Fallback to generic selection
-        Self::select_best_generic(indices, all_cards)
-    }
-
-    /// Select best Four of a Kind (best four + highest kicker)
-    fn select_best_four_of_a_kind(indices: &[usize], _cards: &[&CardData], all_cards: &[PositionedCard]) -> Vec<usize> {
-        let mut by_value: HashMap<u8, Vec<usize>> = HashMap::new();
-        for &idx in indices {
-            if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                by_value.entry(card.card.value).or_insert_with(Vec::new).push(idx);
-            }
-        }
-
-        // Find best four-of-a-kind
-        let mut quads: Vec<(u8, Vec<usize>)> = by_value.iter()
-            .filter(|(_, indices)| indices.len() >= 4)
-            .map(|(&val, indices)| (val, indices.clone()))
-            .collect();
-        quads.sort_by(|a, b| b.0.cmp(&a.0));
-
-        if let Some((quad_val, quad_indices)) = quads.first() {
-            let mut result = Self::pick_n_deterministic(&quad_indices, 4, all_cards);
-
-            // Find highest kicker
-            let kicker_indices: Vec<usize> = indices.iter()
-                .filter(|&&idx| {
-                    if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                        card.card.value != *quad_val
-                    } else {
-                        false
-                    }
-                })
-                .copied()
-                .collect();
-
-            if !kicker_indices.is_empty() {
-                result.extend(Self::pick_n_deterministic(&kicker_indices, 1, all_cards));
-            }
-            return result;
-        }
-
-        Self::select_best_generic(indices, all_cards)
-    }
-
-    /// Select best Three of a Kind (best three + two highest kickers)
-    fn select_best_three_of_a_kind(indices: &[usize], _cards: &[&CardData], all_cards: &[PositionedCard]) -> Vec<usize> {
-        let mut by_value: HashMap<u8, Vec<usize>> = HashMap::new();
-        for &idx in indices {
-            if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                by_value.entry(card.card.value).or_insert_with(Vec::new).push(idx);
-            }
-        }
-
-        let mut trips: Vec<(u8, Vec<usize>)> = by_value.iter()
-            .filter(|(_, indices)| indices.len() >= 3)
-            .map(|(&val, indices)| (val, indices.clone()))
-            .collect();
-        trips.sort_by(|a, b| b.0.cmp(&a.0));
-
-        if let Some((trips_val, trips_indices)) = trips.first() {
-            let mut result = Self::pick_n_deterministic(&trips_indices, 3, all_cards);
-
-            let kicker_indices: Vec<usize> = indices.iter()
-                .filter(|&&idx| {
-                    if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                        card.card.value != *trips_val
-                    } else {
-                        false
-                    }
-                })
-                .copied()
-                .collect();
-
-            result.extend(Self::pick_n_deterministic(&kicker_indices, 2, all_cards));
-            return result;
-        }
-
-        Self::select_best_generic(indices, all_cards)
-    }
-
-    /// Select best Two Pair (two highest pairs + highest kicker)
-    fn select_best_two_pair(indices: &[usize], _cards: &[&CardData], all_cards: &[PositionedCard]) -> Vec<usize> {
-        let mut by_value: HashMap<u8, Vec<usize>> = HashMap::new();
-        for &idx in indices {
-            if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                by_value.entry(card.card.value).or_insert_with(Vec::new).push(idx);
-            }
-        }
-
-        let mut pairs: Vec<(u8, Vec<usize>)> = by_value.iter()
-            .filter(|(_, indices)| indices.len() >= 2)
-            .map(|(&val, indices)| (val, indices.clone()))
-            .collect();
-        pairs.sort_by(|a, b| b.0.cmp(&a.0));
-
-        if pairs.len() >= 2 {
-            let mut result = Vec::new();
-            result.extend(Self::pick_n_deterministic(&pairs[0].1, 2, all_cards));
-            result.extend(Self::pick_n_deterministic(&pairs[1].1, 2, all_cards));
-
-            let used_values = vec![pairs[0].0, pairs[1].0];
-            let kicker_indices: Vec<usize> = indices.iter()
-                .filter(|&&idx| {
-                    if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                        !used_values.contains(&card.card.value)
-                    } else {
-                        false
-                    }
-                })
-                .copied()
-                .collect();
-
-            result.extend(Self::pick_n_deterministic(&kicker_indices, 1, all_cards));
-            return result;
-        }
-
-        Self::select_best_generic(indices, all_cards)
-    }
-
-    /// Select best One Pair (highest pair + three highest kickers)
-    fn select_best_one_pair(indices: &[usize], _cards: &[&CardData], all_cards: &[PositionedCard]) -> Vec<usize> {
-        let mut by_value: HashMap<u8, Vec<usize>> = HashMap::new();
-        for &idx in indices {
-            if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                by_value.entry(card.card.value).or_insert_with(Vec::new).push(idx);
-            }
-        }
-
-        let mut pairs: Vec<(u8, Vec<usize>)> = by_value.iter()
-            .filter(|(_, indices)| indices.len() >= 2)
-            .map(|(&val, indices)| (val, indices.clone()))
-            .collect();
-        pairs.sort_by(|a, b| b.0.cmp(&a.0));
-
-        if let Some((pair_val, pair_indices)) = pairs.first() {
-            let mut result = Self::pick_n_deterministic(&pair_indices, 2, all_cards);
-
-            let kicker_indices: Vec<usize> = indices.iter()
-                .filter(|&&idx| {
-                    if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                        card.card.value != *pair_val
-                    } else {
-                        false
-                    }
-                })
-                .copied()
-                .collect();
-
-            result.extend(Self::pick_n_deterministic(&kicker_indices, 3, all_cards));
-            return result;
-        }
-
-        Self::select_best_generic(indices, all_cards)
-    }
-
-    /// Select best Flush (5 highest cards of same suit)
-    fn select_best_flush(indices: &[usize], _cards: &[&CardData], all_cards: &[PositionedCard]) -> Vec<usize> {
-        let mut by_suit: HashMap<u8, Vec<usize>> = HashMap::new();
-        for &idx in indices {
-            if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                by_suit.entry(card.card.suit).or_insert_with(Vec::new).push(idx);
-            }
-        }
-
-        // Find suit with 5+ cards
-        for (_, suit_indices) in by_suit.iter() {
-            if suit_indices.len() >= 5 {
-                return Self::pick_n_deterministic(suit_indices, 5, all_cards);
-            }
-        }
-
-        Self::select_best_generic(indices, all_cards)
-    }
-
-    /// Select best Straight (highest straight)
-    fn select_best_straight(indices: &[usize], _cards: &[&CardData], all_cards: &[PositionedCard]) -> Vec<usize> {
-        // Group cards by value
-        let mut by_value: HashMap<u8, Vec<usize>> = HashMap::new();
-        for &idx in indices {
-            if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                by_value.entry(card.card.value).or_insert_with(Vec::new).push(idx);
-            }
-        }
-
-        let mut values: Vec<u8> = by_value.keys().copied().collect();
-        values.sort_by(|a, b| b.cmp(a)); // Sort descending
-
-        // Check for 10-J-Q-K-A (Broadway / Ace high straight) - HIGHEST possible straight
-        if values.contains(&1) && values.contains(&10) && values.contains(&11)
-           && values.contains(&12) && values.contains(&13) {
-            let mut result = Vec::new();
-            for &val in &[1, 13, 12, 11, 10] { // Ace, King, Queen, Jack, 10
-                if let Some(card_indices) = by_value.get(&val) {
-                    result.extend(Self::pick_n_deterministic(card_indices, 1, all_cards));
-                }
-            }
-            return result;
-        }
-
-        // Try to find highest straight (standard consecutive)
-        for i in 0..=values.len().saturating_sub(5) {
-            let window = &values[i..i+5];
-            let mut is_straight = true;
-            for j in 0..4 {
-                if window[j] != window[j+1] + 1 {
-                    is_straight = false;
-                    break;
-                }
-            }
-            if is_straight {
-                let mut result = Vec::new();
-                for &val in window {
-                    if let Some(card_indices) = by_value.get(&val) {
-                        result.extend(Self::pick_n_deterministic(card_indices, 1, all_cards));
-                    }
-                }
-                return result;
-            }
-        }
-
-        // Check for A-2-3-4-5 (wheel / Ace low straight) - LOWEST straight
-        if values.contains(&1) && values.contains(&2) && values.contains(&3)
-           && values.contains(&4) && values.contains(&5) {
-            let mut result = Vec::new();
-            for &val in &[5, 4, 3, 2, 1] {
-                if let Some(card_indices) = by_value.get(&val) {
-                    result.extend(Self::pick_n_deterministic(card_indices, 1, all_cards));
-                }
-            }
-            return result;
-        }
-
-        Self::select_best_generic(indices, all_cards)
-    }
-
-    /// Select best Straight Flush
-    fn select_best_straight_flush(indices: &[usize], _cards: &[&CardData], all_cards: &[PositionedCard]) -> Vec<usize> {
-        // Group by suit first
-        let mut by_suit: HashMap<u8, Vec<usize>> = HashMap::new();
-        for &idx in indices {
-            if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                by_suit.entry(card.card.suit).or_insert_with(Vec::new).push(idx);
-            }
-        }
-
-        // For each suit with 5+ cards, try to find a straight
-        for (_, suit_indices) in by_suit.iter() {
-            if suit_indices.len() >= 5 {
-                // Collect cards for this suit
-                let suit_cards: Vec<&CardData> = suit_indices.iter()
-                    .filter_map(|&idx| all_cards.iter().find(|c| c.index == idx).map(|c| &c.card))
-                    .collect();
-
-                // Use straight selection logic on this suit
-                return Self::select_best_straight(suit_indices, &suit_cards, all_cards);
-            }
-        }
-
-        Self::select_best_generic(indices, all_cards)
-    }
-
-    /// Select best Royal Flush
-    fn select_best_royal_flush(indices: &[usize], _cards: &[&CardData], all_cards: &[PositionedCard]) -> Vec<usize> {
-        let royal_values = vec![1, 10, 11, 12, 13]; // A, 10, J, Q, K
-
-        let mut by_suit: HashMap<u8, HashMap<u8, Vec<usize>>> = HashMap::new();
-        for &idx in indices {
-            if let Some(card) = all_cards.iter().find(|c| c.index == idx) {
-                by_suit.entry(card.card.suit)
-                    .or_insert_with(HashMap::new)
-                    .entry(card.card.value)
-                    .or_insert_with(Vec::new)
-                    .push(idx);
-            }
-        }
-
-        // Find suit that has all royal values
-        for (_, values_map) in by_suit.iter() {
-            let has_all_royal = royal_values.iter().all(|&val| values_map.contains_key(&val));
-            if has_all_royal {
-                let mut result = Vec::new();
-                for &val in &royal_values {
-                    if let Some(card_indices) = values_map.get(&val) {
-                        result.extend(Self::pick_n_deterministic(card_indices, 1, all_cards));
-                    }
-                }
-                return result;
-            }
-        }
-
-        Self::select_best_generic(indices, all_cards)
-    }
-
-    /// Pick N cards deterministically from a set of indices
-    /// Sorts by value (desc), then position for tiebreaking
-    fn pick_n_deterministic(indices: &[usize], n: usize, all_cards: &[PositionedCard]) -> Vec<usize> {
-        let mut sortable: Vec<(usize, u8, i32, i32)> = indices
-            .iter()
-            .filter_map(|&idx| {
-                all_cards.iter()
-                    .find(|c| c.index == idx)
-                    .map(|c| (idx, c.card.value, c.x, c.y))
-            })
-            .collect();
-
-        sortable.sort_by(|a, b| {
-            b.1.cmp(&a.1)
-                .then_with(|| a.2.cmp(&b.2))
-                .then_with(|| a.3.cmp(&b.3))
-        });
-
-        sortable.iter().take(n).map(|&(idx, _, _, _)| idx).collect()
-    }
-
-    /// Helper to build ComboResult from original indices
-    /// Looks up positions and cards using the original indices
-    /// Includes both combo cards and wildcards in the result
-    fn build_combo_result(
-        hand: PokerHand,
-        combo_card_indices: Vec<usize>,
-        wildcard_indices: &[usize],
-        all_cards: &[PositionedCard]
-    ) -> ComboResult {
-        // Combine combo cards and wildcards into one list
-        let mut all_indices = combo_card_indices;
-        all_indices.extend_from_slice(wildcard_indices);
-
-        let positions: Vec<(i32, i32)> = all_indices.iter()
-            .filter_map(|&orig_idx| all_cards.iter().find(|c| c.index == orig_idx))
-            .map(|c| (c.x, c.y))
-            .collect();
-
-        let cards: Vec<&CardData> = all_indices.iter()
-            .filter_map(|&orig_idx| all_cards.iter().find(|c| c.index == orig_idx))
-            .map(|c| &c.card)
-            .collect();
-
-        ComboResult::with_resources(hand, all_indices, positions, &cards)
-    }
-
-    /// Check a group of adjacent cards for poker combos (skipping jokers)
-    fn check_group_for_combos(group: &[usize], all_cards: &[PositionedCard]) -> Option<ComboResult> {
-        // Extract standard cards from group (skip jokers/custom cards for combo detection)
-        // Keep track of ORIGINAL indices (from GDScript input) for both standard and wildcard cards
-        let mut standard_card_original_indices = Vec::new();
-        let mut wildcard_original_indices = Vec::new();
-        let mut group_cards = Vec::new();
-
-        for &local_idx in group.iter() {
-            let positioned_card = &all_cards[local_idx];
-            let card = &positioned_card.card;
-            if !card.is_custom {
-                // Use the ORIGINAL index from GDScript input, not the local array index
-                standard_card_original_indices.push(positioned_card.index);
-                group_cards.push(card);
-            } else {
-                // Track wildcard indices to include in final result (so they get cleared too)
-                wildcard_original_indices.push(positioned_card.index);
-            }
-        }
-
-        // Need at least 2 cards for any combo (pairs)
-        if group_cards.len() < 2 {
-            return None;
-        }
-
-        // Check for hands (from strongest to weakest)
-        // For hands that require exactly 5 cards, limit to best 5
-        if let Some(_) = Self::check_royal_flush(&group_cards) {
-            let best_5 = Self::select_best_5_for_hand(&standard_card_original_indices, &group_cards, all_cards, PokerHand::RoyalFlush);
-            return Some(Self::build_combo_result(PokerHand::RoyalFlush, best_5, &wildcard_original_indices, all_cards));
-        }
-
-        if let Some(_) = Self::check_straight_flush(&group_cards) {
-            let best_5 = Self::select_best_5_for_hand(&standard_card_original_indices, &group_cards, all_cards, PokerHand::StraightFlush);
-            return Some(Self::build_combo_result(PokerHand::StraightFlush, best_5, &wildcard_original_indices, all_cards));
-        }
-
-        if let Some(_) = Self::check_four_of_a_kind(&group_cards) {
-            let best_5 = Self::select_best_5_for_hand(&standard_card_original_indices, &group_cards, all_cards, PokerHand::FourOfAKind);
-            return Some(Self::build_combo_result(PokerHand::FourOfAKind, best_5, &wildcard_original_indices, all_cards));
-        }
-
-        if let Some(_) = Self::check_full_house(&group_cards) {
-            let best_5 = Self::select_best_5_for_hand(&standard_card_original_indices, &group_cards, all_cards, PokerHand::FullHouse);
-            return Some(Self::build_combo_result(PokerHand::FullHouse, best_5, &wildcard_original_indices, all_cards));
-        }
-
-        if let Some(_) = Self::check_flush(&group_cards) {
-            let best_5 = Self::select_best_5_for_hand(&standard_card_original_indices, &group_cards, all_cards, PokerHand::Flush);
-            return Some(Self::build_combo_result(PokerHand::Flush, best_5, &wildcard_original_indices, all_cards));
-        }
-
-        if let Some(_) = Self::check_straight(&group_cards) {
-            let best_5 = Self::select_best_5_for_hand(&standard_card_original_indices, &group_cards, all_cards, PokerHand::Straight);
-            return Some(Self::build_combo_result(PokerHand::Straight, best_5, &wildcard_original_indices, all_cards));
-        }
-
-        if let Some(_) = Self::check_three_of_a_kind(&group_cards) {
-            let best_5 = Self::select_best_5_for_hand(&standard_card_original_indices, &group_cards, all_cards, PokerHand::ThreeOfAKind);
-            return Some(Self::build_combo_result(PokerHand::ThreeOfAKind, best_5, &wildcard_original_indices, all_cards));
-        }
-
-        if let Some(_) = Self::check_two_pair(&group_cards) {
-            let best_5 = Self::select_best_5_for_hand(&standard_card_original_indices, &group_cards, all_cards, PokerHand::TwoPair);
-            return Some(Self::build_combo_result(PokerHand::TwoPair, best_5, &wildcard_original_indices, all_cards));
-        }
-
-        if let Some(_) = Self::check_one_pair(&group_cards) {
-            let best_5 = Self::select_best_5_for_hand(&standard_card_original_indices, &group_cards, all_cards, PokerHand::OnePair);
-            return Some(Self::build_combo_result(PokerHand::OnePair, best_5, &wildcard_original_indices, all_cards));
-        }
-
-        None
-    }
-
-    /// Count cards by value
-    fn count_by_value(cards: &[&CardData]) -> HashMap<u8, usize> {
-        let mut counts = HashMap::new();
-        for card in cards {
-            *counts.entry(card.value).or_insert(0) += 1;
-        }
-        counts
-    }
-
-    /// Count cards by suit
-    fn count_by_suit(cards: &[&CardData]) -> HashMap<u8, usize> {
-        let mut counts = HashMap::new();
-        for card in cards {
-            *counts.entry(card.suit).or_insert(0) += 1;
-        }
-        counts
-    }
-
-    // Poker hand checking functions (similar to before but simplified)
-
-    fn check_royal_flush(cards: &[&CardData]) -> Option<()> {
-        let by_suit = Self::count_by_suit(cards);
-        for (_suit, count) in by_suit.iter() {
-            if *count >= 5 {
-                let values: Vec<u8> = cards.iter().map(|c| c.value).collect();
-                if values.contains(&10) && values.contains(&11) && values.contains(&12)
-                   && values.contains(&13) && values.contains(&1) {
-                    return Some(());
-                }
-            }
-        }
-        None
-    }
-
-    fn check_straight_flush(cards: &[&CardData]) -> Option<()> {
-        if Self::check_flush(cards).is_some() && Self::check_straight(cards).is_some() {
-            return Some(());
-        }
-        None
-    }
-
-    fn check_four_of_a_kind(cards: &[&CardData]) -> Option<()> {
-        let by_value = Self::count_by_value(cards);
-        for (_value, count) in by_value.iter() {
-            if *count == 4 {
-                return Some(());
-            }
-        }
-        None
-    }
-
-    fn check_full_house(cards: &[&CardData]) -> Option<()> {
-        let by_value = Self::count_by_value(cards);
-        let mut has_three = false;
-        let mut has_pair = false;
-
-        for (_value, count) in by_value.iter() {
-            if *count == 3 {
-                has_three = true;
-            } else if *count == 2 {
-                has_pair = true;
-            }
-        }
-
-        if has_three && has_pair {
-            Some(())
-        } else {
-            None
-        }
-    }
-
-    fn check_flush(cards: &[&CardData]) -> Option<()> {
-        let by_suit = Self::count_by_suit(cards);
-        for (_suit, count) in by_suit.iter() {
-            if *count >= 5 {
-                return Some(());
-            }
-        }
-        None
-    }
-
-    fn check_straight(cards: &[&CardData]) -> Option<()> {
-        if cards.len() < 5 {
-            return None;
-        }
-
-        let mut values: Vec<u8> = cards.iter().map(|c| c.value).collect();
-        values.sort();
-        values.dedup();
-
-        if values.len() < 5 {
-            return None;
-        }
-
-        // Check for consecutive values
-        for window in values.windows(5) {
-            let mut is_consecutive = true;
-            for i in 0..4 {
-                if window[i + 1] != window[i] + 1 {
-                    is_consecutive = false;
-                    break;
-                }
-            }
-            if is_consecutive {
-                return Some(());
-            }
-        }
-
-        // Check for A-2-3-4-5 (Ace low straight / wheel)
-        if values.contains(&1) && values.contains(&2) && values.contains(&3)
-           && values.contains(&4) && values.contains(&5) {
-            return Some(());
-        }
-
-        // Check for 10-J-Q-K-A (Ace high straight / Broadway)
-        if values.contains(&1) && values.contains(&10) && values.contains(&11)
-           && values.contains(&12) && values.contains(&13) {
-            return Some(());
-        }
-
-        None
-    }
-
-    fn check_three_of_a_kind(cards: &[&CardData]) -> Option<()> {
-        let by_value = Self::count_by_value(cards);
-        for (_value, count) in by_value.iter() {
-            if *count == 3 {
-                return Some(());
-            }
-        }
-        None
-    }
-
-    fn check_two_pair(cards: &[&CardData]) -> Option<()> {
-        let by_value = Self::count_by_value(cards);
-        let pairs = by_value.values().filter(|&&count| count == 2).count();
-        if pairs >= 2 {
-            Some(())
-        } else {
-            None
-        }
-    }
-
-    fn check_one_pair(cards: &[&CardData]) -> Option<()> {
-        let by_value = Self::count_by_value(cards);
-        for (_value, count) in by_value.iter() {
-            if *count == 2 {
-                return Some(());
-            }
-        }
-        None
-    }
-}
+pub use godo_sim::card_combo::{ComboDetector, ComboResult, PokerHand, PositionedCard, ResourceBonus};
 
 use std::sync::{Arc, Mutex};
 use std::sync::mpsc::{channel, Sender, Receiver};
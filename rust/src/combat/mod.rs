@@ -4,4 +4,4 @@
 
 pub mod range_calculator;
 
-pub use range_calculator::{hex_distance, is_in_range};
+pub use range_calculator::{hex_distance, is_in_range, sightline};
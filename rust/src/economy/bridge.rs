@@ -1,9 +1,12 @@
 use godot::prelude::*;
 use godot::classes::{Node, INode};
 use super::resource_ledger;
+use godo_sim::resource_registry;
+use godo_sim::types::DecayRule;
 
 /// Godot-Rust bridge for ResourceLedger
-/// Connects to GameTimer signals and emits resource changes back to GDScript
+/// Synchronous reads of the local player's pool; every write is a request to the GameActor,
+/// whose changes come back through UnifiedEventBridge.resource_changed
 #[derive(GodotClass)]
 #[class(base=Node)]
 pub struct ResourceLedgerBridge {
@@ -14,46 +17,28 @@ pub struct ResourceLedgerBridge {
 #[godot_api]
 impl INode for ResourceLedgerBridge {
     fn init(base: Base<Node>) -> Self {
-        // Initialize Rust ledger (starts worker thread)
-        resource_ledger::initialize();
-
         Self { base }
     }
+}
 
-    fn ready(&mut self) {
-        // NOTE: Signal emissions disabled - GameActor via UnifiedEventBridge is the source of truth
-        // This bridge is now only used as a CACHE for synchronous queries from GDScript
-        // Initial resource values are emitted by GameActor on startup
-    }
-
-    fn process(&mut self, _delta: f64) {
-        // NOTE: Signal emissions disabled - GameActor via UnifiedEventBridge is the source of truth
-        // Still drain tick results to prevent queue buildup, but don't emit signals
-        while let Some(_result) = resource_ledger::get_tick_result() {
-            // Discard tick results - GameActor handles all resource change events
-        }
-    }
+/// Parse a {resource_type: int -> amount: float} Dictionary
+fn parse_cost(cost_dict: &Dictionary) -> Vec<(resource_ledger::ResourceType, f64)> {
+    cost_dict
+        .iter_shared()
+        .filter_map(|(k, v)| {
+            let resource_type = k.try_to::<i32>().ok()?;
+            let amount = v.try_to::<f64>().ok()?;
+            let rt = resource_ledger::ResourceType::from_i32(resource_type)?;
+            Some((rt, amount))
+        })
+        .collect()
 }
 
 #[godot_api]
 impl ResourceLedgerBridge {
-    /// Signal emitted when a resource changes
-    /// Args: resource_type (int), current (float), cap (float), rate (float)
-    #[signal]
-    fn resource_changed(resource_type: i32, current: f32, cap: f32, rate: f32);
-
-    /// Called by GameTimer every second (via signal connection)
-    /// Queues a tick request for the worker thread (non-blocking)
-    #[func]
-    fn on_timer_tick(&mut self, _time_left: i32) {
-        // Queue tick request for worker thread (1 second delta)
-        resource_ledger::request_tick(1.0);
-        // Results will be processed in process() and signals emitted there
-    }
-
     /// Get current amount of a resource
     #[func]
-    fn get_current(&self, resource_type: i32) -> f32 {
+    fn get_current(&self, resource_type: i32) -> f64 {
         if let Some(rt) = resource_ledger::ResourceType::from_i32(resource_type) {
             resource_ledger::get_current(rt)
         } else {
@@ -63,7 +48,7 @@ impl ResourceLedgerBridge {
 
     /// Get cap of a resource
     #[func]
-    fn get_cap(&self, resource_type: i32) -> f32 {
+    fn get_cap(&self, resource_type: i32) -> f64 {
         if let Some(rt) = resource_ledger::ResourceType::from_i32(resource_type) {
             resource_ledger::get_cap(rt)
         } else {
@@ -73,7 +58,7 @@ impl ResourceLedgerBridge {
 
     /// Get net rate of a resource
     #[func]
-    fn get_rate(&self, resource_type: i32) -> f32 {
+    fn get_rate(&self, resource_type: i32) -> f64 {
         if let Some(rt) = resource_ledger::ResourceType::from_i32(resource_type) {
             resource_ledger::get_rate(rt)
         } else {
@@ -83,51 +68,25 @@ impl ResourceLedgerBridge {
 
     /// Set the cap for a resource
     #[func]
-    fn set_cap(&mut self, resource_type: i32, cap: f32) {
+    fn set_cap(&mut self, resource_type: i32, cap: f64) {
         if let Some(rt) = resource_ledger::ResourceType::from_i32(resource_type) {
             resource_ledger::set_cap(rt, cap);
-
-            // Emit change signal
-            let current = resource_ledger::get_current(rt);
-            let rate = resource_ledger::get_rate(rt);
-            self.base_mut().emit_signal(
-                "resource_changed",
-                &[resource_type.to_variant(), current.to_variant(), cap.to_variant(), rate.to_variant()],
-            );
         }
     }
 
     /// Set current amount (clamped to [0, cap])
     #[func]
-    fn set_current(&mut self, resource_type: i32, amount: f32) {
+    fn set_current(&mut self, resource_type: i32, amount: f64) {
         if let Some(rt) = resource_ledger::ResourceType::from_i32(resource_type) {
             resource_ledger::set_current(rt, amount);
-
-            // Emit change signal
-            let current = resource_ledger::get_current(rt);
-            let cap = resource_ledger::get_cap(rt);
-            let rate = resource_ledger::get_rate(rt);
-            self.base_mut().emit_signal(
-                "resource_changed",
-                &[resource_type.to_variant(), current.to_variant(), cap.to_variant(), rate.to_variant()],
-            );
         }
     }
 
-    /// Add to current amount (clamped to [0, cap])
+    /// Add to current amount (clamped to cap; a negative amount the pool can't cover is rejected)
     #[func]
-    fn add(&mut self, resource_type: i32, amount: f32) {
+    fn add(&mut self, resource_type: i32, amount: f64) {
         if let Some(rt) = resource_ledger::ResourceType::from_i32(resource_type) {
             resource_ledger::add(rt, amount);
-
-            // Emit change signal
-            let current = resource_ledger::get_current(rt);
-            let cap = resource_ledger::get_cap(rt);
-            let rate = resource_ledger::get_rate(rt);
-            self.base_mut().emit_signal(
-                "resource_changed",
-                &[resource_type.to_variant(), current.to_variant(), cap.to_variant(), rate.to_variant()],
-            );
         }
     }
 
@@ -135,120 +94,107 @@ impl ResourceLedgerBridge {
     /// cost_dict: Dictionary of {resource_type: int -> amount: float}
     #[func]
     fn can_spend(&self, cost_dict: Dictionary) -> bool {
-        let cost: Vec<(resource_ledger::ResourceType, f32)> = cost_dict
-            .iter_shared()
-            .filter_map(|(k, v)| {
-                let resource_type = k.try_to::<i32>().ok()?;
-                let amount = v.try_to::<f32>().ok()?;
-                let rt = resource_ledger::ResourceType::from_i32(resource_type)?;
-                Some((rt, amount))
-            })
-            .collect();
-
-        resource_ledger::can_spend(&cost)
+        resource_ledger::can_spend(&parse_cost(&cost_dict))
     }
 
     /// Spend resources (returns false if not enough)
     /// cost_dict: Dictionary of {resource_type: int -> amount: float}
     #[func]
     fn spend(&mut self, cost_dict: Dictionary) -> bool {
-        let cost: Vec<(resource_ledger::ResourceType, f32)> = cost_dict
-            .iter_shared()
-            .filter_map(|(k, v)| {
-                let resource_type = k.try_to::<i32>().ok()?;
-                let amount = v.try_to::<f32>().ok()?;
-                let rt = resource_ledger::ResourceType::from_i32(resource_type)?;
-                Some((rt, amount))
-            })
-            .collect();
-
-        godot_print!("ResourceLedger.spend() called with cost: {:?}", cost);
-        let success = resource_ledger::spend(&cost);
-        godot_print!("ResourceLedger.spend() success: {}", success);
-
-        if success {
-            // Emit change signals for all spent resources
-            for (rt, _) in cost {
-                let current = resource_ledger::get_current(rt);
-                let cap = resource_ledger::get_cap(rt);
-                let rate = resource_ledger::get_rate(rt);
-                self.base_mut().emit_signal(
-                    "resource_changed",
-                    &[(rt as i32).to_variant(), current.to_variant(), cap.to_variant(), rate.to_variant()],
-                );
-            }
-        }
-
-        success
+        resource_ledger::spend(&parse_cost(&cost_dict))
     }
 
     /// Register a producer
     #[func]
-    fn register_producer(&mut self, ulid: PackedByteArray, resource_type: i32, rate_per_sec: f32, active: bool) {
+    fn register_producer(&mut self, ulid: PackedByteArray, resource_type: i32, rate_per_sec: f64, active: bool) {
         if let Some(rt) = resource_ledger::ResourceType::from_i32(resource_type) {
-            let ulid_bytes: Vec<u8> = ulid.to_vec();
-            resource_ledger::register_producer(ulid_bytes, rt, rate_per_sec, active);
+            resource_ledger::register_producer(ulid.to_vec(), rt, rate_per_sec, active);
         }
     }
 
     /// Register a consumer
     #[func]
-    fn register_consumer(&mut self, ulid: PackedByteArray, resource_type: i32, rate_per_sec: f32, active: bool) {
+    fn register_consumer(&mut self, ulid: PackedByteArray, resource_type: i32, rate_per_sec: f64, active: bool) {
         if let Some(rt) = resource_ledger::ResourceType::from_i32(resource_type) {
-            let ulid_bytes: Vec<u8> = ulid.to_vec();
-            resource_ledger::register_consumer(ulid_bytes, rt, rate_per_sec, active);
+            resource_ledger::register_consumer(ulid.to_vec(), rt, rate_per_sec, active);
         }
     }
 
     /// Set producer active state
     #[func]
     fn set_producer_active(&mut self, ulid: PackedByteArray, active: bool) {
-        let ulid_bytes: Vec<u8> = ulid.to_vec();
-        resource_ledger::set_producer_active(&ulid_bytes, active);
+        resource_ledger::set_producer_active(&ulid.to_vec(), active);
     }
 
     /// Set consumer active state
     #[func]
     fn set_consumer_active(&mut self, ulid: PackedByteArray, active: bool) {
-        let ulid_bytes: Vec<u8> = ulid.to_vec();
-        resource_ledger::set_consumer_active(&ulid_bytes, active);
+        resource_ledger::set_consumer_active(&ulid.to_vec(), active);
     }
 
     /// Remove a producer
     #[func]
     fn remove_producer(&mut self, ulid: PackedByteArray) {
-        let ulid_bytes: Vec<u8> = ulid.to_vec();
-        resource_ledger::remove_producer(&ulid_bytes);
+        resource_ledger::remove_producer(&ulid.to_vec());
     }
 
     /// Remove a consumer
     #[func]
     fn remove_consumer(&mut self, ulid: PackedByteArray) {
-        let ulid_bytes: Vec<u8> = ulid.to_vec();
-        resource_ledger::remove_consumer(&ulid_bytes);
+        resource_ledger::remove_consumer(&ulid.to_vec());
     }
 
-    /// Reset all resources to their initial values (1000 each)
+    /// Reset all resources to their starting amounts and caps
     #[func]
     fn reset_resources(&mut self) {
         resource_ledger::reset_resources_to_default();
+    }
 
-        // Emit change signals for all resources
-        let resources = [
-            resource_ledger::ResourceType::Gold,
-            resource_ledger::ResourceType::Food,
-            resource_ledger::ResourceType::Labor,
-            resource_ledger::ResourceType::Faith,
-        ];
+    /// Resource types from the registry: Array of { id, key, name, default_amount, default_cap, decay, base_price, suit }
+    /// decay: { kind: "none" } | { kind: "spoil", rate, threshold } | { kind: "drift", rate, baseline };
+    /// suit is -1 for resources no card suit pays out in
+    #[func]
+    fn get_resource_types(&self) -> Array<Dictionary> {
+        let mut arr = Array::new();
+        for def in resource_registry::resources().iter() {
+            let mut dict = Dictionary::new();
+            dict.set("id", def.id);
+            dict.set("key", def.key.as_str());
+            dict.set("name", def.name.as_str());
+            dict.set("default_amount", def.default_amount);
+            dict.set("default_cap", def.default_cap);
+            let mut decay = Dictionary::new();
+            match def.decay {
+                DecayRule::None => decay.set("kind", "none"),
+                DecayRule::Spoil { rate, threshold } => {
+                    decay.set("kind", "spoil");
+                    decay.set("rate", rate);
+                    decay.set("threshold", threshold);
+                }
+                DecayRule::Drift { rate, baseline } => {
+                    decay.set("kind", "drift");
+                    decay.set("rate", rate);
+                    decay.set("baseline", baseline);
+                }
+            }
+            dict.set("decay", decay);
+            dict.set("base_price", def.base_price);
+            dict.set("suit", def.suit.map_or(-1, |suit| suit as i32));
+            arr.push(&dict);
+        }
+        arr
+    }
 
-        for rt in resources {
-            let current = resource_ledger::get_current(rt);
-            let cap = resource_ledger::get_cap(rt);
-            let rate = resource_ledger::get_rate(rt);
-            self.base_mut().emit_signal(
-                "resource_changed",
-                &[(rt as i32).to_variant(), current.to_variant(), cap.to_variant(), rate.to_variant()],
-            );
+    /// Replace the resource registry with a data file's contents (JSON, see resource_registry)
+    /// Call before the session starts; returns false (keeping the current registry) if the data is invalid
+    #[func]
+    fn load_resource_registry(&mut self, json: GString) -> bool {
+        match resource_registry::load_resources(&json.to_string()) {
+            Ok(()) => true,
+            Err(e) => {
+                godot_error!("ResourceLedgerBridge: {}", e);
+                false
+            }
         }
     }
 
@@ -279,27 +225,16 @@ impl ResourceLedgerBridge {
     /// Load from save data (Array of Dictionaries)
     #[func]
     fn load_save_data(&mut self, data_array: Array<Dictionary>) {
-        let data: Vec<(i32, f32, f32)> = data_array
+        let data: Vec<(i32, f64, f64)> = data_array
             .iter_shared()
             .filter_map(|dict| {
                 let resource_type = dict.get("type")?.try_to::<i32>().ok()?;
-                let current = dict.get("current")?.try_to::<f32>().ok()?;
-                let cap = dict.get("cap")?.try_to::<f32>().ok()?;
+                let current = dict.get("current")?.try_to::<f64>().ok()?;
+                let cap = dict.get("cap")?.try_to::<f64>().ok()?;
                 Some((resource_type, current, cap))
             })
             .collect();
 
         resource_ledger::load_save_data(&data);
-
-        // Emit signals for all loaded resources
-        for (resource_type, current, cap) in data {
-            if let Some(rt) = resource_ledger::ResourceType::from_i32(resource_type) {
-                let rate = resource_ledger::get_rate(rt);
-                self.base_mut().emit_signal(
-                    "resource_changed",
-                    &[resource_type.to_variant(), current.to_variant(), cap.to_variant(), rate.to_variant()],
-                );
-            }
-        }
     }
 }
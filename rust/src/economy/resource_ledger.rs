@@ -1,398 +1,226 @@
-use dashmap::DashMap;
-use once_cell::sync::Lazy;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::thread;
-use crossbeam_queue::SegQueue;
+// Resource ledger - synchronous view of the GameActor's economy
+// The Actor's economy system owns every pool, producer, consumer and cap; reads here come
+// from the pools it publishes (ACTOR_RESOURCES) and writes are queued as GameRequests,
+// so the legacy API and UnifiedEventBridge always see the same numbers
 
-/// Resource types (must match GDScript enum)
+use crate::events::actor::ACTOR_RESOURCES;
+use crate::events::bridge::{local_player, try_queue_request};
+use crate::events::types::{GameRequest, TransactionReason};
+use godo_sim::resource_registry::{resource_name, resources};
+
+/// A resource type defined in the resource registry (ids match the GDScript enums)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(i32)]
-pub enum ResourceType {
-    Gold = 0,
-    Food = 1,
-    Labor = 2,
-    Faith = 3,
-}
+pub struct ResourceType(i64);
 
 impl ResourceType {
+    /// None for ids the registry doesn't define
     pub fn from_i32(value: i32) -> Option<Self> {
-        match value {
-            0 => Some(ResourceType::Gold),
-            1 => Some(ResourceType::Food),
-            2 => Some(ResourceType::Labor),
-            3 => Some(ResourceType::Faith),
-            _ => None,
-        }
+        resources().get(value as i64).map(|def| ResourceType(def.id))
     }
-}
 
-/// Resource data for a single resource type
-#[derive(Debug, Clone)]
-pub struct ResourceData {
-    pub current: f32,
-    pub cap: f32,
-    pub rate: f32, // net production per second (can be negative)
+    /// Economy resource_type
+    pub fn id(self) -> i64 {
+        self.0
+    }
 }
 
-impl ResourceData {
-    pub fn new(initial: f32, cap: f32) -> Self {
-        Self {
-            current: initial,
-            cap,
-            rate: 0.0,
+/// Resource data for a single resource type (as last published by the Actor)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourceData {
+    pub current: f64,
+    pub cap: f64,
+    pub rate: f64, // net production per second (can be negative)
+}
+
+/// Queue a request for the Actor; false if the queue is full or the Actor is gone
+fn queue(request: GameRequest) -> bool {
+    match try_queue_request(request) {
+        Ok(()) => true,
+        Err(e) => {
+            godot::prelude::godot_warn!("ResourceLedger: request dropped ({})", e);
+            false
         }
     }
 }
 
-/// Producer data tracked by ULID
-#[derive(Debug, Clone)]
-pub struct ProducerData {
-    pub resource_type: ResourceType,
-    pub rate_per_sec: f32,
-    pub active: bool,
+/// Latest state of one resource in a player's pool (None until the Actor publishes it)
+pub fn get(player_ulid: &[u8], resource_type: ResourceType) -> Option<ResourceData> {
+    ACTOR_RESOURCES
+        .get(&(player_ulid.to_vec(), resource_type.id()))
+        .map(|entry| {
+            let (current, cap, rate) = *entry.value();
+            ResourceData { current, cap, rate }
+        })
 }
 
-/// Consumer data tracked by ULID
-#[derive(Debug, Clone)]
-pub struct ConsumerData {
-    pub resource_type: ResourceType,
-    pub rate_per_sec: f32,
-    pub active: bool,
+/// Every resource of a player's pool, sorted by type
+pub fn pool(player_ulid: &[u8]) -> Vec<(i64, ResourceData)> {
+    let mut resources: Vec<(i64, ResourceData)> = ACTOR_RESOURCES
+        .iter()
+        .filter(|entry| entry.key().0 == player_ulid)
+        .map(|entry| {
+            let (current, cap, rate) = *entry.value();
+            (entry.key().1, ResourceData { current, cap, rate })
+        })
+        .collect();
+    resources.sort_by_key(|(resource_type, _)| *resource_type);
+    resources
 }
 
-/// Global resource ledger (thread-safe)
-static RESOURCE_LEDGER: Lazy<Arc<DashMap<ResourceType, ResourceData>>> = Lazy::new(|| {
-    let ledger = DashMap::new();
-
-    // Initialize with default values
-    // All resources use f32::MAX for effectively unlimited caps
-    ledger.insert(ResourceType::Gold, ResourceData::new(1000.0, f32::MAX));
-    ledger.insert(ResourceType::Food, ResourceData::new(1000.0, f32::MAX));
-    ledger.insert(ResourceType::Labor, ResourceData::new(1000.0, f32::MAX));
-    ledger.insert(ResourceType::Faith, ResourceData::new(1000.0, f32::MAX));
-
-    Arc::new(ledger)
-});
-
-/// Global producers registry (ULID -> ProducerData)
-static PRODUCERS: Lazy<Arc<DashMap<Vec<u8>, ProducerData>>> =
-    Lazy::new(|| Arc::new(DashMap::new()));
-
-/// Global consumers registry (ULID -> ConsumerData)
-static CONSUMERS: Lazy<Arc<DashMap<Vec<u8>, ConsumerData>>> =
-    Lazy::new(|| Arc::new(DashMap::new()));
-
-/// Tick request structure
-#[derive(Debug, Clone)]
-pub struct TickRequest {
-    pub delta_s: f32,
+/// Get current amount of a resource in the local player's pool
+pub fn get_current(resource_type: ResourceType) -> f64 {
+    get(&local_player(), resource_type).map(|r| r.current).unwrap_or(0.0)
 }
 
-/// Tick result structure (resource changes)
-#[derive(Debug, Clone)]
-pub struct TickResult {
-    pub changes: Vec<(ResourceType, f32, f32)>, // (type, current, cap)
+/// Get cap of a resource in the local player's pool
+pub fn get_cap(resource_type: ResourceType) -> f64 {
+    get(&local_player(), resource_type).map(|r| r.cap).unwrap_or(0.0)
 }
 
-/// Tick request queue (GDScript -> Worker thread)
-static TICK_REQUESTS: Lazy<Arc<SegQueue<TickRequest>>> = Lazy::new(|| Arc::new(SegQueue::new()));
-
-/// Tick result queue (Worker thread -> GDScript)
-static TICK_RESULTS: Lazy<Arc<SegQueue<TickResult>>> = Lazy::new(|| Arc::new(SegQueue::new()));
-
-/// Worker thread running flag
-static WORKER_RUNNING: AtomicBool = AtomicBool::new(false);
-
-/// Initialize the resource ledger and start worker thread (called on startup)
-pub fn initialize() {
-    // Force initialization of lazy statics
-    let _ = &*RESOURCE_LEDGER;
-    let _ = &*PRODUCERS;
-    let _ = &*CONSUMERS;
-    let _ = &*TICK_REQUESTS;
-    let _ = &*TICK_RESULTS;
-
-    // Start worker thread if not already running
-    if !WORKER_RUNNING.swap(true, Ordering::Relaxed) {
-        thread::spawn(|| {
-            economy_worker_thread();
-        });
-    }
+/// Get net rate of a resource in the local player's pool
+pub fn get_rate(resource_type: ResourceType) -> f64 {
+    get(&local_player(), resource_type).map(|r| r.rate).unwrap_or(0.0)
 }
 
-/// Worker thread for economy ticks (runs in background)
-fn economy_worker_thread() {
-    loop {
-        if !WORKER_RUNNING.load(Ordering::Relaxed) {
-            break;
-        }
-
-        // Process tick requests
-        if let Some(request) = TICK_REQUESTS.pop() {
-            let changes = tick_internal(request.delta_s);
-            TICK_RESULTS.push(TickResult { changes });
-        } else {
-            // No requests, sleep briefly to avoid busy-waiting
-            thread::sleep(std::time::Duration::from_millis(10));
-        }
-    }
-}
-
-/// Shutdown worker thread
-pub fn shutdown() {
-    WORKER_RUNNING.store(false, Ordering::Relaxed);
+/// Set the cap for a resource (current is clamped to it)
+pub fn set_cap(resource_type: ResourceType, cap: f64) -> bool {
+    queue(GameRequest::SetResourceCap {
+        player_ulid: local_player(),
+        resource_type: resource_type.id(),
+        cap,
+    })
 }
 
-/// Get current amount of a resource
-pub fn get_current(resource_type: ResourceType) -> f32 {
-    RESOURCE_LEDGER
-        .get(&resource_type)
-        .map(|r| r.current)
-        .unwrap_or(0.0)
-}
-
-/// Get cap of a resource
-pub fn get_cap(resource_type: ResourceType) -> f32 {
-    RESOURCE_LEDGER
-        .get(&resource_type)
-        .map(|r| r.cap)
-        .unwrap_or(0.0)
+/// Set the current amount (clamped to [0, cap])
+pub fn set_current(resource_type: ResourceType, amount: f64) -> bool {
+    queue(GameRequest::SetResourceAmount {
+        player_ulid: local_player(),
+        resource_type: resource_type.id(),
+        amount,
+    })
 }
 
-/// Get net rate of a resource
-pub fn get_rate(resource_type: ResourceType) -> f32 {
-    RESOURCE_LEDGER
-        .get(&resource_type)
-        .map(|r| r.rate)
-        .unwrap_or(0.0)
+/// Add to current amount (negative amounts are spends and fail if the pool can't cover them)
+pub fn add(resource_type: ResourceType, amount: f64) -> bool {
+    apply(TransactionReason::Adjustment, &[(resource_type, amount)])
 }
 
-/// Set the cap for a resource
-pub fn set_cap(resource_type: ResourceType, cap: f32) {
-    if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&resource_type) {
-        entry.cap = cap.max(0.0);
-        // Clamp current to new cap
-        entry.current = entry.current.min(entry.cap);
+/// Credit/debit several resources of the local player's pool as one ledger transaction
+pub fn apply(reason: TransactionReason, changes: &[(ResourceType, f64)]) -> bool {
+    let changes: Vec<(i64, f64)> = changes
+        .iter()
+        .filter(|(_, amount)| *amount != 0.0)
+        .map(|(resource_type, amount)| (resource_type.id(), *amount))
+        .collect();
+    if changes.is_empty() {
+        return true;
     }
-}
 
-/// Set the current amount (clamped to [0, cap])
-pub fn set_current(resource_type: ResourceType, amount: f32) {
-    if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&resource_type) {
-        entry.current = amount.clamp(0.0, entry.cap);
-    }
+    queue(GameRequest::ApplyTransaction {
+        request_id: 0,
+        player_ulid: local_player(),
+        reason,
+        changes,
+    })
 }
 
-/// Add to current amount (clamped to [0, cap])
-pub fn add(resource_type: ResourceType, amount: f32) {
-    if amount == 0.0 {
-        return;
-    }
-
-    if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&resource_type) {
-        entry.current = (entry.current + amount).clamp(0.0, entry.cap);
-    }
+/// Check if we can spend a cost (multiple resources) from the last published amounts
+pub fn can_spend(cost: &[(ResourceType, f64)]) -> bool {
+    let player_ulid = local_player();
+    cost.iter().all(|(resource_type, amount)| {
+        get(&player_ulid, *resource_type).is_some_and(|r| r.current >= *amount)
+    })
 }
 
-/// Check if we can spend a cost (multiple resources)
-/// Cost is represented as Vec<(ResourceType, f32)>
-pub fn can_spend(cost: &[(ResourceType, f32)]) -> bool {
-    for (resource_type, amount) in cost {
-        if get_current(*resource_type) < *amount {
-            return false;
-        }
-    }
-    true
-}
-
-/// Spend resources (returns false if not enough)
-pub fn spend(cost: &[(ResourceType, f32)]) -> bool {
-    // Check first
+/// Spend resources (false if not enough as of the last published amounts)
+/// The Actor re-checks and rejects the spend if the pool changed in the meantime
+pub fn spend(cost: &[(ResourceType, f64)]) -> bool {
     if !can_spend(cost) {
-        println!("ResourceLedger: Cannot spend {:?} - insufficient resources", cost);
         return false;
     }
 
-    println!("ResourceLedger: Spending {:?}", cost);
-
-    // Spend atomically
-    for (resource_type, amount) in cost {
-        if let Some(mut entry) = RESOURCE_LEDGER.get_mut(resource_type) {
-            let old_value = entry.current;
-            entry.current -= amount;
-            println!("ResourceLedger: {:?} {} -> {}", resource_type, old_value, entry.current);
-        }
-    }
-
-    true
+    queue(GameRequest::SpendResources {
+        player_ulid: local_player(),
+        cost: cost.iter().map(|(resource_type, amount)| (resource_type.id(), *amount)).collect(),
+    })
 }
 
-/// Register a producer (returns ULID for future reference)
-pub fn register_producer(ulid: Vec<u8>, resource_type: ResourceType, rate_per_sec: f32, active: bool) {
-    PRODUCERS.insert(ulid, ProducerData {
-        resource_type,
+/// Register a producer for the local player's pool
+pub fn register_producer(ulid: Vec<u8>, resource_type: ResourceType, rate_per_sec: f64, active: bool) -> bool {
+    queue(GameRequest::RegisterProducer {
+        ulid,
+        player_ulid: local_player(),
+        resource_type: resource_type.id(),
         rate_per_sec,
         active,
-    });
+    })
 }
 
-/// Register a consumer (returns ULID for future reference)
-pub fn register_consumer(ulid: Vec<u8>, resource_type: ResourceType, rate_per_sec: f32, active: bool) {
-    CONSUMERS.insert(ulid, ConsumerData {
-        resource_type,
+/// Register a consumer for the local player's pool
+pub fn register_consumer(ulid: Vec<u8>, resource_type: ResourceType, rate_per_sec: f64, active: bool) -> bool {
+    queue(GameRequest::RegisterConsumer {
+        ulid,
+        player_ulid: local_player(),
+        resource_type: resource_type.id(),
         rate_per_sec,
         active,
-    });
+    })
 }
 
 /// Set producer active state
-pub fn set_producer_active(ulid: &[u8], active: bool) {
-    if let Some(mut producer) = PRODUCERS.get_mut(ulid) {
-        producer.active = active;
-    }
+pub fn set_producer_active(ulid: &[u8], active: bool) -> bool {
+    queue(GameRequest::SetProducerActive { ulid: ulid.to_vec(), active })
 }
 
 /// Set consumer active state
-pub fn set_consumer_active(ulid: &[u8], active: bool) {
-    if let Some(mut consumer) = CONSUMERS.get_mut(ulid) {
-        consumer.active = active;
-    }
+pub fn set_consumer_active(ulid: &[u8], active: bool) -> bool {
+    queue(GameRequest::SetConsumerActive { ulid: ulid.to_vec(), active })
 }
 
 /// Remove a producer
-pub fn remove_producer(ulid: &[u8]) {
-    PRODUCERS.remove(ulid);
+pub fn remove_producer(ulid: &[u8]) -> bool {
+    queue(GameRequest::RemoveProducer { ulid: ulid.to_vec() })
 }
 
 /// Remove a consumer
-pub fn remove_consumer(ulid: &[u8]) {
-    CONSUMERS.remove(ulid);
-}
-
-/// Recalculate net rates for all resources based on active producers/consumers
-pub fn recalculate_rates() {
-    // Reset all rates to 0
-    for mut entry in RESOURCE_LEDGER.iter_mut() {
-        entry.rate = 0.0;
-    }
-
-    // Sum active producers
-    for producer in PRODUCERS.iter() {
-        if producer.active {
-            if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&producer.resource_type) {
-                entry.rate += producer.rate_per_sec;
-            }
-        }
-    }
-
-    // Subtract active consumers
-    for consumer in CONSUMERS.iter() {
-        if consumer.active {
-            if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&consumer.resource_type) {
-                entry.rate -= consumer.rate_per_sec;
-            }
-        }
-    }
-}
-
-/// Internal tick function (runs on worker thread)
-/// Returns Vec<(ResourceType, current, cap)> for changed resources (to emit signals)
-fn tick_internal(delta_s: f32) -> Vec<(ResourceType, f32, f32)> {
-    let mut changed = Vec::new();
-
-    // Recalculate rates based on active producers/consumers
-    recalculate_rates();
-
-    // Apply rates to all resources
-    for mut entry in RESOURCE_LEDGER.iter_mut() {
-        if entry.rate != 0.0 {
-            let old_current = entry.current;
-            entry.current = (entry.current + entry.rate * delta_s).clamp(0.0, entry.cap);
-
-            // Track changes for signals
-            if entry.current != old_current {
-                changed.push((*entry.key(), entry.current, entry.cap));
-            }
-        }
-    }
-
-    changed
-}
-
-/// Request a tick (async - queues request for worker thread)
-pub fn request_tick(delta_s: f32) {
-    TICK_REQUESTS.push(TickRequest { delta_s });
-}
-
-/// Get tick result (non-blocking, called from main thread)
-pub fn get_tick_result() -> Option<TickResult> {
-    TICK_RESULTS.pop()
+pub fn remove_consumer(ulid: &[u8]) -> bool {
+    queue(GameRequest::RemoveConsumer { ulid: ulid.to_vec() })
 }
 
 /// Get statistics for debugging
 pub fn get_stats() -> String {
     let mut stats = String::from("=== Resource Ledger Stats ===\n");
 
-    for entry in RESOURCE_LEDGER.iter() {
+    for (resource_type, data) in pool(&local_player()) {
         stats.push_str(&format!(
-            "{:?}: {:.1}/{:.1} (rate: {:.2}/s)\n",
-            entry.key(), entry.current, entry.cap, entry.rate
+            "{}: {:.1}/{:.1} (rate: {:.2}/s)\n",
+            resource_name(resource_type), data.current, data.cap, data.rate
         ));
     }
 
-    stats.push_str(&format!("\nProducers: {}\n", PRODUCERS.len()));
-    stats.push_str(&format!("Consumers: {}\n", CONSUMERS.len()));
-
     stats
 }
 
-/// Save to dictionary format
-pub fn to_save_data() -> Vec<(i32, f32, f32)> {
-    RESOURCE_LEDGER
-        .iter()
-        .map(|entry| (*entry.key() as i32, entry.current, entry.cap))
+/// Save data for the local player's pool: (type, current, cap)
+pub fn to_save_data() -> Vec<(i32, f64, f64)> {
+    pool(&local_player())
+        .into_iter()
+        .map(|(resource_type, data)| (resource_type as i32, data.current, data.cap))
         .collect()
 }
 
-/// Load from save data
-pub fn load_save_data(data: &[(i32, f32, f32)]) {
+/// Load save data into the local player's pool (cap first so the amount isn't clamped to the old cap)
+pub fn load_save_data(data: &[(i32, f64, f64)]) {
     for (type_id, current, cap) in data {
         if let Some(resource_type) = ResourceType::from_i32(*type_id) {
-            if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&resource_type) {
-                entry.current = *current;
-                entry.cap = *cap;
-            }
+            set_cap(resource_type, *cap);
+            set_current(resource_type, *current);
         }
     }
 }
 
-/// Reset all resources to their initial values (1000 each)
-pub fn reset_resources_to_default() {
-    if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&ResourceType::Gold) {
-        entry.current = 1000.0;
-        entry.cap = f32::MAX;
-        entry.rate = 0.0;
-    }
-    if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&ResourceType::Food) {
-        entry.current = 1000.0;
-        entry.cap = f32::MAX;
-        entry.rate = 0.0;
-    }
-    if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&ResourceType::Labor) {
-        entry.current = 1000.0;
-        entry.cap = f32::MAX;
-        entry.rate = 0.0;
-    }
-    if let Some(mut entry) = RESOURCE_LEDGER.get_mut(&ResourceType::Faith) {
-        entry.current = 1000.0;
-        entry.cap = f32::MAX;
-        entry.rate = 0.0;
-    }
-}
-
-/// Clear all producers and consumers (useful for testing/reset)
-pub fn clear_producers_and_consumers() {
-    PRODUCERS.clear();
-    CONSUMERS.clear();
+/// Reset the local player's resources to their starting amounts and caps
+/// Producers and consumers are kept
+pub fn reset_resources_to_default() -> bool {
+    queue(GameRequest::ResetResources { player_ulid: local_player() })
 }
@@ -3,31 +3,314 @@
 
 use godot::prelude::*;
 use godot::classes::INode;
-use crossbeam_channel::{Sender, Receiver, unbounded};
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender, TrySendError};
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::sync::{Mutex};
+use parking_lot::RwLock;
+use std::thread::JoinHandle;
+use std::sync::atomic::{AtomicU64, Ordering};
 use once_cell::sync::Lazy;
 
-use super::actor::spawn_actor_thread;
-use super::types::{GameEvent, GameRequest};
+use super::actor::{spawn_actor_thread, REQUEST_QUEUE_CAPACITY};
+use super::types::{
+    ForecastHorizon, GameEvent, GameRequest, LedgerEntry, QueuePolicy, Recipe, RecipeReport, RecipeStatus, RequestId, ResourceForecast,
+    CombatProfile, ShortageConfig, ShortageEffect, SplashProfile, StatusKind, TargetingPolicy,
+    TransactionReason, TurnSummary,
+};
+use godo_sim::entity::ProjectileType;
+use godo_sim::metrics::{ActorMetrics, TimingStats};
 use crate::npc::terrain_cache::TerrainType;
 
 // Global channels (proper Actor pattern with crossbeam_channel)
 struct Channels {
     request_tx: Sender<GameRequest>,
     event_rx: Receiver<GameEvent>,
+    actor_thread: Mutex<Option<JoinHandle<()>>>,  // Taken by shutdown_actor()
 }
 
 static CHANNELS: Lazy<Channels> = Lazy::new(|| {
-    // Create channels
-    let (request_tx, request_rx) = unbounded::<GameRequest>();
+    // Create channels (requests are bounded; see send_request for the overflow policy)
+    let (request_tx, request_rx) = bounded::<GameRequest>(REQUEST_QUEUE_CAPACITY);
     let (event_tx, event_rx) = unbounded::<GameEvent>();
 
     // Spawn Actor thread with its channels
-    spawn_actor_thread(request_rx, event_tx);
+    let actor_thread = match spawn_actor_thread(request_rx, event_tx) {
+        Ok(handle) => Some(handle),
+        Err(e) => {
+            godot_error!("[UnifiedEventBridge] Failed to spawn game-actor thread: {}", e);
+            None
+        }
+    };
 
-    Channels { request_tx, event_rx }
+    Channels { request_tx, event_rx, actor_thread: Mutex::new(actor_thread) }
 });
 
+/// Timing stats as { count, last_ms, avg_ms, max_ms }
+fn timing_to_dictionary(timing: &TimingStats) -> Dictionary {
+    let mut dict = Dictionary::new();
+    dict.set("count", timing.count as i64);
+    dict.set("last_ms", timing.last_ms);
+    dict.set("avg_ms", timing.avg_ms);
+    dict.set("max_ms", timing.max_ms);
+    dict
+}
+
+/// Layout shared by get_metrics() and the metrics_reported signal
+fn metrics_to_dictionary(metrics: &ActorMetrics) -> Dictionary {
+    let mut phases = Dictionary::new();
+    for phase in &metrics.phases {
+        phases.set(GString::from(&phase.phase), timing_to_dictionary(&phase.timing));
+    }
+
+    let mut worker_queues = Dictionary::new();
+    for queue in &metrics.worker_queues {
+        let mut depths = Dictionary::new();
+        depths.set("pending_jobs", queue.pending_jobs as i64);
+        depths.set("pending_results", queue.pending_results as i64);
+        worker_queues.set(GString::from(&queue.worker), depths);
+    }
+
+    let mut dict = Dictionary::new();
+    dict.set("tick_count", metrics.tick_count as i64);
+    dict.set("tick", timing_to_dictionary(&metrics.tick));
+    dict.set("phases", phases);
+    dict.set("request_queue", metrics.request_queue as i64);
+    dict.set("event_queue", metrics.event_queue as i64);
+    dict.set("worker_queues", worker_queues);
+    dict.set("path_latency", timing_to_dictionary(&metrics.path_latency));
+    dict.set("path_backlog", metrics.path_backlog as i64);
+    dict.set("superseded_paths", metrics.superseded_paths as i64);
+    dict.set("coalesced_requests", metrics.coalesced_requests as i64);
+    dict
+}
+
+/// Ledger entry as { transaction_id, player_ulid, reason, reason_name, changes: { resource_type: amount }, reverts, rolled_back }
+/// reverts is -1 unless the entry is a rollback
+fn ledger_entry_to_dictionary(entry: &LedgerEntry) -> Dictionary {
+    let mut changes = Dictionary::new();
+    for (resource_type, amount) in &entry.changes {
+        changes.set(*resource_type, *amount);
+    }
+
+    let mut dict = Dictionary::new();
+    dict.set("transaction_id", entry.transaction_id as i64);
+    dict.set("player_ulid", PackedByteArray::from(&entry.player_ulid[..]));
+    dict.set("reason", entry.reason as i64);
+    dict.set("reason_name", GString::from(entry.reason.name()));
+    dict.set("changes", changes);
+    dict.set("reverts", entry.reverts.map_or(-1, |id| id as i64));
+    dict.set("rolled_back", entry.rolled_back);
+    dict
+}
+
+/// Resource forecast as { resource_type, current, cap, rate, upkeep_per_turn, decay_protection, projected, empty_at, full_at }
+/// empty_at/full_at are seconds from now, -1.0 when not reached within the horizon
+fn resource_forecast_to_dictionary(forecast: &ResourceForecast) -> Dictionary {
+    let mut dict = Dictionary::new();
+    dict.set("resource_type", forecast.resource_type);
+    dict.set("current", forecast.current);
+    dict.set("cap", forecast.cap);
+    dict.set("rate", forecast.rate);
+    dict.set("upkeep_per_turn", forecast.upkeep_per_turn);
+    dict.set("decay_protection", forecast.decay_protection);
+    dict.set("projected", forecast.projected);
+    dict.set("empty_at", forecast.empty_at.unwrap_or(-1.0));
+    dict.set("full_at", forecast.full_at.unwrap_or(-1.0));
+    dict
+}
+
+/// Shortage effect as { kind: "starvation", units, hp_loss, morale_loss } | { kind: "production_paused", producers }
+/// | { kind: "reputation_loss", amount }
+fn shortage_effect_to_dictionary(effect: &ShortageEffect) -> Dictionary {
+    let ulids = |ulids: &[Vec<u8>]| {
+        let mut list = VariantArray::new();
+        for ulid in ulids {
+            list.push(&PackedByteArray::from(&ulid[..]).to_variant());
+        }
+        list
+    };
+
+    let mut dict = Dictionary::new();
+    match effect {
+        ShortageEffect::Starvation { units, hp_loss, morale_loss } => {
+            dict.set("kind", "starvation");
+            dict.set("units", ulids(units));
+            dict.set("hp_loss", *hp_loss);
+            dict.set("morale_loss", *morale_loss);
+        }
+        ShortageEffect::ProductionPaused { producers } => {
+            dict.set("kind", "production_paused");
+            dict.set("producers", ulids(producers));
+        }
+        ShortageEffect::ReputationLoss { amount } => {
+            dict.set("kind", "reputation_loss");
+            dict.set("amount", *amount);
+        }
+    }
+    dict
+}
+
+/// Recipe status as { kind: "running" | "inactive" | "paused" } | { kind: "stalled", missing: { resource_type: amount } }
+fn recipe_status_to_dictionary(status: &RecipeStatus) -> Dictionary {
+    let mut dict = Dictionary::new();
+    match status {
+        RecipeStatus::Running => dict.set("kind", "running"),
+        RecipeStatus::Inactive => dict.set("kind", "inactive"),
+        RecipeStatus::Paused => dict.set("kind", "paused"),
+        RecipeStatus::Stalled { missing } => {
+            let mut amounts = Dictionary::new();
+            for (resource_type, amount) in missing {
+                amounts.set(*resource_type, *amount);
+            }
+            dict.set("kind", "stalled");
+            dict.set("missing", amounts);
+        }
+    }
+    dict
+}
+
+/// Recipe report as { ulid, inputs, outputs, cycle_secs, status, progress, cycles_completed }
+/// inputs/outputs: { resource_type: amount per cycle }; status: see recipe_status_to_dictionary
+fn recipe_report_to_dictionary(report: &RecipeReport) -> Dictionary {
+    let amounts = |list: &[(i64, f64)]| {
+        let mut dict = Dictionary::new();
+        for (resource_type, amount) in list {
+            dict.set(*resource_type, *amount);
+        }
+        dict
+    };
+
+    let mut dict = Dictionary::new();
+    dict.set("ulid", PackedByteArray::from(&report.ulid[..]));
+    dict.set("inputs", amounts(&report.recipe.inputs));
+    dict.set("outputs", amounts(&report.recipe.outputs));
+    dict.set("cycle_secs", report.recipe.cycle_secs);
+    dict.set("status", recipe_status_to_dictionary(&report.status));
+    dict.set("progress", report.progress);
+    dict.set("cycles_completed", report.cycles_completed as i64);
+    dict
+}
+
+/// { resource_type: amount } Dictionary as (resource_type, amount) pairs, skipping non-numeric entries
+fn resource_amounts(amounts: &Dictionary) -> Vec<(i64, f64)> {
+    amounts
+        .iter_shared()
+        .filter_map(|(key, value)| Some((key.try_to::<i64>().ok()?, value.try_to::<f64>().ok()?)))
+        .collect()
+}
+
+/// Turn summary as { turn, food_upkeep, production, decay, paths_resolved, attacks, damage_dealt, deaths, loot, combos }
+/// production, decay: Array of { player_ulid, resource_type, change };
+/// loot: Array of { ulid, entity_type, player_ulid, rewards: { reward: amount } };
+/// combos: Array of { player_ulid, hand_rank, hand_name, positions: Array of Vector2i, bonuses: { resource_type: amount } }
+fn turn_summary_to_dictionary(summary: &TurnSummary) -> Dictionary {
+    let changes = |list: &[(Vec<u8>, i64, f64)]| {
+        let mut changes = VariantArray::new();
+        for (player_ulid, resource_type, change) in list {
+            let mut entry = Dictionary::new();
+            entry.set("player_ulid", PackedByteArray::from(&player_ulid[..]));
+            entry.set("resource_type", *resource_type);
+            entry.set("change", *change);
+            changes.push(&entry.to_variant());
+        }
+        changes
+    };
+    let production = changes(&summary.production);
+    let decay = changes(&summary.decay);
+
+    let mut deaths = VariantArray::new();
+    for ulid in &summary.deaths {
+        deaths.push(&PackedByteArray::from(&ulid[..]).to_variant());
+    }
+
+    let mut loot = VariantArray::new();
+    for drop in &summary.loot {
+        let mut rewards = Dictionary::new();
+        for (reward, amount) in &drop.rewards {
+            rewards.set(GString::from(reward), *amount);
+        }
+        let mut entry = Dictionary::new();
+        entry.set("ulid", PackedByteArray::from(&drop.ulid[..]));
+        entry.set("entity_type", GString::from(&drop.entity_type));
+        entry.set("player_ulid", PackedByteArray::from(&drop.player_ulid[..]));
+        entry.set("rewards", rewards);
+        loot.push(&entry.to_variant());
+    }
+
+    let mut combos = VariantArray::new();
+    for combo in &summary.combos {
+        let mut positions = VariantArray::new();
+        for (x, y) in &combo.card_positions {
+            positions.push(&Vector2i::new(*x, *y).to_variant());
+        }
+        let mut bonuses = Dictionary::new();
+        for (resource_type, amount) in &combo.resource_bonuses {
+            bonuses.set(*resource_type, *amount);
+        }
+        let mut entry = Dictionary::new();
+        entry.set("player_ulid", PackedByteArray::from(&combo.player_ulid[..]));
+        entry.set("hand_rank", combo.hand_rank);
+        entry.set("hand_name", GString::from(&combo.hand_name));
+        entry.set("positions", positions);
+        entry.set("bonuses", bonuses);
+        combos.push(&entry.to_variant());
+    }
+
+    let mut dict = Dictionary::new();
+    dict.set("turn", summary.turn as i64);
+    dict.set("food_upkeep", summary.food_upkeep);
+    dict.set("production", production);
+    dict.set("decay", decay);
+    dict.set("paths_resolved", summary.paths_resolved as i64);
+    dict.set("attacks", summary.attacks as i64);
+    dict.set("damage_dealt", summary.damage_dealt);
+    dict.set("deaths", deaths);
+    dict.set("loot", loot);
+    dict.set("combos", combos);
+    dict
+}
+
+/// Stop the Actor and its workers and wait for them (extension deinit)
+/// No-op if no bridge ever started the Actor
+pub fn shutdown_actor() {
+    let Some(channels) = Lazy::get(&CHANNELS) else {
+        return;
+    };
+
+    let _ = channels.request_tx.send(GameRequest::Shutdown);
+    let handle = channels.actor_thread.lock().ok().and_then(|mut thread| thread.take());
+    if let Some(handle) = handle {
+        if handle.join().is_err() {
+            godot_error!("[UnifiedEventBridge] game-actor thread panicked during shutdown");
+        }
+    }
+}
+
+// Query ids are global so several bridge nodes never hand out the same id
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    // request_id -> callback of every query awaiting its reply (invalid callback = signal only)
+    // Global like the event channel, so the callback runs whichever bridge node drains the reply
+    // (bridge nodes and their Callables live on the main thread)
+    static PENDING_QUERIES: RefCell<HashMap<RequestId, Callable>> = RefCell::new(HashMap::new());
+}
+
+// Player whose pool the resource/card methods use and resource_changed reports
+// (empty = AI/neutral pool until the host sets its player); shared with ResourceLedgerBridge
+static LOCAL_PLAYER: Lazy<RwLock<Vec<u8>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+pub fn local_player() -> Vec<u8> {
+    LOCAL_PLAYER.read().clone()
+}
+
+/// Queue a request for the Actor without blocking (for FFI callers that aren't a bridge node)
+/// Err hands back the request when the queue is full or the Actor is gone
+pub fn try_queue_request(request: GameRequest) -> Result<(), TrySendError<GameRequest>> {
+    CHANNELS.request_tx.try_send(request)
+}
+
 #[derive(GodotClass)]
 #[class(base=Node)]
 pub struct UnifiedEventBridge {
@@ -40,7 +323,9 @@ impl INode for UnifiedEventBridge {
         // Initialize channels (Lazy will spawn Actor thread on first access)
         let _ = &*CHANNELS;
 
-        Self { base }
+        Self {
+            base,
+        }
     }
 
     fn ready(&mut self) {
@@ -90,9 +375,9 @@ impl UnifiedEventBridge {
     #[signal]
     fn combat_started(attacker: PackedByteArray, defender: PackedByteArray);
 
-    /// Emitted when damage is dealt
+    /// Emitted when damage is dealt (outcome: "normal", "critical", "glancing" or "dodged")
     #[signal]
-    fn damage_dealt(attacker: PackedByteArray, defender: PackedByteArray, damage: i32);
+    fn damage_dealt(attacker: PackedByteArray, defender: PackedByteArray, damage: i32, outcome: GString);
 
     /// Emitted when an entity dies
     #[signal]
@@ -103,6 +388,8 @@ impl UnifiedEventBridge {
     fn combat_ended(attacker: PackedByteArray, defender: PackedByteArray);
 
     /// Emitted when a projectile should be spawned (ranged/bow/magic combat)
+    /// splash_radius is how far it bursts on impact, in hexes (0 = hits only its target)
+    /// cover is how many tiles shield the target from the shot; pass it back to projectile_hit
     #[signal]
     fn spawn_projectile(
         attacker_ulid: PackedByteArray,
@@ -112,13 +399,19 @@ impl UnifiedEventBridge {
         target_pos_q: i32,
         target_pos_r: i32,
         projectile_type: i32,
-        damage: i32
+        damage: i32,
+        splash_radius: i32,
+        cover: i32
     );
 
-    /// Emitted when a resource changes
+    /// Emitted when a resource in the local player's pool changes (see set_local_player)
     #[signal]
     fn resource_changed(resource_type: i32, current: f32, cap: f32, rate: f32);
 
+    /// Emitted when a resource in any pool changes (empty player_ulid = AI/neutral pool)
+    #[signal]
+    fn player_resource_changed(player_ulid: PackedByteArray, resource_type: i32, current: f32, cap: f32, rate: f32);
+
     /// Emitted when a stat changes
     #[signal]
     fn stat_changed(ulid: PackedByteArray, stat_type: i64, new_value: f32);
@@ -131,10 +424,104 @@ impl UnifiedEventBridge {
     #[signal]
     fn entity_healed(ulid: PackedByteArray, heal_amount: f32, new_hp: f32);
 
+    /// Emitted when a status effect takes hold, stacks or refreshes (kind: StatusKind value,
+    /// duration in seconds); move_speed_multiplier is the entity's speed with all its effects
+    #[signal]
+    fn status_applied(ulid: PackedByteArray, source_ulid: PackedByteArray, kind: i64, stacks: i64, duration: f32, move_speed_multiplier: f32);
+
+    /// Emitted when a status effect runs out
+    #[signal]
+    fn status_expired(ulid: PackedByteArray, kind: i64, move_speed_multiplier: f32);
+
     /// Emitted when a combo is detected
     #[signal]
     fn combo_detected(hand_rank: i32, hand_name: GString, positions: VariantArray, bonuses: VariantArray);
 
+    /// Emitted for every applied transaction, including loot, combo rewards and upkeep
+    /// (layout in ledger_entry_to_dictionary)
+    #[signal]
+    fn transaction_applied(entry: Dictionary);
+
+    /// Emitted when a transaction or rollback changed nothing (shortfall: { resource_type: missing })
+    #[signal]
+    fn transaction_rejected(player_ulid: PackedByteArray, reason: i64, error: GString, shortfall: Dictionary);
+
+    /// Emitted when a pool's shortage has consequences (effect layout in shortage_effect_to_dictionary)
+    /// Starvation and reputation_loss repeat every upkeep; StructureManager mirrors reputation_loss
+    /// onto the player's structures
+    #[signal]
+    fn resource_shortage(player_ulid: PackedByteArray, resource_type: i64, deficit: f64, effect: Dictionary);
+
+    /// Emitted when a shortage reported by resource_shortage is over (paused production resumes)
+    #[signal]
+    fn shortage_ended(player_ulid: PackedByteArray, resource_type: i64);
+
+    /// Emitted when a recipe producer starts running, stalls on missing inputs, pauses or is switched off
+    /// (status layout in recipe_status_to_dictionary)
+    #[signal]
+    fn recipe_status_changed(ulid: PackedByteArray, player_ulid: PackedByteArray, status: Dictionary);
+
+    /// Emitted when a trade settles at a market (sold/bought: { resource_type: amount })
+    #[signal]
+    fn trade_completed(player_ulid: PackedByteArray, market_id: i64, sold: Dictionary, bought: Dictionary);
+
+    /// Emitted every upkeep for each taxed city (city_id = structure id); StructureManager applies
+    /// reputation_delta to the structure (apply_tax_reputation)
+    #[signal]
+    fn city_taxed(city_id: i64, player_ulid: PackedByteArray, income_per_turn: f64, reputation_delta: f64);
+
+    /// Emitted for every query_* reply (result keys depend on the query), by the bridge node that drained it
+    #[signal]
+    fn query_replied(request_id: i64, result: Dictionary);
+
+    /// Emitted when a replay recording has been written to disk
+    #[signal]
+    fn replay_saved(path: GString, ticks: i64);
+
+    /// Emitted when a replay recording could not be saved
+    #[signal]
+    fn replay_failed(error: GString);
+
+    /// Emitted when the game state has been written to disk
+    #[signal]
+    fn game_saved(path: GString);
+
+    /// Emitted when the game state could not be saved
+    #[signal]
+    fn save_failed(path: GString, error: GString);
+
+    /// Emitted when the game state has been replaced from a save file
+    #[signal]
+    fn game_loaded(path: GString, entity_count: i64, card_count: i64);
+
+    /// Emitted when a save file could not be loaded (state left untouched)
+    #[signal]
+    fn load_failed(path: GString, error: GString);
+
+    /// Emitted when a worker thread dies (restarted = false once the supervisor gives up)
+    #[signal]
+    fn worker_failed(worker: GString, error: GString, restarted: bool);
+
+    /// Emitted periodically once set_metrics_interval() is enabled (same layout as get_metrics())
+    #[signal]
+    fn metrics_reported(metrics: Dictionary);
+
+    /// Emitted when a request is dropped because a queue is full (kind = request name)
+    #[signal]
+    fn request_rejected(kind: GString, ulid: PackedByteArray, reason: GString);
+
+    /// Emitted after set_simulation_speed / set_simulation_paused / step_simulation
+    #[signal]
+    fn simulation_clock_changed(paused: bool, speed: f64);
+
+    /// Emitted when turn mode is switched (also after loading a save in the other mode)
+    #[signal]
+    fn turn_mode_changed(enabled: bool, turn: i64);
+
+    /// Emitted after step_turn() ran every phase (layout in turn_summary_to_dictionary)
+    #[signal]
+    fn turn_completed(summary: Dictionary);
+
     // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
     // // === IRC Chat Signals ===
     //
@@ -189,7 +576,7 @@ impl UnifiedEventBridge {
     /// Spawn an entity
     #[func]
     fn spawn_entity(&mut self, entity_type: GString, terrain_type: i32, preferred_q: i32, preferred_r: i32, search_radius: i32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::SpawnEntity {
+        self.send_request(GameRequest::SpawnEntity {
             entity_type: entity_type.to_string(),
             terrain_type: if terrain_type == 0 { TerrainType::Water } else { TerrainType::Land },
             preferred_location: (preferred_q, preferred_r),
@@ -200,7 +587,7 @@ impl UnifiedEventBridge {
     /// Request pathfinding
     #[func]
     fn request_path(&mut self, ulid: PackedByteArray, terrain_type: i32, start_q: i32, start_r: i32, goal_q: i32, goal_r: i32, avoid_entities: bool) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RequestPath {
+        self.send_request(GameRequest::RequestPath {
             ulid: ulid.to_vec(),
             terrain_type: if terrain_type == 0 { TerrainType::Water } else { TerrainType::Land },
             start: (start_q, start_r),
@@ -212,7 +599,7 @@ impl UnifiedEventBridge {
     /// Request random destination
     #[func]
     fn request_random_destination(&mut self, ulid: PackedByteArray, terrain_type: i32, start_q: i32, start_r: i32, min_distance: i32, max_distance: i32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RequestRandomDest {
+        self.send_request(GameRequest::RequestRandomDest {
             ulid: ulid.to_vec(),
             terrain_type: if terrain_type == 0 { TerrainType::Water } else { TerrainType::Land },
             start: (start_q, start_r),
@@ -224,7 +611,7 @@ impl UnifiedEventBridge {
     /// Update entity position
     #[func]
     fn update_entity_position(&mut self, ulid: PackedByteArray, q: i32, r: i32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::UpdateEntityPosition {
+        self.send_request(GameRequest::UpdateEntityPosition {
             ulid: ulid.to_vec(),
             position: (q, r),
         });
@@ -233,7 +620,7 @@ impl UnifiedEventBridge {
     /// Update entity state
     #[func]
     fn set_entity_state(&mut self, ulid: PackedByteArray, state: i64) {
-        let _ = CHANNELS.request_tx.send(GameRequest::UpdateEntityState {
+        self.send_request(GameRequest::UpdateEntityState {
             ulid: ulid.to_vec(),
             state,
         });
@@ -242,27 +629,29 @@ impl UnifiedEventBridge {
     /// Remove entity
     #[func]
     fn remove_entity(&mut self, ulid: PackedByteArray) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RemoveEntity {
+        self.send_request(GameRequest::RemoveEntity {
             ulid: ulid.to_vec(),
         });
     }
 
-    /// Register a resource producer
+    /// Register a resource producer (feeds the local player's pool)
     #[func]
     fn register_producer(&mut self, ulid: PackedByteArray, resource_type: i64, rate_per_sec: f64, active: bool) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RegisterProducer {
+        self.send_request(GameRequest::RegisterProducer {
             ulid: ulid.to_vec(),
+            player_ulid: local_player(),
             resource_type,
             rate_per_sec,
             active,
         });
     }
 
-    /// Register a resource consumer
+    /// Register a resource consumer (drains the local player's pool)
     #[func]
     fn register_consumer(&mut self, ulid: PackedByteArray, resource_type: i64, rate_per_sec: f64, active: bool) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RegisterConsumer {
+        self.send_request(GameRequest::RegisterConsumer {
             ulid: ulid.to_vec(),
+            player_ulid: local_player(),
             resource_type,
             rate_per_sec,
             active,
@@ -272,7 +661,7 @@ impl UnifiedEventBridge {
     /// Remove a producer
     #[func]
     fn remove_producer(&mut self, ulid: PackedByteArray) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RemoveProducer {
+        self.send_request(GameRequest::RemoveProducer {
             ulid: ulid.to_vec(),
         });
     }
@@ -280,7 +669,7 @@ impl UnifiedEventBridge {
     /// Remove a consumer
     #[func]
     fn remove_consumer(&mut self, ulid: PackedByteArray) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RemoveConsumer {
+        self.send_request(GameRequest::RemoveConsumer {
             ulid: ulid.to_vec(),
         });
     }
@@ -292,7 +681,7 @@ impl UnifiedEventBridge {
     /// Register entity stats (called when entity spawns)
     #[func]
     fn register_entity_stats(&mut self, ulid: PackedByteArray, player_ulid: PackedByteArray, entity_type: GString, terrain_type: i32, q: i32, r: i32, combat_type: i32, projectile_type: i32, combat_range: i32, aggro_range: i32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RegisterEntityStats {
+        self.send_request(GameRequest::RegisterEntityStats {
             ulid: ulid.to_vec(),
             player_ulid: player_ulid.to_vec(),
             entity_type: entity_type.to_string(),
@@ -308,7 +697,7 @@ impl UnifiedEventBridge {
     /// Set stat value
     #[func]
     fn set_stat(&mut self, ulid: PackedByteArray, stat_type: i64, value: f32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::SetStat {
+        self.send_request(GameRequest::SetStat {
             ulid: ulid.to_vec(),
             stat_type,
             value,
@@ -318,7 +707,7 @@ impl UnifiedEventBridge {
     /// Entity takes damage
     #[func]
     fn take_damage(&mut self, ulid: PackedByteArray, damage: f32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::TakeDamage {
+        self.send_request(GameRequest::TakeDamage {
             ulid: ulid.to_vec(),
             damage,
         });
@@ -327,21 +716,101 @@ impl UnifiedEventBridge {
     /// Heal entity
     #[func]
     fn heal(&mut self, ulid: PackedByteArray, amount: f32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::Heal {
+        self.send_request(GameRequest::Heal {
             ulid: ulid.to_vec(),
             amount,
         });
     }
 
+    /// Put a timed status effect on an entity (kind: 0 = poison, 1 = burn, 2 = stun, 3 = slow,
+    /// 4 = regen); magnitude is HP per tick per stack, or the fraction of speed a slow takes off
+    #[func]
+    fn apply_status(&mut self, ulid: PackedByteArray, source_ulid: PackedByteArray, kind: i64, duration: f32, magnitude: f32) {
+        let Some(kind) = u8::try_from(kind).ok().and_then(StatusKind::from_u8) else {
+            godot_error!("[UnifiedEventBridge] Unknown status kind {}", kind);
+            return;
+        };
+        self.send_request(GameRequest::ApplyStatus {
+            ulid: ulid.to_vec(),
+            source_ulid: source_ulid.to_vec(),
+            kind,
+            duration,
+            magnitude,
+        });
+    }
+
+    /// Status kinds an entity ignores from now on (replaces its previous immunities)
+    #[func]
+    fn set_status_immunities(&mut self, ulid: PackedByteArray, kinds: PackedInt64Array) {
+        let kinds = kinds.as_slice().iter()
+            .filter_map(|kind| u8::try_from(*kind).ok().and_then(StatusKind::from_u8))
+            .collect();
+        self.send_request(GameRequest::SetStatusImmunities {
+            ulid: ulid.to_vec(),
+            kinds,
+        });
+    }
+
+    /// How units of an entity type pick targets (targeting: 0 = nearest, 1 = lowest HP,
+    /// 2 = highest threat, 3 = prefer ranged, 4 = prefer structures); is_structure marks the type
+    /// as a structure for other units' prefer-structures targeting
+    #[func]
+    fn set_combat_profile(&mut self, entity_type: GString, targeting: i64, is_structure: bool) {
+        let Some(targeting) = u8::try_from(targeting).ok().and_then(TargetingPolicy::from_u8) else {
+            godot_error!("[UnifiedEventBridge] Unknown targeting policy {}", targeting);
+            return;
+        };
+        self.send_request(GameRequest::SetCombatProfile {
+            entity_type: entity_type.to_string(),
+            profile: CombatProfile { targeting, is_structure },
+        });
+    }
+
+    /// Every enemy within radius hexes of the entity gains threat toward it (tanks, war cries)
+    #[func]
+    fn taunt(&mut self, ulid: PackedByteArray, radius: i32, threat: f32) {
+        self.send_request(GameRequest::Taunt {
+            ulid: ulid.to_vec(),
+            radius,
+            threat,
+        });
+    }
+
+    /// How a projectile type bursts on impact: everyone within radius hexes of the impact takes
+    /// the power less falloff per hex; friendly_fire also catches the shooter's allies
+    #[func]
+    fn set_projectile_splash(&mut self, projectile_type: i64, radius: i32, falloff: f32, friendly_fire: bool) {
+        let Some(projectile) = u8::try_from(projectile_type).ok().and_then(ProjectileType::from_u8) else {
+            godot_error!("[UnifiedEventBridge] Unknown projectile type {}", projectile_type);
+            return;
+        };
+        self.send_request(GameRequest::SetProjectileSplash {
+            projectile_type: projectile.to_u8(),
+            splash: SplashProfile { radius, falloff, friendly_fire },
+        });
+    }
+
     /// Called by GDScript when a projectile hits its target
-    /// This applies the damage for ranged/bow/magic combat
+    /// This applies the damage for ranged/bow/magic combat; impact_q/impact_r is the hex it landed on,
+    /// the center of its burst if it splashes. damage and cover come from spawn_projectile
     #[func]
-    fn projectile_hit(&mut self, attacker_ulid: PackedByteArray, defender_ulid: PackedByteArray, damage: i32, projectile_type: i32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::ProjectileHit {
+    fn projectile_hit(
+        &mut self,
+        attacker_ulid: PackedByteArray,
+        defender_ulid: PackedByteArray,
+        damage: i32,
+        projectile_type: i32,
+        impact_q: i32,
+        impact_r: i32,
+        cover: i32,
+    ) {
+        self.send_request(GameRequest::ProjectileHit {
             attacker_ulid: attacker_ulid.to_vec(),
             defender_ulid: defender_ulid.to_vec(),
             damage,
             projectile_type: projectile_type as u8,
+            impact_position: (impact_q, impact_r),
+            cover: cover.max(0) as u32,
         });
     }
 
@@ -349,20 +818,45 @@ impl UnifiedEventBridge {
     // RESOURCE METHODS
     // ========================================================================
 
-    /// Add resources (called by combo system, building rewards, etc.)
+    /// Player whose pool the resource/card methods use and resource_changed reports
+    /// (empty = AI/neutral pool, the default until the host sets its player)
+    #[func]
+    fn set_local_player(&mut self, player_ulid: PackedByteArray) {
+        *LOCAL_PLAYER.write() = player_ulid.to_vec();
+
+        // Have the Actor create and publish the pool before the first spend
+        self.send_request(GameRequest::EnsurePlayerPool { player_ulid: player_ulid.to_vec() });
+    }
+
+    /// Add resources to the local player's pool (called by combo system, building rewards, etc.)
     #[func]
     fn add_resources(&mut self, resource_type: i64, amount: f64) {
-        let _ = CHANNELS.request_tx.send(GameRequest::AddResources {
+        let player_ulid = PackedByteArray::from(&local_player()[..]);
+        self.add_player_resources(player_ulid, resource_type, amount);
+    }
+
+    /// Add resources to a specific player's pool (empty = AI/neutral pool)
+    #[func]
+    fn add_player_resources(&mut self, player_ulid: PackedByteArray, resource_type: i64, amount: f64) {
+        self.send_request(GameRequest::AddResources {
+            player_ulid: player_ulid.to_vec(),
             resource_type,
             amount,
         });
     }
 
-    /// Spend resources (called by building costs, unit spawning, etc.)
+    /// Spend resources from the local player's pool (called by building costs, unit spawning, etc.)
     /// Accepts PackedByteArray with serialized cost data: [resource_type: i64, amount: f64] pairs
     /// Note: This is async - actual result comes via resource_changed signal
     #[func]
     fn spend_resources(&mut self, costs_bytes: PackedByteArray) {
+        let player_ulid = PackedByteArray::from(&local_player()[..]);
+        self.spend_player_resources(player_ulid, costs_bytes);
+    }
+
+    /// Spend resources from a specific player's pool (same cost format as spend_resources)
+    #[func]
+    fn spend_player_resources(&mut self, player_ulid: PackedByteArray, costs_bytes: PackedByteArray) {
         // Deserialize PackedByteArray into Vec<(i64, f64)>
         // Format: [resource_type (8 bytes), amount (8 bytes)] repeated
         let bytes = costs_bytes.as_slice();
@@ -383,7 +877,8 @@ impl UnifiedEventBridge {
             }
         }
 
-        let _ = CHANNELS.request_tx.send(GameRequest::SpendResources {
+        self.send_request(GameRequest::SpendResources {
+            player_ulid: player_ulid.to_vec(),
             cost: cost_vec,
         });
     }
@@ -392,7 +887,108 @@ impl UnifiedEventBridge {
     /// Consumes 1 food per active entity
     #[func]
     fn process_turn_consumption(&mut self) {
-        let _ = CHANNELS.request_tx.send(GameRequest::ProcessTurnConsumption);
+        self.send_request(GameRequest::ProcessTurnConsumption);
+    }
+
+    /// Credit (positive) / debit (negative) several resources of a pool at once, all-or-nothing
+    /// changes: { resource_type: signed amount }; reason: TransactionReason value (0 = adjustment,
+    /// 1 = combo reward, 2 = loot, 3 = upkeep, 4 = construction)
+    /// Result { applied, entry } or { applied: false, error, shortfall }
+    #[func]
+    fn apply_transaction(&mut self, player_ulid: PackedByteArray, reason: i64, changes: Dictionary, callback: Callable) -> i64 {
+        let Some(reason) = TransactionReason::from_i64(reason) else {
+            godot_error!("[UnifiedEventBridge] Unknown transaction reason {}", reason);
+            return 0;
+        };
+
+        self.submit_query(callback, |request_id| GameRequest::ApplyTransaction {
+            request_id,
+            player_ulid: player_ulid.to_vec(),
+            reason,
+            changes: resource_amounts(&changes),
+        })
+    }
+
+    /// Undo a transaction by id (same result layout as apply_transaction)
+    #[func]
+    fn rollback_transaction(&mut self, transaction_id: i64, callback: Callable) -> i64 {
+        self.submit_query(callback, |request_id| GameRequest::RollbackTransaction {
+            request_id,
+            transaction_id: transaction_id.max(0) as u64,
+        })
+    }
+
+    /// Change shortage consequences; missing keys keep their defaults
+    /// config: { starvation_hp, starvation_morale, labor_pauses_production, faith_reputation_loss }
+    #[func]
+    fn set_shortage_config(&mut self, config: Dictionary) {
+        let defaults = ShortageConfig::default();
+        let number = |key: &str, default: f32| config.get(key).and_then(|v| v.try_to::<f32>().ok()).unwrap_or(default);
+        let config = ShortageConfig {
+            starvation_hp: number("starvation_hp", defaults.starvation_hp),
+            starvation_morale: number("starvation_morale", defaults.starvation_morale),
+            labor_pauses_production: config.get("labor_pauses_production")
+                .and_then(|v| v.try_to::<bool>().ok())
+                .unwrap_or(defaults.labor_pauses_production),
+            faith_reputation_loss: number("faith_reputation_loss", defaults.faith_reputation_loss),
+        };
+        self.send_request(GameRequest::SetShortageConfig { config });
+    }
+
+    /// Register a recipe producer for the local player's pool: takes `inputs` at the start of every
+    /// cycle and adds `outputs` when it completes, stalling while the pool can't cover the inputs
+    /// inputs/outputs: { resource_type: amount per cycle }; remove/toggle it like any other producer
+    #[func]
+    fn register_recipe(&mut self, ulid: PackedByteArray, inputs: Dictionary, outputs: Dictionary, cycle_secs: f64, active: bool) {
+        self.send_request(GameRequest::RegisterRecipe {
+            ulid: ulid.to_vec(),
+            player_ulid: local_player(),
+            recipe: Recipe { inputs: resource_amounts(&inputs), outputs: resource_amounts(&outputs), cycle_secs },
+            active,
+        });
+    }
+
+    /// Register a storage structure protecting the local player's stock from decay
+    /// protection: { resource_type: share of decay losses prevented (0.0-1.0) }; registering the same ulid replaces it
+    #[func]
+    fn register_storage(&mut self, ulid: PackedByteArray, protection: Dictionary) {
+        self.send_request(GameRequest::RegisterStorage {
+            ulid: ulid.to_vec(),
+            player_ulid: local_player(),
+            protection: resource_amounts(&protection),
+        });
+    }
+
+    /// Remove a storage registered with register_storage
+    #[func]
+    fn remove_storage(&mut self, ulid: PackedByteArray) {
+        self.send_request(GameRequest::RemoveStorage { ulid: ulid.to_vec() });
+    }
+
+    /// Sell `amount` of `sell_type` at a market for as much `buy_type` as its prices give
+    /// Same result layout as apply_transaction; trade_completed follows a settled trade
+    #[func]
+    fn trade(&mut self, market_id: i64, sell_type: i64, amount: f64, buy_type: i64, callback: Callable) -> i64 {
+        self.submit_query(callback, |request_id| GameRequest::Trade {
+            request_id,
+            player_ulid: local_player(),
+            market_id,
+            sell: (sell_type, amount),
+            buy: buy_type,
+        })
+    }
+
+    /// A market's prices: result { market_id, trade_modifier, prices: Array of { resource_type, stock, price } }
+    /// price is the unit price before the trade fee and modifier; prices is empty for unknown markets
+    #[func]
+    fn query_market(&mut self, market_id: i64, callback: Callable) -> i64 {
+        self.submit_query(callback, |request_id| GameRequest::QueryMarket { request_id, market_id })
+    }
+
+    /// The local player's recipe producers: result { recipes: Array } (see recipe_report_to_dictionary)
+    #[func]
+    fn query_recipes(&mut self, callback: Callable) -> i64 {
+        self.submit_query(callback, |request_id| GameRequest::QueryRecipes { request_id, player_ulid: local_player() })
     }
 
     // ========================================================================
@@ -403,10 +999,11 @@ impl UnifiedEventBridge {
     /// Actor's CardRegistry is the single source of truth for card placement
     #[func]
     fn place_card(&mut self, x: i32, y: i32, ulid: PackedByteArray, suit: i32, value: i32, card_id: i32, is_custom: bool) {
-        let _ = CHANNELS.request_tx.send(GameRequest::PlaceCard {
+        self.send_request(GameRequest::PlaceCard {
             x,
             y,
             ulid: ulid.to_vec(),
+            player_ulid: local_player(),
             suit: suit as u8,
             value: value as u8,
             card_id,
@@ -417,13 +1014,13 @@ impl UnifiedEventBridge {
     /// Remove a card from the board by position
     #[func]
     fn remove_card_at(&mut self, x: i32, y: i32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RemoveCardAt { x, y });
+        self.send_request(GameRequest::RemoveCardAt { x, y });
     }
 
     /// Remove a card from the board by ULID
     #[func]
     fn remove_card_by_ulid(&mut self, ulid: PackedByteArray) {
-        let _ = CHANNELS.request_tx.send(GameRequest::RemoveCardByUlid {
+        self.send_request(GameRequest::RemoveCardByUlid {
             ulid: ulid.to_vec(),
         });
     }
@@ -432,10 +1029,174 @@ impl UnifiedEventBridge {
     /// Actor will check cards in radius and emit combo event if found
     #[func]
     fn detect_combo(&mut self, center_x: i32, center_y: i32, radius: i32) {
-        let _ = CHANNELS.request_tx.send(GameRequest::DetectCombo {
+        self.send_request(GameRequest::DetectCombo {
+            center_x,
+            center_y,
+            radius,
+        });
+    }
+
+    // ========================================================================
+    // QUERY METHODS (answered asynchronously, matched by request id)
+    // ========================================================================
+    // Each query returns its request id. The reply arrives via query_replied and,
+    // if a valid callback was given, by calling it with the result Dictionary.
+
+    /// Query one stat: result { ulid, stat_type, found, value }
+    #[func]
+    fn query_stat(&mut self, ulid: PackedByteArray, stat_type: i64, callback: Callable) -> i64 {
+        self.submit_query(callback, |request_id| GameRequest::GetStat {
+            request_id,
+            ulid: ulid.to_vec(),
+            stat_type,
+        })
+    }
+
+    /// Query path cost without moving: result { found, path, cost }
+    #[func]
+    fn query_path_cost(&mut self, terrain_type: i32, start_q: i32, start_r: i32, goal_q: i32, goal_r: i32, callback: Callable) -> i64 {
+        self.submit_query(callback, |request_id| GameRequest::QueryPathCost {
+            request_id,
+            terrain_type: if terrain_type == 0 { TerrainType::Water } else { TerrainType::Land },
+            start: (start_q, start_r),
+            goal: (goal_q, goal_r),
+        })
+    }
+
+    /// Check a cost ({ resource_type: amount }) against the local player's pool without spending: result { affordable, shortfall }
+    #[func]
+    fn query_can_afford(&mut self, cost: Dictionary, callback: Callable) -> i64 {
+        let cost = resource_amounts(&cost);
+        self.submit_query(callback, |request_id| GameRequest::QueryCanAfford { request_id, player_ulid: local_player(), cost })
+    }
+
+    /// Ledger history, newest `limit` entries oldest first (0 = all kept): result { entries: Array }
+    /// filter: optional { player_ulid: PackedByteArray, reason: int }
+    #[func]
+    fn query_ledger(&mut self, filter: Dictionary, limit: i32, callback: Callable) -> i64 {
+        let player_ulid = filter.get("player_ulid").and_then(|v| v.try_to::<PackedByteArray>().ok()).map(|p| p.to_vec());
+        let reason = filter.get("reason").and_then(|v| v.try_to::<i64>().ok()).and_then(TransactionReason::from_i64);
+
+        self.submit_query(callback, |request_id| GameRequest::QueryLedger { request_id, player_ulid, reason, limit: limit.max(0) as u32 })
+    }
+
+    /// Project the local player's pool `turns` turns ahead (turn mode: upkeep, then a turn of production)
+    /// Result { horizon_secs, resources: Array } (see resource_forecast_to_dictionary)
+    #[func]
+    fn query_forecast_turns(&mut self, turns: i32, callback: Callable) -> i64 {
+        self.query_forecast(ForecastHorizon::Turns(turns.max(0) as u32), callback)
+    }
+
+    /// Project the local player's pool `seconds` ahead in real time (upkeep at the end of every full turn)
+    /// Result { horizon_secs, resources: Array } (see resource_forecast_to_dictionary)
+    #[func]
+    fn query_forecast_seconds(&mut self, seconds: f64, callback: Callable) -> i64 {
+        self.query_forecast(ForecastHorizon::Seconds(seconds), callback)
+    }
+
+    /// Preview the combo at a position with optional extra cards
+    /// extra_cards: Array of Dictionaries { x, y, suit, value }
+    /// Result { hand_rank, hand_name, positions, bonuses } (hand_rank 0 = no combo)
+    #[func]
+    fn query_combo(&mut self, center_x: i32, center_y: i32, radius: i32, extra_cards: Array<Dictionary>, callback: Callable) -> i64 {
+        let extra_cards: Vec<(i32, i32, u8, u8)> = extra_cards
+            .iter_shared()
+            .filter_map(|card| {
+                let x = card.get("x")?.try_to::<i32>().ok()?;
+                let y = card.get("y")?.try_to::<i32>().ok()?;
+                let suit = card.get("suit")?.try_to::<i32>().ok()?;
+                let value = card.get("value")?.try_to::<i32>().ok()?;
+                Some((x, y, suit as u8, value as u8))
+            })
+            .collect();
+
+        self.submit_query(callback, |request_id| GameRequest::QueryCombo {
+            request_id,
             center_x,
             center_y,
             radius,
+            extra_cards,
+        })
+    }
+
+    // ========================================================================
+    // REPLAY METHODS
+    // ========================================================================
+
+    /// Start recording every request/event into a replay (seeds the Actor's RNG)
+    #[func]
+    fn start_replay_recording(&mut self, seed: i64) {
+        self.send_request(GameRequest::StartReplayRecording {
+            seed: seed as u64,
+        });
+    }
+
+    /// Stop recording and write the replay file (emits replay_saved or replay_failed)
+    #[func]
+    fn stop_replay_recording(&mut self, path: GString) {
+        self.send_request(GameRequest::StopReplayRecording {
+            path: path.to_string(),
+        });
+    }
+
+    // ========================================================================
+    // SIMULATION CLOCK METHODS (debugging fights, fast-forward)
+    // ========================================================================
+
+    /// Simulated time multiplier (clamped to 0.5..=8.0)
+    #[func]
+    fn set_simulation_speed(&mut self, speed: f64) {
+        self.send_request(GameRequest::SetSimulationSpeed { speed });
+    }
+
+    /// Freeze/resume simulated time (requests are still handled while paused)
+    #[func]
+    fn set_simulation_paused(&mut self, paused: bool) {
+        self.send_request(GameRequest::SetSimulationPaused { paused });
+    }
+
+    /// Advance a paused simulation by single ticks
+    #[func]
+    fn step_simulation(&mut self, ticks: i32) {
+        self.send_request(GameRequest::StepSimulation {
+            ticks: ticks.max(0) as u32,
+        });
+    }
+
+    // ========================================================================
+    // TURN METHODS
+    // ========================================================================
+
+    /// Pause real-time economy/combat and advance only on step_turn() (emits turn_mode_changed)
+    /// In turn mode the Actor also rolls loot for units it kills, so skip the real-time loot path
+    #[func]
+    fn set_turn_mode(&mut self, enabled: bool) {
+        self.send_request(GameRequest::SetTurnMode { enabled });
+    }
+
+    /// Run one turn: upkeep, production, movement, combat, loot, combo rewards (emits turn_completed)
+    #[func]
+    fn step_turn(&mut self) {
+        self.send_request(GameRequest::StepTurn);
+    }
+
+    // ========================================================================
+    // SAVE/LOAD METHODS
+    // ========================================================================
+
+    /// Save all Actor-owned state (emits game_saved or save_failed)
+    #[func]
+    fn save_game(&mut self, path: GString) {
+        self.send_request(GameRequest::SaveGame {
+            path: path.to_string(),
+        });
+    }
+
+    /// Replace all Actor-owned state from a save (emits game_loaded or load_failed)
+    #[func]
+    fn load_game(&mut self, path: GString) {
+        self.send_request(GameRequest::LoadGame {
+            path: path.to_string(),
         });
     }
 
@@ -492,6 +1253,28 @@ impl UnifiedEventBridge {
         dict
     }
 
+    /// Latest Actor telemetry (synchronous query, refreshed every Actor tick)
+    /// Keys: tick_count, tick, phases, request_queue, event_queue, worker_queues, path_latency,
+    /// path_backlog, superseded_paths, coalesced_requests
+    /// Timings are Dictionaries with count, last_ms, avg_ms, max_ms
+    #[func]
+    fn get_metrics(&self) -> Dictionary {
+        use super::actor::ACTOR_METRICS;
+
+        match ACTOR_METRICS.lock() {
+            Ok(metrics) => metrics_to_dictionary(&metrics),
+            Err(_) => Dictionary::new(),
+        }
+    }
+
+    /// Emit metrics_reported every interval_secs of Actor time (0 = off)
+    #[func]
+    fn set_metrics_interval(&mut self, interval_secs: f64) {
+        self.send_request(GameRequest::SetMetricsInterval {
+            interval_secs,
+        });
+    }
+
     // DEPRECATED: IRC/WebSocket now handled by GDScript (irc_websocket_client.gd)
     // // ========================================================================
     // // IRC CHAT HISTORY METHODS (Query DashMap storage from GDScript)
@@ -737,8 +1520,256 @@ impl UnifiedEventBridge {
     // EVENT EMISSION (Internal - converts Rust events to Godot signals)
     // ========================================================================
 
+    /// Send the request `build` makes for a fresh request id and remember the callback (invalid =
+    /// signal only) until its reply; returns the id (the callback is forgotten if the request was not queued)
+    fn submit_query(&mut self, callback: Callable, build: impl FnOnce(RequestId) -> GameRequest) -> i64 {
+        let request_id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+        PENDING_QUERIES.with_borrow_mut(|pending| pending.insert(request_id, callback));
+        if !self.send_request(build(request_id)) {
+            PENDING_QUERIES.with_borrow_mut(|pending| pending.remove(&request_id));
+        }
+        request_id as i64
+    }
+
+    fn query_forecast(&mut self, horizon: ForecastHorizon, callback: Callable) -> i64 {
+        self.submit_query(callback, |request_id| GameRequest::QueryForecast { request_id, player_ulid: local_player(), horizon })
+    }
+
+    /// Deliver a query result to its callback and the query_replied signal
+    /// Ids no query_* call issued (e.g. the Actor's own transactions) are not replies and are skipped
+    fn reply_query(&mut self, request_id: RequestId, result: Dictionary) {
+        // Taken out before the call: the callback may well send the next query
+        let Some(callback) = PENDING_QUERIES.with_borrow_mut(|pending| pending.remove(&request_id)) else {
+            return;
+        };
+        if callback.is_valid() {
+            callback.call(&[result.to_variant()]);
+        }
+
+        self.base_mut().emit_signal(
+            "query_replied",
+            &[(request_id as i64).to_variant(), result.to_variant()],
+        );
+    }
+
+    /// Queue a request for the Actor without blocking the main thread
+    /// When the queue is full, position updates are dropped (a newer one follows) and
+    /// everything else is reported via request_rejected. Returns false if not queued.
+    fn send_request(&mut self, request: GameRequest) -> bool {
+        match try_queue_request(request) {
+            Ok(()) => true,
+            Err(TrySendError::Full(request)) => {
+                if request.queue_policy() != QueuePolicy::Coalesce {
+                    self.emit_event(request.rejected("request queue full"));
+                }
+                false
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        }
+    }
+
     fn emit_event(&mut self, event: GameEvent) {
         match event {
+            GameEvent::StatReply { request_id, ulid, stat_type, value } => {
+                let mut result = Dictionary::new();
+                result.set("ulid", PackedByteArray::from(&ulid[..]));
+                result.set("stat_type", stat_type);
+                result.set("found", value.is_some());
+                result.set("value", value.unwrap_or(0.0));
+                self.reply_query(request_id, result);
+            }
+
+            GameEvent::PathCostReply { request_id, found, path, cost } => {
+                let mut path_array = Array::<Vector2i>::new();
+                for (q, r) in path {
+                    path_array.push(Vector2i::new(q, r));
+                }
+
+                let mut result = Dictionary::new();
+                result.set("found", found);
+                result.set("path", path_array);
+                result.set("cost", cost);
+                self.reply_query(request_id, result);
+            }
+
+            GameEvent::CanAffordReply { request_id, affordable, shortfall } => {
+                let mut missing = Dictionary::new();
+                for (resource_type, amount) in shortfall {
+                    missing.set(resource_type, amount);
+                }
+
+                let mut result = Dictionary::new();
+                result.set("affordable", affordable);
+                result.set("shortfall", missing);
+                self.reply_query(request_id, result);
+            }
+
+            GameEvent::LedgerReply { request_id, entries } => {
+                let mut list = VariantArray::new();
+                for entry in &entries {
+                    list.push(&ledger_entry_to_dictionary(entry).to_variant());
+                }
+
+                let mut result = Dictionary::new();
+                result.set("entries", list);
+                self.reply_query(request_id, result);
+            }
+
+            GameEvent::RecipesReply { request_id, recipes } => {
+                let mut list = VariantArray::new();
+                for report in &recipes {
+                    list.push(&recipe_report_to_dictionary(report).to_variant());
+                }
+
+                let mut result = Dictionary::new();
+                result.set("recipes", list);
+                self.reply_query(request_id, result);
+            }
+
+            GameEvent::MarketReply { request_id, market_id, trade_modifier, prices } => {
+                let mut list = VariantArray::new();
+                for entry in &prices {
+                    let mut dict = Dictionary::new();
+                    dict.set("resource_type", entry.resource_type);
+                    dict.set("stock", entry.stock);
+                    dict.set("price", entry.price);
+                    list.push(&dict.to_variant());
+                }
+
+                let mut result = Dictionary::new();
+                result.set("market_id", market_id);
+                result.set("trade_modifier", trade_modifier);
+                result.set("prices", list);
+                self.reply_query(request_id, result);
+            }
+
+            GameEvent::TradeCompleted { request_id: _, player_ulid, market_id, sold, bought } => {
+                let mut sold_dict = Dictionary::new();
+                sold_dict.set(sold.0, sold.1);
+                let mut bought_dict = Dictionary::new();
+                bought_dict.set(bought.0, bought.1);
+                self.base_mut().emit_signal(
+                    "trade_completed",
+                    &[
+                        PackedByteArray::from(&player_ulid[..]).to_variant(),
+                        market_id.to_variant(),
+                        sold_dict.to_variant(),
+                        bought_dict.to_variant(),
+                    ],
+                );
+            }
+
+            GameEvent::CityTaxed { city_id, player_ulid, income_per_turn, reputation_delta } => {
+                self.base_mut().emit_signal(
+                    "city_taxed",
+                    &[
+                        city_id.to_variant(),
+                        PackedByteArray::from(&player_ulid[..]).to_variant(),
+                        income_per_turn.to_variant(),
+                        reputation_delta.to_variant(),
+                    ],
+                );
+            }
+
+            GameEvent::RecipeStatusChanged { ulid, player_ulid, status } => {
+                self.base_mut().emit_signal(
+                    "recipe_status_changed",
+                    &[
+                        PackedByteArray::from(&ulid[..]).to_variant(),
+                        PackedByteArray::from(&player_ulid[..]).to_variant(),
+                        recipe_status_to_dictionary(&status).to_variant(),
+                    ],
+                );
+            }
+
+            GameEvent::ForecastReply { request_id, player_ulid: _, horizon_secs, resources } => {
+                let mut list = VariantArray::new();
+                for forecast in &resources {
+                    list.push(&resource_forecast_to_dictionary(forecast).to_variant());
+                }
+
+                let mut result = Dictionary::new();
+                result.set("horizon_secs", horizon_secs);
+                result.set("resources", list);
+                self.reply_query(request_id, result);
+            }
+
+            GameEvent::TransactionApplied { request_id, entry } => {
+                let entry = ledger_entry_to_dictionary(&entry);
+                let mut result = Dictionary::new();
+                result.set("applied", true);
+                result.set("entry", entry.clone());
+                self.reply_query(request_id, result);
+                self.base_mut().emit_signal("transaction_applied", &[entry.to_variant()]);
+            }
+
+            GameEvent::ResourceShortage { player_ulid, resource_type, deficit, effect } => {
+                self.base_mut().emit_signal(
+                    "resource_shortage",
+                    &[
+                        PackedByteArray::from(&player_ulid[..]).to_variant(),
+                        resource_type.to_variant(),
+                        deficit.to_variant(),
+                        shortage_effect_to_dictionary(&effect).to_variant(),
+                    ],
+                );
+            }
+
+            GameEvent::ShortageEnded { player_ulid, resource_type } => {
+                self.base_mut().emit_signal(
+                    "shortage_ended",
+                    &[PackedByteArray::from(&player_ulid[..]).to_variant(), resource_type.to_variant()],
+                );
+            }
+
+            GameEvent::TransactionRejected { request_id, player_ulid, reason, error, shortfall } => {
+                let mut missing = Dictionary::new();
+                for (resource_type, amount) in shortfall {
+                    missing.set(resource_type, amount);
+                }
+
+                let mut result = Dictionary::new();
+                result.set("applied", false);
+                result.set("error", GString::from(&error));
+                result.set("shortfall", missing.clone());
+                self.reply_query(request_id, result);
+                self.base_mut().emit_signal(
+                    "transaction_rejected",
+                    &[
+                        PackedByteArray::from(&player_ulid[..]).to_variant(),
+                        (reason as i64).to_variant(),
+                        GString::from(&error).to_variant(),
+                        missing.to_variant(),
+                    ],
+                );
+            }
+
+            GameEvent::ComboReply { request_id, hand_rank, hand_name, card_positions, resource_bonuses } => {
+                // Same shapes as combo_detected
+                let mut positions = VariantArray::new();
+                for (x, y) in card_positions {
+                    let mut pos_dict = Dictionary::new();
+                    let _ = pos_dict.insert("x", x);
+                    let _ = pos_dict.insert("y", y);
+                    positions.push(&pos_dict.to_variant());
+                }
+
+                let mut bonuses = VariantArray::new();
+                for (resource_type, amount) in resource_bonuses {
+                    let mut bonus_dict = Dictionary::new();
+                    let _ = bonus_dict.insert("resource_type", resource_type);
+                    let _ = bonus_dict.insert("amount", amount);
+                    bonuses.push(&bonus_dict.to_variant());
+                }
+
+                let mut result = Dictionary::new();
+                result.set("hand_rank", hand_rank);
+                result.set("hand_name", GString::from(&hand_name));
+                result.set("positions", positions);
+                result.set("bonuses", bonuses);
+                self.reply_query(request_id, result);
+            }
+
             GameEvent::EntitySpawned { ulid, position, terrain_type, entity_type } => {
                 self.base_mut().emit_signal(
                     "entity_spawned",
@@ -808,13 +1839,14 @@ impl UnifiedEventBridge {
                 );
             }
 
-            GameEvent::DamageDealt { attacker_ulid, defender_ulid, damage } => {
+            GameEvent::DamageDealt { attacker_ulid, defender_ulid, damage, outcome } => {
                 self.base_mut().emit_signal(
                     "damage_dealt",
                     &[
                         PackedByteArray::from(&attacker_ulid[..]).to_variant(),
                         PackedByteArray::from(&defender_ulid[..]).to_variant(),
                         damage.to_variant(),
+                        GString::from(outcome.name()).to_variant(),
                     ],
                 );
             }
@@ -843,11 +1875,15 @@ impl UnifiedEventBridge {
                 target_position,
                 projectile_type,
                 damage,
+                splash_radius,
+                cover,
             } => {
                 godot_print!(
-                    "[Rust Bridge] Emitting spawn_projectile signal: type={}, damage={}, pos=({},{})->({},{})",
+                    "[Rust Bridge] Emitting spawn_projectile signal: type={}, damage={}, splash={}, cover={}, pos=({},{})->({},{})",
                     projectile_type,
                     damage,
+                    splash_radius,
+                    cover,
                     attacker_position.0,
                     attacker_position.1,
                     target_position.0,
@@ -864,14 +1900,28 @@ impl UnifiedEventBridge {
                         target_position.1.to_variant(),
                         projectile_type.to_variant(),
                         damage.to_variant(),
+                        splash_radius.to_variant(),
+                        (cover as i32).to_variant(),
                     ],
                 );
             }
 
-            GameEvent::ResourceChanged { resource_type, current, cap, rate } => {
+            GameEvent::ResourceChanged { player_ulid, resource_type, current, cap, rate } => {
+                if player_ulid == local_player() {
+                    self.base_mut().emit_signal(
+                        "resource_changed",
+                        &[
+                            resource_type.to_variant(),
+                            current.to_variant(),
+                            cap.to_variant(),
+                            rate.to_variant(),
+                        ],
+                    );
+                }
                 self.base_mut().emit_signal(
-                    "resource_changed",
+                    "player_resource_changed",
                     &[
+                        PackedByteArray::from(&player_ulid[..]).to_variant(),
                         resource_type.to_variant(),
                         current.to_variant(),
                         cap.to_variant(),
@@ -913,6 +1963,31 @@ impl UnifiedEventBridge {
                 );
             }
 
+            GameEvent::StatusApplied { ulid, source_ulid, kind, stacks, duration, move_speed_multiplier } => {
+                self.base_mut().emit_signal(
+                    "status_applied",
+                    &[
+                        PackedByteArray::from(&ulid[..]).to_variant(),
+                        PackedByteArray::from(&source_ulid[..]).to_variant(),
+                        (kind.to_u8() as i64).to_variant(),
+                        (stacks as i64).to_variant(),
+                        duration.to_variant(),
+                        move_speed_multiplier.to_variant(),
+                    ],
+                );
+            }
+
+            GameEvent::StatusExpired { ulid, kind, move_speed_multiplier } => {
+                self.base_mut().emit_signal(
+                    "status_expired",
+                    &[
+                        PackedByteArray::from(&ulid[..]).to_variant(),
+                        (kind.to_u8() as i64).to_variant(),
+                        move_speed_multiplier.to_variant(),
+                    ],
+                );
+            }
+
             GameEvent::ComboDetected { hand_rank, hand_name, card_positions, resource_bonuses } => {
                 // Convert positions to VariantArray
                 let mut positions = VariantArray::new();
@@ -943,6 +2018,98 @@ impl UnifiedEventBridge {
                 );
             }
 
+            GameEvent::ReplaySaved { path, ticks } => {
+                self.base_mut().emit_signal(
+                    "replay_saved",
+                    &[GString::from(&path).to_variant(), (ticks as i64).to_variant()],
+                );
+            }
+
+            GameEvent::ReplayFailed { error } => {
+                self.base_mut().emit_signal(
+                    "replay_failed",
+                    &[GString::from(&error).to_variant()],
+                );
+            }
+
+            GameEvent::GameSaved { path } => {
+                self.base_mut().emit_signal(
+                    "game_saved",
+                    &[GString::from(&path).to_variant()],
+                );
+            }
+
+            GameEvent::SaveFailed { path, error } => {
+                self.base_mut().emit_signal(
+                    "save_failed",
+                    &[GString::from(&path).to_variant(), GString::from(&error).to_variant()],
+                );
+            }
+
+            GameEvent::GameLoaded { path, entity_count, card_count } => {
+                self.base_mut().emit_signal(
+                    "game_loaded",
+                    &[
+                        GString::from(&path).to_variant(),
+                        (entity_count as i64).to_variant(),
+                        (card_count as i64).to_variant(),
+                    ],
+                );
+            }
+
+            GameEvent::LoadFailed { path, error } => {
+                self.base_mut().emit_signal(
+                    "load_failed",
+                    &[GString::from(&path).to_variant(), GString::from(&error).to_variant()],
+                );
+            }
+
+            GameEvent::MetricsReport { metrics } => {
+                let metrics = metrics_to_dictionary(&metrics);
+                self.base_mut().emit_signal("metrics_reported", &[metrics.to_variant()]);
+            }
+
+            GameEvent::RequestRejected { kind, ulid, reason } => {
+                self.base_mut().emit_signal(
+                    "request_rejected",
+                    &[
+                        GString::from(&kind).to_variant(),
+                        PackedByteArray::from(&ulid[..]).to_variant(),
+                        GString::from(&reason).to_variant(),
+                    ],
+                );
+            }
+
+            GameEvent::SimulationClockChanged { paused, speed } => {
+                self.base_mut().emit_signal(
+                    "simulation_clock_changed",
+                    &[paused.to_variant(), speed.to_variant()],
+                );
+            }
+
+            GameEvent::TurnModeChanged { enabled, turn } => {
+                self.base_mut().emit_signal(
+                    "turn_mode_changed",
+                    &[enabled.to_variant(), (turn as i64).to_variant()],
+                );
+            }
+
+            GameEvent::TurnCompleted { summary } => {
+                let summary = turn_summary_to_dictionary(&summary);
+                self.base_mut().emit_signal("turn_completed", &[summary.to_variant()]);
+            }
+
+            GameEvent::WorkerFailed { worker, error, restarted } => {
+                self.base_mut().emit_signal(
+                    "worker_failed",
+                    &[
+                        GString::from(&worker).to_variant(),
+                        GString::from(&error).to_variant(),
+                        restarted.to_variant(),
+                    ],
+                );
+            }
+
             GameEvent::NetworkConnected { session_id } => {
                 self.base_mut().emit_signal(
                     "network_connected",
@@ -1,10 +1,10 @@
 // Unified event system module
 // Actor-coordinator pattern with lock-free communication
+// The Actor, its workers and the request/event types live in the headless
+// godo-sim crate; this module only adds the Godot bridge.
 
-pub mod actor;
-pub mod types;
+pub use godo_sim::{actor, types, workers};
 pub mod bridge;
-pub mod workers;
 
 pub use actor::GameActor;
 pub use types::{GameEvent, GameRequest};
@@ -11,7 +11,7 @@ macro_rules! debug_log {
 //mod animation;
 //mod inventory_data_warehouse;
 
-pub mod config;  // Centralized configuration constants
+pub use godo_sim::config;  // Centralized configuration constants
 mod db;  // Platform-specific SQLite abstraction
 mod npc;
 mod ui;
@@ -42,12 +42,25 @@ mod macos;
 #[cfg(target_os = "windows")]
 mod windows;
 
+/// Forwards godo-sim log output to godot_print / godot_warn / godot_error
+fn godot_log_sink(level: godo_sim::log::LogLevel, message: &str) {
+    use godo_sim::log::LogLevel;
+    match level {
+        LogLevel::Info => godot_print!("{}", message),
+        LogLevel::Warn => godot_warn!("{}", message),
+        LogLevel::Error => godot_error!("{}", message),
+    }
+}
+
 struct Godo;
 
 #[gdextension]
 unsafe impl ExtensionLibrary for Godo {
     fn on_level_init(level: InitLevel) {
         if level == InitLevel::Scene {
+            // Route headless simulation core logging to the Godot console
+            godo_sim::log::set_log_sink(godot_log_sink);
+
             // Re-enabled: AsyncRuntime for future async operations (native only)
             #[cfg(not(target_family = "wasm"))]
             {
@@ -71,6 +84,9 @@ unsafe impl ExtensionLibrary for Godo {
 
     fn on_level_deinit(level: InitLevel) {
         if level == InitLevel::Scene {
+            // Stop the Actor and join its worker threads before the library is unloaded
+            events::bridge::shutdown_actor();
+
             // Cleanup AsyncRuntime singleton (native only)
             #[cfg(not(target_family = "wasm"))]
             {
@@ -81,6 +97,8 @@ unsafe impl ExtensionLibrary for Godo {
                     godot_print!("[IRC] AsyncRuntime singleton cleaned up");
                 }
             }
+
+            godo_sim::log::clear_log_sink();
         }
     }
 }
\ No newline at end of file
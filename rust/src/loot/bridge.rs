@@ -1,7 +1,7 @@
 // GDScript bridge for loot system
 
 use godot::prelude::*;
-use super::drop_table::{generate_loot, RewardType};
+use godo_sim::drop_table::{generate_loot, RewardType};
 use super::loot_system;
 
 #[derive(GodotClass)]
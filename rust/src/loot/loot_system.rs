@@ -5,9 +5,10 @@ use std::collections::HashMap;
 use parking_lot::RwLock;
 use crossbeam_queue::SegQueue;
 
-use super::drop_table::{generate_loot, Reward, RewardType};
+use godo_sim::drop_table::{generate_loot, Reward, RewardType};
 use crate::ui::toast;
 use crate::economy::resource_ledger::{self, ResourceType};
+use crate::events::types::TransactionReason;
 use crate::storage::ulid_storage;
 
 /// Loot event to send to GDScript (for Draw/XP only)
@@ -61,9 +62,9 @@ impl LootSystem {
             }
         };
 
-        // Note: We don't need killer_ulid or player_ulid anymore because:
-        // - Resources are applied globally (not per-player yet)
-        // - When we add multiplayer, we'll need to track player ownership elsewhere
+        // Note: We don't need killer_ulid or player_ulid here because:
+        // - Real-time loot goes to the local player's pool (see resource_ledger::apply)
+        // - Turn-mode loot is credited to the killer's owner by the Actor's loot system
 
         // Look up entity type from ULID storage
         let entity_type = ulid_storage::get_entity_class(dead_entity_ulid);
@@ -80,12 +81,9 @@ impl LootSystem {
         // GDScript will replace {resource.gold}, {card.draw}, etc. with translated text
         let mut reward_texts = Vec::new();
         for reward in &rewards {
-            let text = match reward.reward_type {
+            let text = match &reward.reward_type {
                 RewardType::Draw => format!("+{} {{{{card.draw}}}}", reward.amount),
-                RewardType::Gold => format!("+{} {{{{resource.gold}}}}", reward.amount),
-                RewardType::Food => format!("+{} {{{{resource.food}}}}", reward.amount),
-                RewardType::Faith => format!("+{} {{{{resource.faith}}}}", reward.amount),
-                RewardType::Labor => format!("+{} {{{{resource.labor}}}}", reward.amount),
+                RewardType::Resource(key) => format!("+{} {{{{resource.{}}}}}", reward.amount, key),
                 RewardType::Experience => format!("+{} {{{{stat.experience}}}}", reward.amount),
             };
             reward_texts.push(text);
@@ -96,29 +94,23 @@ impl LootSystem {
         let toast_message = format!("{{{{game.loot}}}}: {}", reward_texts.join(", "));
         toast::send_message(toast_message);
 
-        // Apply resources directly from Rust
+        // Resource rewards go to the local player's pool as one loot transaction
+        let changes: Vec<(ResourceType, f64)> = rewards.iter()
+            .filter_map(|reward| {
+                let resource_type = ResourceType::from_i32(reward.reward_type.resource_type()? as i32)?;
+                Some((resource_type, reward.amount as f64))
+            })
+            .collect();
+        resource_ledger::apply(TransactionReason::Loot, &changes);
+
+        // Queue the rest for GDScript - card draw and XP systems don't exist yet in Rust
         for reward in &rewards {
-            match reward.reward_type {
-                RewardType::Gold => {
-                    resource_ledger::add(ResourceType::Gold, reward.amount as f32);
-                },
-                RewardType::Food => {
-                    resource_ledger::add(ResourceType::Food, reward.amount as f32);
-                },
-                RewardType::Labor => {
-                    resource_ledger::add(ResourceType::Labor, reward.amount as f32);
-                },
-                RewardType::Faith => {
-                    resource_ledger::add(ResourceType::Faith, reward.amount as f32);
-                },
-                RewardType::Draw | RewardType::Experience => {
-                    // Queue these for GDScript - card draw and XP systems don't exist yet in Rust
-                    self.loot_events.push(LootEvent {
-                        player_ulid: vec![], // Not used yet (no multiplayer)
-                        entity_type: entity_type.clone(),
-                        rewards: vec![reward.clone()],
-                    });
-                }
+            if matches!(reward.reward_type, RewardType::Draw | RewardType::Experience) {
+                self.loot_events.push(LootEvent {
+                    player_ulid: vec![], // Not used yet (no multiplayer)
+                    entity_type: entity_type.clone(),
+                    rewards: vec![reward.clone()],
+                });
             }
         }
     }
@@ -1,9 +1,8 @@
 // Loot system module
 
-mod drop_table;
 mod loot_system;
 mod bridge;
 
-pub use drop_table::{generate_loot, Reward, RewardType, DropTable, DropTableRegistry};
+pub use godo_sim::drop_table::{generate_loot, Reward, RewardType, DropTable, DropTableRegistry};
 pub use loot_system::{LootSystem, LootEvent};
 pub use bridge::LootBridge;
@@ -1,5 +1,6 @@
 use godot::prelude::*;
 use bitflags::bitflags;
+use godo_sim::taxation::NEUTRAL_TAX_RATE;
 
 bitflags! {
     /// Bitwise flags for structure types - allows structures to have multiple properties
@@ -16,6 +17,7 @@ bitflags! {
         const INHABITED    = 1 << 7;  // 128 - has population
         const HOSTILE      = 1 << 8;  // 256 - attacks on sight
         const ABANDONED    = 1 << 9;  // 512 - no longer active
+        const STORAGE      = 1 << 10; // 1024 - granary/warehouse, slows its owner's resource decay
     }
 }
 
@@ -49,6 +51,8 @@ impl StructureType {
     const HOSTILE: i64 = StructureFlags::HOSTILE.bits();
     #[constant]
     const ABANDONED: i64 = StructureFlags::ABANDONED.bits();
+    #[constant]
+    const STORAGE: i64 = StructureFlags::STORAGE.bits();
 
     /// Check if flags contain a specific flag
     #[func]
@@ -163,6 +167,10 @@ pub struct Structure {
     /// Reputation/relationship with player (-100 to 100)
     reputation: f32,
 
+    /// Share of what the population can afford paid to the owner every turn (0.0 to 1.0)
+    /// Above NEUTRAL_TAX_RATE reputation falls every turn, below it rises
+    tax_rate: f32,
+
     /// Whether the structure is active/intact
     is_active: bool,
 }
@@ -191,6 +199,7 @@ impl Structure {
                 population: 0,
                 wealth: 50.0,
                 reputation: 0.0,
+                tax_rate: NEUTRAL_TAX_RATE as f32,
                 is_active: true,
             }
         })
@@ -259,6 +268,11 @@ impl Structure {
         self.reputation
     }
 
+    #[func]
+    pub fn get_tax_rate(&self) -> f32 {
+        self.tax_rate
+    }
+
     #[func]
     pub fn is_structure_active(&self) -> bool {
         self.is_active
@@ -295,6 +309,11 @@ impl Structure {
         self.reputation = reputation.clamp(-100.0, 100.0);
     }
 
+    #[func]
+    pub fn set_tax_rate(&mut self, tax_rate: f32) {
+        self.tax_rate = tax_rate.clamp(0.0, 1.0);
+    }
+
     #[func]
     pub fn set_active(&mut self, active: bool) {
         self.is_active = active;
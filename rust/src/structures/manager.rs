@@ -1,8 +1,19 @@
 use godot::prelude::*;
 use super::{Structure, StructureFlags};
+use crate::events::bridge::try_queue_request;
+use crate::events::types::GameRequest;
+use godo_sim::resource_registry::resources;
 use std::sync::Arc;
 use parking_lot::RwLock;
 
+/// Share of every resource's decay a STORAGE structure prevents for its owner
+const STORAGE_PROTECTION: f64 = 0.5;
+
+/// Economy storage id of a structure (8 bytes, so it can't collide with 16-byte ULIDs)
+fn storage_ulid(id: i64) -> Vec<u8> {
+    id.to_be_bytes().to_vec()
+}
+
 /// Structure manager - handles spawning and tracking structures in the world
 #[derive(GodotClass)]
 #[class(init, base=Node)]
@@ -132,6 +143,9 @@ impl StructureManager {
         let mut structures = self.structures.write();
         if let Some(index) = structures.iter().position(|s| s.bind().get_id() == id) {
             structures.remove(index);
+            let _ = try_queue_request(GameRequest::RemoveStorage { ulid: storage_ulid(id) });
+            let _ = try_queue_request(GameRequest::RemoveMarket { market_id: id });
+            let _ = try_queue_request(GameRequest::RemoveCity { city_id: id });
             godot_print!("StructureManager: Removed structure ID {}", id);
             true
         } else {
@@ -139,14 +153,164 @@ impl StructureManager {
         }
     }
 
+    /// Change the reputation of every active structure a player owns (e.g. faith shortages)
+    /// Returns how many structures changed
+    #[func]
+    pub fn modify_owner_reputation(&mut self, owner_ulid: PackedByteArray, delta: f32) -> i64 {
+        let mut structures = self.structures.write();
+        let mut changed = 0;
+        for structure in structures.iter_mut() {
+            let mut structure = structure.bind_mut();
+            if structure.is_owned_by(owner_ulid.clone()) && structure.is_structure_active() {
+                structure.modify_reputation(delta);
+                Self::sync_market(&structure);
+                Self::sync_city(&structure);
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// UnifiedEventBridge.resource_shortage: a faithless player's structures lose reputation
+    #[func]
+    fn on_resource_shortage(&mut self, player_ulid: PackedByteArray, _resource_type: i64, _deficit: f64, effect: Dictionary) {
+        if effect.get("kind").map(|kind| kind.to_string()) != Some("reputation_loss".to_string()) {
+            return;
+        }
+        let amount = effect.get("amount").and_then(|amount| amount.try_to::<f32>().ok()).unwrap_or(0.0);
+        self.modify_owner_reputation(player_ulid, -amount);
+    }
+
+    /// Re-sync a structure's decay protection with the economy after its flags, owner or state changed
+    /// (owned, active STORAGE structures protect their owner's stock; anything else protects nothing)
+    #[func]
+    pub fn refresh_storage(&self, id: i64) {
+        let Some(structure) = self.get_structure(id) else {
+            return;
+        };
+        Self::sync_storage(&structure.bind());
+    }
+
+    /// Re-sync a structure's market terms with the economy after its flags, reputation, wealth or state changed
+    /// (active MARKET/TRADING_POST structures players can still deal with trade; the rest close)
+    #[func]
+    pub fn refresh_market(&self, id: i64) {
+        let Some(structure) = self.get_structure(id) else {
+            return;
+        };
+        Self::sync_market(&structure.bind());
+    }
+
+    /// Re-sync a structure's taxes with the economy after its owner, population, wealth, tax rate or
+    /// state changed (owned, active INHABITED structures pay their owner in gold; the economy keeps
+    /// the reputation of a city it already taxes)
+    #[func]
+    pub fn refresh_city(&self, id: i64) {
+        let Some(structure) = self.get_structure(id) else {
+            return;
+        };
+        Self::sync_city(&structure.bind());
+    }
+
+    /// Apply a turn's tax reputation change (UnifiedEventBridge.city_taxed) to a structure
+    /// Also re-syncs its market, whose terms follow reputation, and its city
+    #[func]
+    pub fn apply_tax_reputation(&mut self, id: i64, delta: f32) {
+        let Some(mut structure) = self.get_structure(id) else {
+            return;
+        };
+        let mut structure = structure.bind_mut();
+        structure.modify_reputation(delta);
+        Self::sync_market(&structure);
+        Self::sync_city(&structure);
+    }
+
+    /// UnifiedEventBridge.city_taxed: the taxed structure follows its city's reputation
+    #[func]
+    fn on_city_taxed(&mut self, city_id: i64, _player_ulid: PackedByteArray, _income_per_turn: f64, reputation_delta: f64) {
+        self.apply_tax_reputation(city_id, reputation_delta as f32);
+    }
+
     /// Get total number of structures
     #[func]
     pub fn get_structure_count(&self) -> i64 {
         self.structures.read().len() as i64
     }
 
+    /// Internal: Register (or drop) a structure's decay protection in the economy
+    fn sync_storage(structure: &Structure) {
+        let ulid = storage_ulid(structure.get_id());
+        let stores = structure.has_type(StructureFlags::STORAGE.bits())
+            && structure.is_owned()
+            && structure.is_structure_active();
+        let request = if stores {
+            GameRequest::RegisterStorage {
+                ulid,
+                player_ulid: structure.get_owner_ulid().to_vec(),
+                protection: resources().iter().map(|def| (def.id, STORAGE_PROTECTION)).collect(),
+            }
+        } else {
+            GameRequest::RemoveStorage { ulid }
+        };
+        if let Err(e) = try_queue_request(request) {
+            godot_warn!("StructureManager: storage update for structure {} dropped ({})", structure.get_id(), e);
+        }
+    }
+
+    /// Internal: Register (or close) a structure's market in the economy (market id = structure id)
+    fn sync_market(structure: &Structure) {
+        let trades = structure.has_type(StructureFlags::MARKET.bits())
+            || structure.has_type(StructureFlags::TRADING_POST.bits());
+        if !trades {
+            return;
+        }
+        let market_id = structure.get_id();
+        let request = if structure.can_interact() {
+            GameRequest::RegisterMarket {
+                market_id,
+                trade_modifier: structure.get_trade_modifier() as f64,
+                wealth: structure.get_wealth() as f64,
+            }
+        } else {
+            GameRequest::RemoveMarket { market_id }
+        };
+        if let Err(e) = try_queue_request(request) {
+            godot_warn!("StructureManager: market update for structure {} dropped ({})", market_id, e);
+        }
+    }
+
+    /// Internal: Register (or drop) a structure's taxes in the economy (city id = structure id)
+    fn sync_city(structure: &Structure) {
+        let city_id = structure.get_id();
+        let taxed = structure.has_type(StructureFlags::INHABITED.bits())
+            && structure.is_owned()
+            && structure.is_structure_active();
+        let request = if taxed {
+            GameRequest::RegisterCity {
+                city_id,
+                player_ulid: structure.get_owner_ulid().to_vec(),
+                population: structure.get_population(),
+                wealth: structure.get_wealth() as f64,
+                reputation: structure.get_reputation() as f64,
+                tax_rate: structure.get_tax_rate() as f64,
+            }
+        } else {
+            GameRequest::RemoveCity { city_id }
+        };
+        if let Err(e) = try_queue_request(request) {
+            godot_warn!("StructureManager: tax update for structure {} dropped ({})", city_id, e);
+        }
+    }
+
     /// Internal: Add a structure to the manager
     fn add_structure(&mut self, structure: Gd<Structure>) {
+        if structure.bind().has_type(StructureFlags::STORAGE.bits()) {
+            Self::sync_storage(&structure.bind());
+        }
+        Self::sync_market(&structure.bind());
+        if structure.bind().has_type(StructureFlags::INHABITED.bits()) {
+            Self::sync_city(&structure.bind());
+        }
         let mut structures = self.structures.write();
         structures.push(structure);
     }
@@ -163,6 +327,15 @@ impl StructureManager {
 #[godot_api]
 impl INode for StructureManager {
     fn ready(&mut self) {
+        // Structures mirror the reputation changes the economy makes to its cities
+        match self.base().get_node_or_null("/root/UnifiedEventBridge") {
+            Some(mut bridge) => {
+                let manager = self.to_gd();
+                bridge.connect("resource_shortage", &Callable::from_object_method(&manager, "on_resource_shortage"));
+                bridge.connect("city_taxed", &Callable::from_object_method(&manager, "on_city_taxed"));
+            }
+            None => godot_warn!("StructureManager: UnifiedEventBridge autoload not found, structure reputation won't follow the economy"),
+        }
         godot_print!("StructureManager ready!");
     }
 }
@@ -1,15 +1,13 @@
 use super::biomes::{BiomeGenerator, TerrainType};
 use super::noise::NoiseGenerator;
 use godot::prelude::*;
-use parking_lot::{Mutex, RwLock};
-use std::collections::HashMap;
+use parking_lot::Mutex;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::Arc;
 use std::thread::{self, JoinHandle};
 
-/// Thread-safe cache of noise generators by seed
-pub static NOISE_CACHE: once_cell::sync::Lazy<RwLock<HashMap<i32, Arc<NoiseGenerator>>>> =
-    once_cell::sync::Lazy::new(|| RwLock::new(HashMap::new()));
+/// Thread-safe cache of noise generators by seed (shared with the terrain cache)
+pub use godo_sim::world_gen::NOISE_CACHE;
 
 /// Chunk generation request
 #[derive(Debug, Clone)]
@@ -1,6 +1,6 @@
-pub mod biomes;
+// Noise and biome generation are shared with the headless godo-sim crate
+pub use godo_sim::world_gen::{biomes, noise};
 pub mod chunk_generator;
-pub mod noise;
 pub mod city_location;
 
 pub use biomes::{BiomeGenerator, TerrainType};